@@ -1,12 +1,16 @@
 use std::hint::black_box as bb;
 
 use criterion::Criterion;
-use jiff::civil;
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+};
 
 use crate::{benchmark, convert::ConvertFrom};
 
 pub(super) fn define(c: &mut Criterion) {
     print_civil_datetime(c);
+    print_zoned_strftime(c);
 }
 
 /// Measures the time it takes to print a civil datetime in ISO 8601 format.
@@ -89,3 +93,42 @@ fn print_civil_datetime(c: &mut Criterion) {
         });
     }
 }
+
+/// Measures the time it takes to print a zoned datetime with a `strftime`
+/// format string.
+///
+/// This compares formatting with a format string that is parsed on every call
+/// against formatting with a format that was compiled once up front.
+fn print_zoned_strftime(c: &mut Criterion) {
+    const NAME: &str = "print/zoned_strftime";
+    const FMT: &str = "%a, %-d %b %Y %T %z";
+    const EXPECTED: &str = "Sun, 30 Jun 2024 09:46:00 -0400";
+    const TZ: TimeZone = TimeZone::fixed(Offset::constant(-4));
+    const DATETIME: civil::DateTime = civil::date(2024, 6, 30).at(9, 46, 0, 0);
+
+    let zdt = DATETIME.to_zoned(TZ).unwrap();
+
+    {
+        let mut buf = String::new();
+        benchmark(c, format!("{NAME}/jiff-per-call"), |b| {
+            b.iter(|| {
+                let tm = jiff::fmt::strtime::BrokenDownTime::from(bb(&zdt));
+                buf.clear();
+                tm.format(bb(FMT), &mut buf).unwrap();
+                assert_eq!(buf, EXPECTED);
+            })
+        });
+    }
+
+    {
+        let format = jiff::fmt::strtime::Format::compile(FMT).unwrap();
+        let mut buf = String::new();
+        benchmark(c, format!("{NAME}/jiff-compiled"), |b| {
+            b.iter(|| {
+                buf.clear();
+                format.format_into(bb(&zdt), &mut buf).unwrap();
+                assert_eq!(buf, EXPECTED);
+            })
+        });
+    }
+}
@@ -17,6 +17,8 @@ pub(super) fn define(c: &mut Criterion) {
     to_civil_datetime_offset_conversion(c);
     to_civil_datetime_offset_holistic(c);
     to_civil_datetime_static(c);
+    to_zoned(c);
+    to_zoned_many(c);
 }
 
 /// Measures how long it takes to add 86400 seconds to a timestamp.
@@ -387,3 +389,73 @@ fn to_civil_datetime_static(c: &mut Criterion) {
     // faster at time of writing (2025-02-14).
     benchmark_with(c, "Asia/Shanghai", 1719711960);
 }
+
+/// Measures the time to convert a timestamp to a `Zoned`.
+///
+/// This compares the UTC time zone, for which no transition lookup is needed,
+/// with a time zone from the tzdb that has DST transitions. The UTC case is
+/// very common, e.g., when converting timestamps from external systems that
+/// always use UTC.
+fn to_zoned(c: &mut Criterion) {
+    const NAME: &str = "timestamp/to_zoned";
+    const STAMP: Timestamp = Timestamp::constant(1719755160, 0);
+
+    {
+        let tz = TimeZone::UTC;
+        let expected = civil::date(2024, 6, 30).at(13, 46, 0, 0);
+        benchmark(c, format!("{NAME}/utc/jiff"), |b| {
+            b.iter(|| {
+                let zdt = bb(STAMP).to_zoned(bb(&tz).clone());
+                assert_eq!(zdt.datetime(), expected);
+            })
+        });
+    }
+
+    if let Ok(tz) = TimeZone::get("America/New_York") {
+        let expected = civil::date(2024, 6, 30).at(9, 46, 0, 0);
+        benchmark(c, format!("{NAME}/America-New-York/zoneinfo/jiff"), |b| {
+            b.iter(|| {
+                let zdt = bb(STAMP).to_zoned(bb(&tz).clone());
+                assert_eq!(zdt.datetime(), expected);
+            })
+        });
+    }
+}
+
+/// Measures the time to convert many timestamps to `Zoned` values in the same
+/// time zone.
+///
+/// Since a `Zoned` owns its time zone, this requires a `TimeZone::clone()`
+/// per timestamp. This is compared with `TimeZone::to_datetime`, which
+/// borrows the time zone, to show the cost of that clone in a tight loop.
+fn to_zoned_many(c: &mut Criterion) {
+    const NAME: &str = "timestamp/to_zoned_many";
+    const COUNT: i64 = 10_000;
+
+    let stamps: Vec<Timestamp> = (0..COUNT)
+        .map(|i| Timestamp::from_second(1719755160 + i * 60).unwrap())
+        .collect();
+    let tz = TimeZone::get("America/New_York").unwrap();
+    {
+        benchmark(c, format!("{NAME}/zoned/jiff"), |b| {
+            b.iter(|| {
+                let mut count = 0;
+                for &ts in bb(&stamps) {
+                    let zdt = ts.to_zoned(bb(&tz).clone());
+                    count += usize::from(zdt.offset() == Offset::constant(-4));
+                }
+                assert_eq!(count, stamps.len());
+            })
+        });
+        benchmark(c, format!("{NAME}/datetime/jiff"), |b| {
+            b.iter(|| {
+                let mut count = 0;
+                for &ts in bb(&stamps) {
+                    let dt = bb(&tz).to_datetime(ts);
+                    count += usize::from(dt.year() == 2024);
+                }
+                assert_eq!(count, stamps.len());
+            })
+        });
+    }
+}
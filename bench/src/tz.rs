@@ -10,6 +10,9 @@ use jiff::{
 use crate::benchmark;
 
 pub(super) fn define(c: &mut Criterion) {
+    db_get_cached(c);
+    db_get_cached_many(c);
+    get_cached(c);
     posix_datetime_to_offset(c);
     posix_timestamp_to_offset(c);
     tzif_bundled_datetime_to_offset(c);
@@ -20,6 +23,95 @@ pub(super) fn define(c: &mut Criterion) {
     tzif_historical_timestamp_to_offset(c);
 }
 
+/// Measures how long it takes to look up a time zone by name in a time zone
+/// database when that time zone has already been loaded.
+///
+/// This is the common case for a long running program that repeatedly looks
+/// up the same handful of time zones. The TZif data is only parsed on the
+/// first lookup, and every lookup after that should be a cheap cache hit.
+fn db_get_cached(c: &mut Criterion) {
+    const NAME: &str = "tz/db_get_cached";
+    const TZ: &str = "America/New_York";
+
+    let db = TimeZoneDatabase::bundled();
+    // Warm the cache.
+    db.get(TZ).unwrap();
+    {
+        benchmark(c, format!("{NAME}/jiff"), |b| {
+            b.iter(|| {
+                let tz = bb(&db).get(bb(TZ)).unwrap();
+                assert_eq!(tz.iana_name(), Some(TZ));
+            })
+        });
+    }
+}
+
+/// Measures how long it takes to look up many different time zones by name
+/// in a time zone database when they have all already been loaded.
+///
+/// Unlike `db_get_cached`, this cycles through a list of time zones, so that
+/// each lookup has to find a different entry in the database's index of
+/// time zones. Lookups should remain cheap regardless of which time zone is
+/// being looked up.
+///
+/// This uses a zoneinfo database read from a directory (`TZDIR`, or
+/// `/usr/share/zoneinfo` if it isn't set), since that's where the index of
+/// time zone names lives. If the directory doesn't exist or lacks any of the
+/// time zones, then this benchmark is skipped.
+fn db_get_cached_many(c: &mut Criterion) {
+    const NAME: &str = "tz/db_get_cached_many";
+    const TZS: &[&str] = &[
+        "America/New_York",
+        "Europe/London",
+        "Asia/Tokyo",
+        "Australia/Sydney",
+        "America/Sao_Paulo",
+        "Africa/Cairo",
+        "Pacific/Auckland",
+        "Asia/Kolkata",
+    ];
+
+    let dir = std::env::var_os("TZDIR")
+        .unwrap_or_else(|| "/usr/share/zoneinfo".into());
+    let Ok(db) = TimeZoneDatabase::from_dir(dir) else { return };
+    // Warm the cache.
+    if !TZS.iter().all(|&tz| db.get(tz).is_ok()) {
+        return;
+    }
+    {
+        benchmark(c, format!("{NAME}/zoneinfo/jiff"), |b| {
+            b.iter(|| {
+                for &name in TZS {
+                    let tz = bb(&db).get(bb(name)).unwrap();
+                    assert_eq!(tz.iana_name(), Some(name));
+                }
+            })
+        });
+    }
+}
+
+/// Measures how long it takes to look up a time zone by name via
+/// `TimeZone::get` when that time zone has already been loaded.
+///
+/// A successful lookup like this shouldn't allocate. That's checked by a
+/// dedicated test in `tests/allocations.rs`, since counting allocations
+/// requires a global allocator that would skew every other benchmark.
+fn get_cached(c: &mut Criterion) {
+    const NAME: &str = "tz/get_cached";
+    const TZ: &str = "America/New_York";
+
+    // Warm the cache.
+    TimeZone::get(TZ).unwrap();
+    {
+        benchmark(c, format!("{NAME}/jiff"), |b| {
+            b.iter(|| {
+                let tz = TimeZone::get(bb(TZ)).unwrap();
+                assert_eq!(tz.iana_name(), Some(TZ));
+            })
+        });
+    }
+}
+
 /// Measures how long it takes to map a civil datetime to a possibly ambiguous
 /// timestamp using a POSIX time zone.
 fn posix_datetime_to_offset(c: &mut Criterion) {
@@ -0,0 +1,133 @@
+// This is a separate test target because counting allocations requires
+// installing a global allocator. Doing that in the benchmark binary would
+// add overhead to every benchmark.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    borrow::Cow,
+    cell::Cell,
+    hint::black_box as bb,
+};
+
+use jiff::{tz::TimeZone, Timestamp};
+
+/// Tests that looking up a time zone that has already been loaded doesn't
+/// allocate, regardless of whether the name is given as a `&str`, a `String`
+/// or a `Cow<str>`. The name should only be copied into the error when a
+/// lookup fails.
+#[test]
+fn time_zone_get_cached() {
+    const TZ: &str = "America/New_York";
+
+    let owned = String::from(TZ);
+    let cow: Cow<'_, str> = Cow::Borrowed(TZ);
+    // Warm the cache. Cached time zones are only revalidated after a TTL of
+    // several minutes, so none of the lookups below should hit the file
+    // system.
+    TimeZone::get(TZ).unwrap();
+
+    let count = allocations(|| {
+        for _ in 0..100 {
+            let tz = TimeZone::get(bb(TZ)).unwrap();
+            assert_eq!(tz.iana_name(), Some(TZ));
+            let tz = TimeZone::get(bb(&owned)).unwrap();
+            assert_eq!(tz.iana_name(), Some(TZ));
+            let tz = TimeZone::get(bb(&cow)).unwrap();
+            assert_eq!(tz.iana_name(), Some(TZ));
+        }
+    });
+    assert_eq!(count, 0, "successful time zone lookups should not allocate");
+
+    let count = allocations(|| {
+        assert!(TimeZone::get(bb("America/Nowhere")).is_err());
+    });
+    assert!(count > 0, "failed time zone lookups should allocate an error");
+}
+
+/// Tests that formatting timestamps and zoned datetimes into a reused buffer
+/// doesn't allocate, while `to_string` allocates a new `String` each time.
+#[test]
+fn format_into_reused_buffer() {
+    let ts = Timestamp::from_second(1_718_810_565).unwrap();
+    let zdt = ts.to_zoned(TimeZone::get("America/New_York").unwrap());
+
+    let mut buf = String::with_capacity(64);
+    let count = allocations(|| {
+        for _ in 0..100 {
+            buf.clear();
+            bb(&ts).format_into(&mut buf).unwrap();
+            assert_eq!(buf, "2024-06-19T15:22:45Z");
+            buf.clear();
+            bb(&zdt).format_into(&mut buf).unwrap();
+            assert_eq!(buf, "2024-06-19T11:22:45-04:00[America/New_York]");
+        }
+    });
+    assert_eq!(
+        count, 0,
+        "formatting into a reused buffer should not allocate"
+    );
+
+    let count = allocations(|| {
+        for _ in 0..100 {
+            bb(bb(&ts).to_string());
+            bb(bb(&zdt).to_string());
+        }
+    });
+    assert!(count >= 200, "to_string should allocate every time");
+}
+
+/// Returns the number of heap allocations made by the current thread while
+/// running the given closure.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(|c| c.get());
+    f();
+    ALLOCATIONS.with(|c| c.get()) - before
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// A global allocator that counts allocations made by each thread and
+/// otherwise defers to the system allocator.
+///
+/// Counting per thread means allocations made concurrently by the test
+/// harness don't cause spurious failures.
+struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count(&self) {
+        // `try_with` fails when the thread local has already been destroyed,
+        // which can happen for allocations made while a thread exits.
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        self.count();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
@@ -0,0 +1,392 @@
+/*!
+This crate provides conversion routines between [`jiff`] and
+[`chrono`](https://docs.rs/chrono/0.4).
+
+The conversion routines are implemented via conversion traits defined in this
+crate. The traits mirror the [`From`], [`Into`], [`TryFrom`] and [`TryInto`]
+traits from the standard library.
+
+The main use case for this crate is incremental migration. That is, a code
+base that uses `chrono` can start using Jiff in some places while converting
+values at the boundaries. It may also be useful when depending on a library
+that exposes `chrono` types in its public API.
+
+# Available conversions
+
+* [`chrono::DateTime<Tz>`](chrono::DateTime) fallibly converts to
+[`jiff::Timestamp`]. The reverse, to a `chrono::DateTime<Utc>`, is
+infallible.
+* [`chrono::DateTime<Tz>`](chrono::DateTime) fallibly converts to
+[`jiff::Zoned`]. The reverse, to a `chrono::DateTime<FixedOffset>`, is also
+fallible.
+
+Since a `chrono::DateTime<Tz>` can be generic over any `chrono` time zone
+implementation, converting it to a [`Zoned`](jiff::Zoned) always produces a
+zoned datetime with a fixed offset time zone. That is, only the offset of the
+`chrono` datetime is preserved. If you need a Jiff time zone with DST rules,
+then convert to a [`Timestamp`](jiff::Timestamp) and use
+[`Timestamp::in_tz`](jiff::Timestamp::in_tz).
+
+Going from `chrono` to Jiff is fallible because `chrono` supports a much
+larger range of years than Jiff does. Going from a `Zoned` to a `chrono`
+datetime is fallible because Jiff supports offsets up to `25:59:59` in
+magnitude, while `chrono` limits offsets to less than 24 hours.
+
+# Leap seconds
+
+`chrono` can represent leap seconds by using a fractional second value
+of `1_000_000_000` nanoseconds or greater. Jiff does not support leap
+seconds. Consistent with how Jiff parses leap seconds, a leap second in a
+`chrono` datetime is clamped to the last nanosecond of the preceding second.
+
+# Example: convert a `DateTime<Utc>` to a `Timestamp`
+
+```
+use jiff::Timestamp;
+use jiff_chrono::{ConvertFrom as _, ConvertTryFrom as _};
+
+let dt = chrono::DateTime::from_timestamp(1_718_414_250, 500_000_000).unwrap();
+let ts = Timestamp::convert_try_from(dt)?;
+assert_eq!(ts.to_string(), "2024-06-15T01:17:30.5Z");
+assert_eq!(chrono::DateTime::convert_from(ts), dt);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Example: convert a `Zoned` to a `DateTime<FixedOffset>`
+
+This shows that while the offset is preserved, the time zone is not:
+
+```
+use jiff::Zoned;
+use jiff_chrono::{ConvertTryFrom as _};
+
+let zdt: Zoned = "2024-06-14T21:17:30-04[America/New_York]".parse()?;
+let dt = chrono::DateTime::convert_try_from(&zdt)?;
+assert_eq!(dt.to_rfc3339(), "2024-06-14T21:17:30-04:00");
+
+let got = Zoned::convert_try_from(dt)?;
+assert_eq!(got.to_string(), "2024-06-14T21:17:30-04:00[-04:00]");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+#![no_std]
+#![deny(missing_docs)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+
+use chrono::{
+    DateTime as ChronoDateTime, FixedOffset as ChronoFixedOffset, Offset as _,
+    TimeZone as ChronoTimeZone, Utc as ChronoUtc,
+};
+use jiff::{
+    tz::{Offset as JiffOffset, TimeZone},
+    Timestamp as JiffTimestamp, Zoned as JiffZoned,
+};
+
+use self::error::err;
+pub use self::{
+    error::Error,
+    traits::{ConvertFrom, ConvertInto, ConvertTryFrom, ConvertTryInto},
+};
+
+mod error;
+mod traits;
+
+/// Converts from a [`chrono::DateTime<Tz>`](chrono::DateTime) to a
+/// [`jiff::Timestamp`].
+///
+/// The offset of the `chrono` datetime is used to determine the instant, but
+/// is otherwise dropped. A leap second is clamped to the last nanosecond of
+/// the preceding second.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of the range supported
+/// by [`jiff::Timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeZone as _;
+/// use jiff::Timestamp;
+/// use jiff_chrono::{ConvertTryFrom as _};
+///
+/// let dt = chrono::Utc.with_ymd_and_hms(2025, 1, 30, 22, 58, 30).unwrap();
+/// let ts = Timestamp::convert_try_from(dt)?;
+/// assert_eq!(ts.to_string(), "2025-01-30T22:58:30Z");
+///
+/// let offset = chrono::FixedOffset::west_opt(5 * 60 * 60).unwrap();
+/// let dt = offset.with_ymd_and_hms(2025, 1, 30, 17, 58, 30).unwrap();
+/// let ts = Timestamp::convert_try_from(dt)?;
+/// assert_eq!(ts.to_string(), "2025-01-30T22:58:30Z");
+///
+/// // chrono supports a much bigger range of years than Jiff.
+/// let dt = chrono::Utc.with_ymd_and_hms(10_000, 1, 1, 0, 0, 0).unwrap();
+/// assert!(Timestamp::convert_try_from(dt).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl<Tz: ChronoTimeZone> ConvertTryFrom<ChronoDateTime<Tz>> for JiffTimestamp {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: ChronoDateTime<Tz>,
+    ) -> Result<JiffTimestamp, Error> {
+        let second = v.timestamp();
+        let nanosecond = v.timestamp_subsec_nanos().min(999_999_999);
+        // OK because of the `min` above.
+        let nanosecond = i32::try_from(nanosecond).unwrap();
+        Ok(JiffTimestamp::new(second, nanosecond)?)
+    }
+}
+
+/// Converts from a [`jiff::Timestamp`] to a
+/// [`chrono::DateTime<Utc>`](chrono::DateTime).
+///
+/// This is infallible because every Jiff timestamp is representable as a
+/// `chrono` datetime.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use jiff_chrono::{ConvertFrom as _};
+///
+/// let ts: Timestamp = "2025-01-30T22:58:30.123456789Z".parse()?;
+/// let dt = chrono::DateTime::convert_from(ts);
+/// assert_eq!(dt.to_rfc3339(), "2025-01-30T22:58:30.123456789+00:00");
+///
+/// let dt = chrono::DateTime::convert_from(Timestamp::MIN);
+/// assert_eq!(dt.to_rfc3339(), "-9999-01-02T01:59:59+00:00");
+///
+/// let dt = chrono::DateTime::convert_from(Timestamp::MAX);
+/// assert_eq!(dt.to_rfc3339(), "9999-12-30T22:00:00.999999999+00:00");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertFrom<JiffTimestamp> for ChronoDateTime<ChronoUtc> {
+    fn convert_from(v: JiffTimestamp) -> ChronoDateTime<ChronoUtc> {
+        // OK because a timestamp's fractional second is always in the
+        // range `-999_999_999..=999_999_999`, and is only negative when the
+        // number of seconds is negative.
+        let (second, nanosecond) = if v.subsec_nanosecond() < 0 {
+            (v.as_second() - 1, 1_000_000_000 + v.subsec_nanosecond())
+        } else {
+            (v.as_second(), v.subsec_nanosecond())
+        };
+        let nanosecond = u32::try_from(nanosecond).unwrap();
+        // OK because `chrono` supports a strictly bigger range of
+        // timestamps than Jiff.
+        ChronoDateTime::from_timestamp(second, nanosecond).unwrap()
+    }
+}
+
+/// Converts from a [`chrono::DateTime<Tz>`](chrono::DateTime) to a
+/// [`jiff::Zoned`].
+///
+/// The zoned datetime returned always has a fixed offset time zone
+/// corresponding to the offset of the `chrono` datetime. In particular, the
+/// time zone rules of `Tz` are not carried over.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of the range supported
+/// by [`jiff::Timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::TimeZone as _;
+/// use jiff::Zoned;
+/// use jiff_chrono::{ConvertTryFrom as _};
+///
+/// let offset = chrono::FixedOffset::east_opt(5 * 60 * 60 + 30 * 60).unwrap();
+/// let dt = offset.with_ymd_and_hms(2025, 1, 30, 17, 58, 30).unwrap();
+/// let zdt = Zoned::convert_try_from(dt)?;
+/// assert_eq!(zdt.to_string(), "2025-01-30T17:58:30+05:30[+05:30]");
+///
+/// let dt = chrono::Utc.with_ymd_and_hms(2025, 1, 30, 17, 58, 30).unwrap();
+/// let zdt = Zoned::convert_try_from(dt)?;
+/// assert_eq!(zdt.to_string(), "2025-01-30T17:58:30+00:00[UTC]");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl<Tz: ChronoTimeZone> ConvertTryFrom<ChronoDateTime<Tz>> for JiffZoned {
+    type Error = Error;
+
+    fn convert_try_from(v: ChronoDateTime<Tz>) -> Result<JiffZoned, Error> {
+        let seconds = v.offset().fix().local_minus_utc();
+        let offset = JiffOffset::from_seconds(seconds)?;
+        let timestamp: JiffTimestamp = v.convert_try_into()?;
+        Ok(timestamp.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+/// Converts from a [`jiff::Zoned`] to a
+/// [`chrono::DateTime<FixedOffset>`](chrono::DateTime).
+///
+/// The `chrono` datetime returned uses the same offset as the zoned
+/// datetime. The time zone of the zoned datetime is dropped.
+///
+/// This is also implemented for `&Zoned`.
+///
+/// # Errors
+///
+/// This returns an error when the offset of the zoned datetime is not
+/// supported by `chrono`. Namely, `chrono` requires that offsets are less
+/// than 24 hours in magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{tz::{self, TimeZone}, Timestamp, Zoned};
+/// use jiff_chrono::{ConvertTryFrom as _};
+///
+/// let zdt: Zoned = "2024-11-03T01:30-05[America/New_York]".parse()?;
+/// let dt = chrono::DateTime::convert_try_from(zdt)?;
+/// assert_eq!(dt.to_rfc3339(), "2024-11-03T01:30:00-05:00");
+///
+/// let zdt = Timestamp::UNIX_EPOCH.to_zoned(TimeZone::fixed(tz::offset(25)));
+/// assert!(chrono::DateTime::convert_try_from(zdt).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<JiffZoned> for ChronoDateTime<ChronoFixedOffset> {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: JiffZoned,
+    ) -> Result<ChronoDateTime<ChronoFixedOffset>, Error> {
+        ChronoDateTime::convert_try_from(&v)
+    }
+}
+
+/// Converts from a [`&jiff::Zoned`](jiff::Zoned) to a
+/// [`chrono::DateTime<FixedOffset>`](chrono::DateTime).
+///
+/// This is like the conversion from an owned `Zoned`, but avoids needing to
+/// clone the zoned datetime.
+impl<'a> ConvertTryFrom<&'a JiffZoned> for ChronoDateTime<ChronoFixedOffset> {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: &'a JiffZoned,
+    ) -> Result<ChronoDateTime<ChronoFixedOffset>, Error> {
+        let seconds = v.offset().seconds();
+        let offset =
+            ChronoFixedOffset::east_opt(seconds).ok_or_else(|| {
+                err!("failed to convert Jiff offset of {seconds}s to `chrono`")
+            })?;
+        let utc: ChronoDateTime<ChronoUtc> = v.timestamp().convert_into();
+        Ok(utc.with_timezone(&offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use chrono::{TimeZone as _, Timelike as _};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip_utc() {
+        let values = [
+            ChronoUtc.with_ymd_and_hms(2024, 6, 14, 21, 17, 30).unwrap(),
+            ChronoUtc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+                + chrono::Duration::nanoseconds(999_999_999),
+            ChronoUtc.with_ymd_and_hms(-9999, 1, 2, 1, 59, 59).unwrap(),
+            ChronoUtc.with_ymd_and_hms(9999, 12, 30, 22, 0, 0).unwrap()
+                + chrono::Duration::nanoseconds(999_999_999),
+        ];
+        for dt in values {
+            let ts: JiffTimestamp = dt.convert_try_into().unwrap();
+            let got: ChronoDateTime<ChronoUtc> = ts.convert_into();
+            assert_eq!(dt, got);
+
+            let zdt: JiffZoned = dt.convert_try_into().unwrap();
+            assert_eq!(zdt.time_zone(), &TimeZone::UTC);
+            let got: ChronoDateTime<ChronoFixedOffset> =
+                zdt.convert_try_into().unwrap();
+            assert_eq!(dt, got);
+        }
+
+        for ts in [
+            JiffTimestamp::MIN,
+            JiffTimestamp::new(-1, -1).unwrap(),
+            JiffTimestamp::UNIX_EPOCH,
+            JiffTimestamp::MAX,
+        ] {
+            let dt: ChronoDateTime<ChronoUtc> = ts.convert_into();
+            let got: JiffTimestamp = dt.convert_try_into().unwrap();
+            assert_eq!(ts, got);
+        }
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset() {
+        let offsets =
+            [-86_399, -4 * 60 * 60, 0, 5 * 60 * 60 + 30 * 60, 86_399];
+        for seconds in offsets {
+            let offset = ChronoFixedOffset::east_opt(seconds).unwrap();
+            let dt = offset
+                .with_ymd_and_hms(2024, 3, 10, 2, 30, 0)
+                .unwrap()
+                .with_nanosecond(123_456_789)
+                .unwrap();
+            let zdt: JiffZoned = dt.convert_try_into().unwrap();
+            assert_eq!(zdt.offset().seconds(), seconds);
+            assert_eq!(
+                zdt.datetime(),
+                jiff::civil::date(2024, 3, 10).at(2, 30, 0, 123_456_789)
+            );
+            let got: ChronoDateTime<ChronoFixedOffset> =
+                (&zdt).convert_try_into().unwrap();
+            assert_eq!(dt, got);
+            assert_eq!(dt.offset(), got.offset());
+        }
+
+        let zdt: JiffZoned =
+            "2024-03-10T03:30:00-04[America/New_York]".parse().unwrap();
+        let dt: ChronoDateTime<ChronoFixedOffset> =
+            (&zdt).convert_try_into().unwrap();
+        let got: JiffZoned = dt.convert_try_into().unwrap();
+        assert_eq!(zdt.timestamp(), got.timestamp());
+        assert_eq!(zdt.datetime(), got.datetime());
+        assert_eq!(got.time_zone(), &TimeZone::fixed(jiff::tz::offset(-4)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let dt = ChronoUtc.with_ymd_and_hms(10_000, 1, 1, 0, 0, 0).unwrap();
+        assert!(JiffTimestamp::convert_try_from(dt).is_err());
+        assert!(JiffZoned::convert_try_from(dt).is_err());
+
+        let dt = ChronoUtc.with_ymd_and_hms(-9999, 1, 1, 0, 0, 0).unwrap();
+        assert!(JiffTimestamp::convert_try_from(dt).is_err());
+        assert!(JiffZoned::convert_try_from(dt).is_err());
+
+        let offset = JiffOffset::from_seconds(86_400).unwrap();
+        let zdt = JiffTimestamp::UNIX_EPOCH.to_zoned(TimeZone::fixed(offset));
+        assert!(ChronoDateTime::convert_try_from(zdt).is_err());
+    }
+
+    #[test]
+    fn leap_second_is_clamped() {
+        let dt = ChronoUtc
+            .with_ymd_and_hms(2016, 12, 31, 23, 59, 59)
+            .unwrap()
+            .with_nanosecond(1_500_000_000)
+            .unwrap();
+        let ts: JiffTimestamp = dt.convert_try_into().unwrap();
+        assert_eq!(ts.to_string(), "2016-12-31T23:59:59.999999999Z");
+    }
+}
@@ -4,6 +4,7 @@ use crate::args;
 
 mod crc32;
 mod shared;
+mod tz_links;
 mod tzdb;
 mod unit_designator_match;
 mod windows_zones;
@@ -20,6 +21,7 @@ COMMANDS:
     crc32                  Generate CRC32 data tables.
     jiff-tzdb              Generate Rust source code from TZif data for jiff-tzdb
     shared                 Copy shared code from Jiff to jiff-static proc macro
+    tz-links               Generate mapping of IANA time zone links to targets.
     unit-designator-match  Generate Rust `match` expression for parsing unit labels
     windows-zones          Generate mapping Windows TZ names to IANA names.
     zoneinfo               Generate TZif data for jiff-tzdb
@@ -30,6 +32,7 @@ pub fn run(p: &mut lexopt::Parser) -> anyhow::Result<()> {
         "crc32" => crc32::run(p),
         "jiff-tzdb" => tzdb::run(p),
         "shared" => shared::run(p),
+        "tz-links" => tz_links::run(p),
         "unit-designator-match" => unit_designator_match::run(p),
         "windows-zones" => windows_zones::run(p),
         "zoneinfo" => zoneinfo::run(p),
@@ -0,0 +1,182 @@
+/*!
+A command for generating Rust source files from a `tzdata.zi` file.
+
+The IANA Time Zone Database has the notion of "links," which are aliases from
+one time zone identifier to another. For example, `US/Eastern` is a link to
+`America/New_York`. Unfortunately, compiled TZif data doesn't record which
+identifiers are links. On some systems, links are represented as symlinks in
+`/usr/share/zoneinfo`, but on others they are hard links or even plain copies.
+And in the case of `jiff-tzdb`, links just share the same TZif data as their
+target (along with any other zone with identical data).
+
+So in order to reliably map an alias back to its canonical identifier, we
+generate a static table of links from `tzdata.zi`. This file is a compact
+version of the zic input files that is produced as part of every IANA Time
+Zone Database release, and it lists every link on a line starting with `L`.
+*/
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use lexopt::{Arg, Parser};
+
+use crate::args::{self, Usage};
+
+const USAGE: &'static str = r#"
+Generate Rust source code from a tzdata.zi file.
+
+USAGE:
+    jiff-cli generate tz-links <path/to/tzdata.zi> [<jiff-dir>]
+
+This command generates the requisite Rust source code for mapping IANA time
+zone links (aliases) to their canonical IANA time zone identifiers.
+
+A `tzdata.zi` file is usually installed at `/usr/share/zoneinfo/tzdata.zi`.
+It is also generated by running `make tzdata.zi` in a checkout of the IANA
+Time Zone Database.
+
+This program should be run from the root of the Jiff repository. Alternatively,
+provide a path to the root of the Jiff repository as a second positional
+argument.
+"#;
+
+pub fn run(p: &mut Parser) -> anyhow::Result<()> {
+    let mut config = Config::default();
+    args::configure(p, USAGE, &mut [&mut config])?;
+
+    let tzdata = config.tzdata()?;
+    let jiff = config.jiff();
+
+    let zi = std::fs::read_to_string(tzdata).with_context(|| {
+        format!("failed to read string from {}", tzdata.display())
+    })?;
+
+    let version = zi
+        .lines()
+        .find_map(|line| line.strip_prefix("# version "))
+        .context("failed to find version in tzdata.zi")?
+        .trim();
+    let mut map: BTreeMap<String, Link> = BTreeMap::new();
+    for line in zi.lines() {
+        let Some(rest) = line.strip_prefix("L ") else { continue };
+        let mut fields = rest.split_whitespace();
+        let (Some(target), Some(name), None) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            anyhow::bail!("found invalid link line: {line:?}");
+        };
+        let name_lower = name.to_ascii_lowercase();
+        let link = Link { name: name.to_string(), target: target.to_string() };
+        anyhow::ensure!(
+            map.insert(name_lower, link).is_none(),
+            "found duplicate link name {name}",
+        );
+    }
+    anyhow::ensure!(!map.is_empty(), "found no links in tzdata.zi");
+
+    let links_path = jiff.join("src/tz/links.rs");
+    write_links(&links_path, version, &map).with_context(|| {
+        format!("failed to write time zone links to {}", links_path.display())
+    })?;
+    super::rustfmt(&links_path)?;
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Link {
+    name: String,
+    target: String,
+}
+
+#[derive(Debug)]
+struct Config {
+    tzdata: Option<PathBuf>,
+    jiff: Option<PathBuf>,
+    verbose: bool,
+}
+
+impl Config {
+    fn tzdata(&self) -> anyhow::Result<&Path> {
+        self.tzdata.as_deref().context("missing path to tzdata.zi file")
+    }
+
+    fn jiff(&self) -> &Path {
+        self.jiff.as_deref().unwrap_or_else(|| Path::new("./"))
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { tzdata: None, jiff: None, verbose: false }
+    }
+}
+
+impl args::Configurable for Config {
+    fn configure(
+        &mut self,
+        _: &mut Parser,
+        arg: &mut Arg,
+    ) -> anyhow::Result<bool> {
+        match *arg {
+            Arg::Short('v') | Arg::Long("verbose") => {
+                self.verbose = true;
+            }
+            Arg::Value(ref mut value) => {
+                if self.tzdata.is_none() {
+                    let path = PathBuf::from(std::mem::take(value));
+                    self.tzdata = Some(path);
+                } else if self.jiff.is_none() {
+                    let path = PathBuf::from(std::mem::take(value));
+                    self.jiff = Some(path);
+                } else {
+                    return Ok(false);
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn usage(&self) -> &[Usage] {
+        const USAGES: &'static [Usage] = &[Usage::new(
+            "-v, --verbose",
+            "Add more output.",
+            r#"
+This is a generic flag that expands output beyond the "normal" amount. Which
+output is added depends on the command.
+"#,
+        )];
+        USAGES
+    }
+}
+
+fn write_links(
+    path: &Path,
+    version: &str,
+    links: &BTreeMap<String, Link>,
+) -> anyhow::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(
+        out,
+        "// Generated from version {version} of the IANA Time Zone Database."
+    )?;
+    writeln!(
+        out,
+        "pub(super) static LINK_TO_CANONICAL: &[(&str, &str)] = &["
+    )?;
+    for (_, link) in links {
+        let Link { name, target } = link;
+        writeln!(out, r#"    (r"{name}", r"{target}"),"#).with_context(
+            || format!("failed to write time zone link for {name}"),
+        )?;
+    }
+    writeln!(out, "];")?;
+    out.flush()?;
+    Ok(())
+}
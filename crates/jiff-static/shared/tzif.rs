@@ -211,12 +211,27 @@ impl TzifOwned {
         // trigger for any times before the first transition found in the TZif
         // data.
         self.transitions.add_with_type_index(TIMESTAMP_MIN, 0);
+        let mut prev: Option<i64> = None;
         while let Some(chunk) = it.next() {
             let mut timestamp = if header.is_32bit() {
                 i64::from(from_be_bytes_i32(chunk))
             } else {
                 from_be_bytes_i64(chunk)
             };
+            // RFC 8536 requires transition times to be sorted in strictly
+            // ascending order. Lookups rely on this (via binary search), so
+            // if it isn't true, we'd silently return wrong offsets.
+            if let Some(prev) = prev {
+                if timestamp <= prev {
+                    return Err(err!(
+                        "found transition at Unix timestamp {timestamp} \
+                         that does not come after the previous transition \
+                         at Unix timestamp {prev}, but transitions must be \
+                         in strictly ascending order",
+                    ));
+                }
+            }
+            prev = Some(timestamp);
             if !(TIMESTAMP_MIN <= timestamp && timestamp <= TIMESTAMP_MAX) {
                 // We really shouldn't error here just because the Unix
                 // timestamp is outside what Jiff supports. Since what Jiff
@@ -253,7 +268,7 @@ impl TzifOwned {
         for (transition_index, &type_index) in (1..).zip(bytes) {
             if usize::from(type_index) >= header.tzh_typecnt {
                 return Err(err!(
-                    "found transition type index {type_index},
+                    "found transition type index {type_index}, \
                      but there are only {} local time types",
                     header.tzh_typecnt,
                 ));
@@ -0,0 +1,343 @@
+/*!
+This crate provides conversion routines between [`jiff`] and
+[`time`](https://docs.rs/time/0.3).
+
+The conversion routines are implemented via conversion traits defined in this
+crate. The traits mirror the [`From`], [`Into`], [`TryFrom`] and [`TryInto`]
+traits from the standard library.
+
+The main use case for this crate is incremental migration. That is, a code
+base that uses `time` can start using Jiff in some places while converting
+values at the boundaries. It may also be useful when depending on a library
+that exposes `time` types in its public API.
+
+# Available conversions
+
+* [`time::OffsetDateTime`] fallibly converts to [`jiff::Zoned`]. The reverse
+is also fallible.
+* [`time::OffsetDateTime`] fallibly converts to [`jiff::Timestamp`]. The
+reverse is also fallible.
+
+Since an [`OffsetDateTime`](time::OffsetDateTime) only has an offset (and no
+time zone), converting it to a [`Zoned`](jiff::Zoned) always produces a zoned
+datetime with a fixed offset time zone. Converting a `Zoned` to an
+`OffsetDateTime` preserves its offset, but necessarily drops its time zone.
+
+All of the conversions are fallible because the two crates do not support
+the same range of values. For example, `time` supports
+`9999-12-31T23:59:59Z`, but Jiff does not. Conversely, when `time`'s
+`large-dates` feature is enabled, it supports years far outside of Jiff's
+range.
+
+# Example: convert an `OffsetDateTime` to a `Zoned`
+
+```
+use jiff::Zoned;
+use jiff_time::{ConvertTryFrom as _};
+use time::macros::datetime;
+
+let odt = datetime!(2024-06-14 21:17:30.5 -04:00);
+let zdt = Zoned::convert_try_from(odt)?;
+assert_eq!(zdt.to_string(), "2024-06-14T21:17:30.5-04:00[-04:00]");
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+# Example: convert a `Zoned` to an `OffsetDateTime`
+
+This shows that while the offset is preserved, the time zone is not:
+
+```
+use jiff::Zoned;
+use jiff_time::{ConvertTryFrom as _};
+use time::{macros::datetime, OffsetDateTime};
+
+let zdt: Zoned = "2024-06-14T21:17:30-04[America/New_York]".parse()?;
+let odt = OffsetDateTime::convert_try_from(&zdt)?;
+assert_eq!(odt, datetime!(2024-06-14 21:17:30 -04:00));
+assert_eq!(odt.offset().whole_hours(), -4);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+#![no_std]
+#![deny(missing_docs)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+
+use jiff::{
+    tz::{Offset as JiffOffset, TimeZone},
+    Timestamp as JiffTimestamp, Zoned as JiffZoned,
+};
+use time::{OffsetDateTime as TimeOffsetDateTime, UtcOffset as TimeUtcOffset};
+
+use self::error::err;
+pub use self::{
+    error::Error,
+    traits::{ConvertFrom, ConvertInto, ConvertTryFrom, ConvertTryInto},
+};
+
+mod error;
+mod traits;
+
+/// Converts from a [`time::OffsetDateTime`] to a [`jiff::Zoned`].
+///
+/// The zoned datetime returned always has a fixed offset time zone
+/// corresponding to the offset on the `OffsetDateTime`.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of the range supported
+/// by [`jiff::Timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use jiff_time::{ConvertTryFrom as _};
+/// use time::macros::datetime;
+///
+/// let odt = datetime!(2025-01-30 17:58:30.123456789 +05:30);
+/// let zdt = Zoned::convert_try_from(odt)?;
+/// assert_eq!(
+///     zdt.to_string(),
+///     "2025-01-30T17:58:30.123456789+05:30[+05:30]",
+/// );
+///
+/// let odt = datetime!(1970-01-01 00:00 UTC);
+/// let zdt = Zoned::convert_try_from(odt)?;
+/// assert_eq!(zdt.to_string(), "1970-01-01T00:00:00+00:00[UTC]");
+///
+/// // Jiff's maximum timestamp is a bit smaller than the one in `time`.
+/// let odt = datetime!(9999-12-31 23:59:59 UTC);
+/// assert!(Zoned::convert_try_from(odt).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<TimeOffsetDateTime> for JiffZoned {
+    type Error = Error;
+
+    fn convert_try_from(v: TimeOffsetDateTime) -> Result<JiffZoned, Error> {
+        let timestamp: JiffTimestamp = v.convert_try_into()?;
+        let offset = JiffOffset::from_seconds(v.offset().whole_seconds())?;
+        Ok(timestamp.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+/// Converts from a [`jiff::Zoned`] to a [`time::OffsetDateTime`].
+///
+/// The `OffsetDateTime` returned uses the same offset as the zoned
+/// datetime. The time zone of the zoned datetime is dropped.
+///
+/// This is also implemented for `&Zoned`.
+///
+/// # Errors
+///
+/// This returns an error when the datetime is outside of the range
+/// supported by `time::OffsetDateTime`.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use jiff_time::{ConvertTryFrom as _};
+/// use time::{macros::datetime, OffsetDateTime};
+///
+/// let zdt: Zoned = "2024-11-03T01:30-05[America/New_York]".parse()?;
+/// let odt = OffsetDateTime::convert_try_from(zdt)?;
+/// assert_eq!(odt, datetime!(2024-11-03 01:30 -05:00));
+/// assert_eq!(odt.offset().whole_hours(), -5);
+///
+/// let zdt = jiff::Timestamp::MIN.to_zoned(jiff::tz::TimeZone::UTC);
+/// let odt = OffsetDateTime::convert_try_from(zdt)?;
+/// assert_eq!(odt, datetime!(-9999-01-02 01:59:59 UTC));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<JiffZoned> for TimeOffsetDateTime {
+    type Error = Error;
+
+    fn convert_try_from(v: JiffZoned) -> Result<TimeOffsetDateTime, Error> {
+        TimeOffsetDateTime::convert_try_from(&v)
+    }
+}
+
+/// Converts from a [`&jiff::Zoned`](jiff::Zoned) to a
+/// [`time::OffsetDateTime`].
+///
+/// This is like the conversion from an owned `Zoned`, but avoids needing to
+/// clone the zoned datetime.
+impl<'a> ConvertTryFrom<&'a JiffZoned> for TimeOffsetDateTime {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: &'a JiffZoned,
+    ) -> Result<TimeOffsetDateTime, Error> {
+        let seconds = v.offset().seconds();
+        let offset =
+            TimeUtcOffset::from_whole_seconds(seconds).map_err(|_| {
+                err!("failed to convert Jiff offset of {seconds}s to `time`")
+            })?;
+        let odt: TimeOffsetDateTime = v.timestamp().convert_try_into()?;
+        odt.checked_to_offset(offset).ok_or_else(|| {
+            err!(
+                "converting Jiff zoned datetime {v} to `time` \
+                 overflowed `time`'s supported range",
+            )
+        })
+    }
+}
+
+/// Converts from a [`time::OffsetDateTime`] to a [`jiff::Timestamp`].
+///
+/// The offset on the `OffsetDateTime` is used to determine the instant, but
+/// is otherwise dropped.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of the range supported
+/// by [`jiff::Timestamp`].
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use jiff_time::{ConvertTryFrom as _};
+/// use time::macros::datetime;
+///
+/// let odt = datetime!(2025-01-30 17:58:30 -05:00);
+/// let ts = Timestamp::convert_try_from(odt)?;
+/// assert_eq!(ts.to_string(), "2025-01-30T22:58:30Z");
+///
+/// let odt = datetime!(-9999-01-01 00:00 UTC);
+/// assert!(Timestamp::convert_try_from(odt).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<TimeOffsetDateTime> for JiffTimestamp {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: TimeOffsetDateTime,
+    ) -> Result<JiffTimestamp, Error> {
+        Ok(JiffTimestamp::from_nanosecond(v.unix_timestamp_nanos())?)
+    }
+}
+
+/// Converts from a [`jiff::Timestamp`] to a [`time::OffsetDateTime`].
+///
+/// The `OffsetDateTime` returned is always in UTC.
+///
+/// # Errors
+///
+/// This returns an error when the instant is outside of the range
+/// supported by `time::OffsetDateTime`. With `time`'s default
+/// configuration, this never happens, since every Jiff timestamp is
+/// representable.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use jiff_time::{ConvertTryFrom as _};
+/// use time::{macros::datetime, OffsetDateTime};
+///
+/// let ts: Timestamp = "2025-01-30T22:58:30.5Z".parse()?;
+/// let odt = OffsetDateTime::convert_try_from(ts)?;
+/// assert_eq!(odt, datetime!(2025-01-30 22:58:30.5 UTC));
+///
+/// let odt = OffsetDateTime::convert_try_from(Timestamp::MAX)?;
+/// assert_eq!(odt, datetime!(9999-12-30 22:00:00.999999999 UTC));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl ConvertTryFrom<JiffTimestamp> for TimeOffsetDateTime {
+    type Error = Error;
+
+    fn convert_try_from(
+        v: JiffTimestamp,
+    ) -> Result<TimeOffsetDateTime, Error> {
+        TimeOffsetDateTime::from_unix_timestamp_nanos(v.as_nanosecond())
+            .map_err(|_| {
+                err!(
+                    "converting Jiff timestamp {v} to `time` \
+                     overflowed `time`'s supported range",
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip_offset_date_time() {
+        let values = [
+            datetime!(2024-06-14 21:17:30.123456789 -04:00),
+            datetime!(1969-12-31 23:59:59.999999999 UTC),
+            datetime!(-0001-02-28 12:00 +25:59:59),
+            datetime!(2000-01-01 00:00 -25:59:59),
+        ];
+        for odt in values {
+            let zdt: JiffZoned = odt.convert_try_into().unwrap();
+            assert_eq!(zdt.offset().seconds(), odt.offset().whole_seconds());
+            let got: TimeOffsetDateTime = zdt.convert_try_into().unwrap();
+            assert_eq!(odt, got);
+            assert_eq!(odt.offset(), got.offset());
+
+            let ts: JiffTimestamp = odt.convert_try_into().unwrap();
+            let got: TimeOffsetDateTime = ts.convert_try_into().unwrap();
+            assert_eq!(odt, got);
+            assert!(got.offset().is_utc());
+        }
+    }
+
+    #[test]
+    fn roundtrip_jiff() {
+        let zdt: JiffZoned =
+            "2024-03-10T03:30:00.5-04[America/New_York]".parse().unwrap();
+        let odt: TimeOffsetDateTime = (&zdt).convert_try_into().unwrap();
+        let got: JiffZoned = odt.convert_try_into().unwrap();
+        assert_eq!(zdt.timestamp(), got.timestamp());
+        assert_eq!(zdt.datetime(), got.datetime());
+        assert_eq!(got.time_zone(), &TimeZone::fixed(jiff::tz::offset(-4)));
+
+        for ts in
+            [JiffTimestamp::MIN, JiffTimestamp::UNIX_EPOCH, JiffTimestamp::MAX]
+        {
+            let odt: TimeOffsetDateTime = ts.convert_try_into().unwrap();
+            let got: JiffTimestamp = odt.convert_try_into().unwrap();
+            assert_eq!(ts, got);
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let odt = datetime!(9999-12-31 23:59:59 UTC);
+        assert!(JiffTimestamp::convert_try_from(odt).is_err());
+        assert!(JiffZoned::convert_try_from(odt).is_err());
+
+        let odt = datetime!(-9999-01-01 00:00 UTC);
+        assert!(JiffTimestamp::convert_try_from(odt).is_err());
+        assert!(JiffZoned::convert_try_from(odt).is_err());
+
+        // Every Jiff zoned datetime is representable in `time`, even at the
+        // extremes of Jiff's range.
+        let offset = JiffOffset::from_seconds(-93599).unwrap();
+        let zdt = JiffTimestamp::MIN.to_zoned(TimeZone::fixed(offset));
+        let odt = TimeOffsetDateTime::convert_try_from(&zdt).unwrap();
+        assert_eq!(odt, datetime!(-9999-01-01 00:00 -25:59:59));
+        let offset = JiffOffset::from_seconds(93599).unwrap();
+        let zdt = JiffTimestamp::MAX.to_zoned(TimeZone::fixed(offset));
+        let odt = TimeOffsetDateTime::convert_try_from(&zdt).unwrap();
+        assert_eq!(odt.date(), time::macros::date!(9999 - 12 - 31));
+    }
+}
@@ -31,6 +31,30 @@ use crate::{
 /// units of time (such as with [`Date::checked_add`]), days are considered to
 /// always be precisely `86,400` seconds long.
 ///
+/// # Year numbering
+///
+/// Years in this crate use astronomical year numbering in the proleptic
+/// Gregorian calendar. That is, the Gregorian calendar is extended backwards
+/// to dates before it was introduced, and the year before `1` is `0`, the
+/// year before that is `-1` and so on. Years in the common era (`CE`) have
+/// the same number in both schemes. But since there is no year `0` when years
+/// are written with an era, the year `0` in this crate is `1 BCE`, the year
+/// `-1` is `2 BCE` and so on.
+///
+/// Use [`Date::era_year`] or [`Date::era`] to get the year of a date with its
+/// [`Era`], and [`Date::from_era_year`] to create a date from a year written
+/// with an era:
+///
+/// ```
+/// use jiff::civil::{Date, Era, date};
+///
+/// let d = Date::from_era_year(44, Era::BCE, 3, 15)?;
+/// assert_eq!(d, date(-43, 3, 15));
+/// assert_eq!(d.era_year(), (44, Era::BCE));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// # Parsing and printing
 ///
 /// The `Date` type provides convenient trait implementations of
@@ -375,6 +399,66 @@ impl Date {
         Date::from_unix_epoch_day(days)
     }
 
+    /// Creates a new `Date` from a year in the given era, a month and a day.
+    ///
+    /// This is the inverse of [`Date::era_year`]. Years in the `CE` era must
+    /// be in the range `1..=9999` and correspond directly to the year of the
+    /// date returned. Years in the `BCE` era must be in the range
+    /// `1..=10000`, where `1 BCE` corresponds to the year `0`, `2 BCE`
+    /// corresponds to the year `-1` and so on. See the
+    /// [year numbering](Date#year-numbering) section for more details.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the year is out of range for its era, or
+    /// when the given year-month-day does not correspond to a valid date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Date, Era, date};
+    ///
+    /// let d = Date::from_era_year(2024, Era::CE, 3, 9)?;
+    /// assert_eq!(d, date(2024, 3, 9));
+    ///
+    /// let d = Date::from_era_year(1, Era::BCE, 3, 9)?;
+    /// assert_eq!(d, date(0, 3, 9));
+    ///
+    /// let d = Date::from_era_year(2, Era::BCE, 3, 9)?;
+    /// assert_eq!(d, date(-1, 3, 9));
+    ///
+    /// // There is no year 0 when writing years with an era.
+    /// assert!(Date::from_era_year(0, Era::CE, 3, 9).is_err());
+    /// assert!(Date::from_era_year(0, Era::BCE, 3, 9).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: leap years
+    ///
+    /// Since the year `0` is a leap year in the proleptic Gregorian calendar,
+    /// so is `1 BCE`:
+    ///
+    /// ```
+    /// use jiff::civil::{Date, Era};
+    ///
+    /// assert!(Date::from_era_year(1, Era::BCE, 2, 29).is_ok());
+    /// assert!(Date::from_era_year(2, Era::BCE, 2, 29).is_err());
+    /// assert!(Date::from_era_year(5, Era::BCE, 2, 29).is_ok());
+    /// ```
+    #[inline]
+    pub fn from_era_year(
+        year: i16,
+        era: Era,
+        month: i8,
+        day: i8,
+    ) -> Result<Date, Error> {
+        let year = year_from_era(year, era)?;
+        let month = Month::try_new("month", month)?;
+        let day = Day::try_new("day", day)?;
+        Date::new_ranged(year, month, day)
+    }
+
     /// Create a builder for constructing a `Date` from the fields of this
     /// date.
     ///
@@ -484,6 +568,30 @@ impl Date {
         }
     }
 
+    /// Returns the era of the year of this date.
+    ///
+    /// This is `Era::CE` for years greater than `0` and `Era::BCE` otherwise.
+    /// It is equivalent to the era returned by [`Date::era_year`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Era, date};
+    ///
+    /// assert_eq!(date(2024, 10, 3).era(), Era::CE);
+    /// assert_eq!(date(1, 1, 1).era(), Era::CE);
+    /// assert_eq!(date(0, 12, 31).era(), Era::BCE);
+    /// assert_eq!(date(-1, 10, 3).era(), Era::BCE);
+    /// ```
+    #[inline]
+    pub fn era(self) -> Era {
+        if self.year() >= 1 {
+            Era::CE
+        } else {
+            Era::BCE
+        }
+    }
+
     /// Returns the month for this date.
     ///
     /// The value returned is guaranteed to be in the range `1..=12`.
@@ -754,6 +862,51 @@ impl Date {
         itime::is_leap_year(self.year_ranged().get())
     }
 
+    /// Returns this date unchanged if its year is within the given inclusive
+    /// range, and an error otherwise.
+    ///
+    /// This is a convenience for validating that a date falls within the
+    /// years supported by some particular domain, where that range is
+    /// narrower than what Jiff supports. The error returned is the same kind
+    /// of error that Jiff returns when a value is outside the range that
+    /// Jiff itself supports.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the year of this date is less than `min`
+    /// or greater than `max`. In particular, if `min > max`, then this
+    /// always returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let d = date(2024, 7, 15);
+    /// assert_eq!(d.require_year_between(1900, 2100)?, d);
+    ///
+    /// let d = date(1850, 1, 1);
+    /// assert_eq!(
+    ///     d.require_year_between(1900, 2100).unwrap_err().to_string(),
+    ///     "parameter 'year' with value 1850 is not \
+    ///      in the required range of 1900..=2100",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn require_year_between(
+        self,
+        min: i16,
+        max: i16,
+    ) -> Result<Date, Error> {
+        let year = self.year();
+        if !(min <= year && year <= max) {
+            return Err(Error::range("year", year, min, max));
+        }
+        Ok(self)
+    }
+
     /// Returns the date immediately following this one.
     ///
     /// # Errors
@@ -894,19 +1047,38 @@ impl Date {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// Since any date in the month can be used, rules like "the third
+    /// Thursday of November" can be computed for a particular year by
+    /// starting from the first day of the month:
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    ///
+    /// let thanksgiving = |year: i16| {
+    ///     date(year, 11, 1).nth_weekday_of_month(4, Weekday::Thursday)
+    /// };
+    /// assert_eq!(thanksgiving(2024)?, date(2024, 11, 28));
+    /// assert_eq!(thanksgiving(2025)?, date(2025, 11, 27));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[inline]
     pub fn nth_weekday_of_month(
         self,
         nth: i8,
         weekday: Weekday,
     ) -> Result<Date, Error> {
+        // We don't use `to_idate` here, since in debug mode, that would
+        // also compute the result for the minimum and maximum dates. And
+        // whether the "nth" weekday exists for those dates has nothing to
+        // do with whether it exists for this date.
         let weekday = weekday.to_iweekday();
         let idate = self
-            .to_idate()
-            .map(|x| x.nth_weekday_of_month(nth, weekday))
-            .transpose()
+            .to_idate_const()
+            .nth_weekday_of_month(nth, weekday)
             .map_err(Error::shared)?;
-        Ok(Date::from_idate(idate))
+        Ok(Date::from_idate_const(idate))
     }
 
     /// Returns the "nth" weekday from this date, not including itself.
@@ -1164,6 +1336,51 @@ impl Date {
             .expect("all Dates infallibly convert to ISOWeekDates")
     }
 
+    /// Returns this date formatted as an [ISO 8601 week date] string.
+    ///
+    /// The format used is `YYYY-Www-D`, where `YYYY` is the ISO week year,
+    /// `ww` is the week number and `D` is the weekday as a number from `1`
+    /// (Monday) to `7` (Sunday). Note that the ISO week year can differ from
+    /// the Gregorian year of this date near the start or end of a year.
+    ///
+    /// This is equivalent to `date.iso_week_date().to_string()`. To parse
+    /// an ISO 8601 week date string, parse it as an [`ISOWeekDate`] and
+    /// convert it to a `Date` with [`Date::from_iso_week_date`].
+    ///
+    /// Since `Date::from_iso_week_date` is infallible and accepts an
+    /// `ISOWeekDate`, there is no `Date` constructor that accepts a year,
+    /// week and weekday directly. Use [`ISOWeekDate::new`] for that instead.
+    /// It returns a range error for invalid weeks, such as week `53` of a
+    /// year with only 52 weeks.
+    ///
+    /// [ISO 8601 week date]: https://en.wikipedia.org/wiki/ISO_week_date
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Date, ISOWeekDate, Weekday, date};
+    ///
+    /// assert_eq!(date(2024, 1, 31).to_iso_week_string(), "2024-W05-3");
+    /// // The ISO week year differs from the Gregorian year here.
+    /// assert_eq!(date(2019, 12, 30).to_iso_week_string(), "2020-W01-1");
+    /// assert_eq!(date(2021, 1, 3).to_iso_week_string(), "2020-W53-7");
+    ///
+    /// let weekdate: ISOWeekDate = "2020-W53-7".parse()?;
+    /// assert_eq!(Date::from_iso_week_date(weekdate), date(2021, 1, 3));
+    ///
+    /// let weekdate = ISOWeekDate::new(2024, 5, Weekday::Wednesday)?;
+    /// assert_eq!(Date::from_iso_week_date(weekdate), date(2024, 1, 31));
+    /// // 2021 only has 52 weeks.
+    /// assert!(ISOWeekDate::new(2021, 53, Weekday::Monday).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_iso_week_string(&self) -> alloc::string::String {
+        DEFAULT_DATETIME_PRINTER.iso_week_date_to_string(&self.iso_week_date())
+    }
+
     /// Converts a civil date to a [`Zoned`] datetime by adding the given
     /// time zone and setting the clock time to midnight.
     ///
@@ -1928,6 +2145,85 @@ impl Date {
         SignedDuration::date_until(other, self)
     }
 
+    /// Returns the calendar difference from this date until `end` as a
+    /// number of whole years, months and days.
+    ///
+    /// This is the breakdown one usually wants when computing someone's age
+    /// or tenure, e.g., "3 years, 2 months and 5 days." It is equivalent to
+    /// calling [`Date::until`] with [`Unit::Year`] as the largest unit and
+    /// extracting the years, months and days from the span returned. In
+    /// particular, months are counted first by their calendar position, and
+    /// the remaining days are borrowed from the month preceding `end`.
+    ///
+    /// When `end` is before this date, all non-zero components returned are
+    /// negative. Their magnitudes may differ from the difference computed in
+    /// the other direction, since the days may be counted in a different
+    /// month.
+    ///
+    /// The months returned are always in the range `-11..=11` and the days
+    /// are always in the range `-30..=30`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let birthday = date(1990, 7, 19);
+    /// let today = date(2024, 3, 5);
+    /// assert_eq!(birthday.years_months_days_until(today), (33, 7, 15));
+    /// // In reverse, every component is negative. Note though that the
+    /// // days differ, since they're borrowed from a different month.
+    /// assert_eq!(today.years_months_days_until(birthday), (-33, -7, -17));
+    /// ```
+    ///
+    /// # Example: borrowing days across month boundaries
+    ///
+    /// Months are counted by adding them to this date. When the month
+    /// reached is too short to contain the day of this date, the day is
+    /// clamped to the last day of that month, and the remaining days are
+    /// counted from there. So dates that are a different number of days
+    /// apart can still have the same difference:
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// // January 31 plus one month is clamped to February 28, which is one
+    /// // day before March 1. These dates are 29 days apart.
+    /// let d = date(2023, 1, 31).years_months_days_until(date(2023, 3, 1));
+    /// assert_eq!(d, (0, 1, 1));
+    /// // January 30 plus one month is clamped to February 29 in a leap
+    /// // year, which is also one day before March 1. But these dates are 31
+    /// // days apart.
+    /// let d = date(2024, 1, 30).years_months_days_until(date(2024, 3, 1));
+    /// assert_eq!(d, (0, 1, 1));
+    /// ```
+    ///
+    /// # Example: birthdays on a leap day
+    ///
+    /// Someone born on February 29 only turns a year older once March 1
+    /// arrives in a non-leap year:
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let birthday = date(2000, 2, 29);
+    /// let age = |today| birthday.years_months_days_until(today);
+    /// assert_eq!(age(date(2001, 2, 28)), (0, 11, 30));
+    /// assert_eq!(age(date(2001, 3, 1)), (1, 0, 1));
+    /// assert_eq!(age(date(2004, 2, 29)), (4, 0, 0));
+    /// ```
+    #[inline]
+    pub fn years_months_days_until(self, end: Date) -> (i16, i8, i8) {
+        let span = self
+            .until((Unit::Year, end))
+            .expect("difference between any two dates fits in a span");
+        // OK because months are less than 12 and days are less than 31
+        // when years are the largest unit.
+        let months = i8::try_from(span.get_months()).unwrap();
+        let days = i8::try_from(span.get_days()).unwrap();
+        (span.get_years(), months, days)
+    }
+
     /// Return an iterator of periodic dates determined by the given span.
     ///
     /// The given span may be negative, in which case, the iterator will move
@@ -1935,6 +2231,27 @@ impl Date {
     /// itself overflows, or it would otherwise exceed the minimum or maximum
     /// `Date` value.
     ///
+    /// Each date yielded is computed by adding a multiple of the span to the
+    /// starting date, and not by repeatedly adding the span to the previous
+    /// date. This means that month-end clamping does not accumulate. For
+    /// example, a monthly series starting on January 31 yields February 29
+    /// (or 28) and then March 31, and not March 29 (or 28).
+    ///
+    /// # Example: month-end clamping
+    ///
+    /// ```
+    /// use jiff::{civil::{Date, date}, ToSpan};
+    ///
+    /// let start = date(2024, 1, 31);
+    /// let monthly: Vec<Date> = start.series(1.month()).take(4).collect();
+    /// assert_eq!(monthly, vec![
+    ///     date(2024, 1, 31),
+    ///     date(2024, 2, 29),
+    ///     date(2024, 3, 31),
+    ///     date(2024, 4, 30),
+    /// ]);
+    /// ```
+    ///
     /// # Example: Halloween day of the week
     ///
     /// As a kid, I always hoped for Halloween to fall on a weekend. With this
@@ -2531,6 +2848,24 @@ impl<'de> serde::Deserialize<'de> for Date {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Date {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Date> {
+        let year = u.int_in_range(Year::MIN_REPR..=Year::MAX_REPR)?;
+        let month = u.int_in_range(Month::MIN_REPR..=Month::MAX_REPR)?;
+        let days_in_month = itime::days_in_month(year, month);
+        let day = u.int_in_range(1..=days_in_month)?;
+        Date::new(year, month, day)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(i16, i8, i8) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Date {
     fn arbitrary(g: &mut quickcheck::Gen) -> Date {
@@ -3179,13 +3514,8 @@ impl DateWith {
         let year = match self.year {
             None => self.original.year_ranged(),
             Some(DateWithYear::Jiff(year)) => Year::try_new("year", year)?,
-            Some(DateWithYear::EraYear(year, Era::CE)) => {
-                let year_ce = t::YearCE::try_new("CE year", year)?;
-                t::Year::try_rfrom("CE year", year_ce)?
-            }
-            Some(DateWithYear::EraYear(year, Era::BCE)) => {
-                let year_bce = t::YearBCE::try_new("BCE year", year)?;
-                t::Year::try_rfrom("BCE year", -year_bce + C(1))?
+            Some(DateWithYear::EraYear(year, era)) => {
+                year_from_era(year, era)?
             }
         };
         let month = match self.month {
@@ -3614,6 +3944,22 @@ fn iso_week_start_from_year(year: impl RInto<t::ISOYear>) -> UnixEpochDay {
     date_in_first_week.to_unix_epoch_day() - diff_from_monday
 }
 
+/// Converts a year in the given era to the astronomical year used by `Date`.
+///
+/// This returns an error if the year is out of range for its era.
+fn year_from_era(year: i16, era: Era) -> Result<Year, Error> {
+    match era {
+        Era::CE => {
+            let year_ce = t::YearCE::try_new("CE year", year)?;
+            t::Year::try_rfrom("CE year", year_ce)
+        }
+        Era::BCE => {
+            let year_bce = t::YearBCE::try_new("BCE year", year)?;
+            t::Year::try_rfrom("BCE year", -year_bce + C(1))
+        }
+    }
+}
+
 /// Adds or subtracts `sign` from the given `year`/`month`.
 ///
 /// If month overflows in either direction, then the `year` returned is
@@ -3929,6 +4275,70 @@ mod tests {
         span_eq!(sp, 30.days());
     }
 
+    #[test]
+    fn years_months_days_until() {
+        let ymd = |d1: Date, d2: Date| d1.years_months_days_until(d2);
+
+        // A birthday on a leap day.
+        let birthday = date(2000, 2, 29);
+        assert_eq!(ymd(birthday, date(2000, 2, 29)), (0, 0, 0));
+        assert_eq!(ymd(birthday, date(2001, 2, 28)), (0, 11, 30));
+        assert_eq!(ymd(birthday, date(2001, 3, 1)), (1, 0, 1));
+        assert_eq!(ymd(birthday, date(2004, 2, 28)), (3, 11, 30));
+        assert_eq!(ymd(birthday, date(2004, 2, 29)), (4, 0, 0));
+        assert_eq!(ymd(birthday, date(2024, 5, 14)), (24, 2, 15));
+        assert_eq!(ymd(date(2001, 3, 1), birthday), (-1, 0, -1));
+
+        // Spanning a leap day from an ordinary date.
+        assert_eq!(ymd(date(2023, 12, 15), date(2024, 3, 14)), (0, 2, 28));
+        assert_eq!(ymd(date(2022, 12, 15), date(2023, 3, 14)), (0, 2, 27));
+
+        // Borrowing days at the end of the month.
+        assert_eq!(ymd(date(2023, 1, 31), date(2023, 2, 28)), (0, 0, 28));
+        assert_eq!(ymd(date(2023, 1, 31), date(2023, 3, 1)), (0, 1, 1));
+        assert_eq!(ymd(date(2023, 1, 31), date(2023, 3, 31)), (0, 2, 0));
+        assert_eq!(ymd(date(2023, 3, 31), date(2023, 4, 30)), (0, 0, 30));
+        assert_eq!(ymd(date(2023, 3, 31), date(2023, 5, 1)), (0, 1, 1));
+        assert_eq!(ymd(date(2023, 5, 1), date(2023, 3, 31)), (0, -1, -1));
+
+        // The extremes.
+        assert_eq!(ymd(Date::MIN, Date::MAX), (19998, 11, 30));
+        assert_eq!(ymd(Date::MAX, Date::MIN), (-19998, -11, -30));
+    }
+
+    #[test]
+    fn era_year_roundtrip() {
+        let cases = [
+            (2024, 2024, Era::CE),
+            (1, 1, Era::CE),
+            (0, 1, Era::BCE),
+            (-1, 2, Era::BCE),
+            (-43, 44, Era::BCE),
+            (9999, 9999, Era::CE),
+            (-9999, 10000, Era::BCE),
+        ];
+        for (year, era_year, era) in cases {
+            let d = date(year, 2, 28);
+            assert_eq!(d.era_year(), (era_year, era), "year {year}");
+            assert_eq!(d.era(), era, "year {year}");
+            let got = Date::from_era_year(era_year, era, 2, 28).unwrap();
+            assert_eq!(got, d, "era year {era_year} {era:?}");
+        }
+
+        // There is no year 0 in either era, and each era has its own range.
+        assert!(Date::from_era_year(0, Era::CE, 1, 1).is_err());
+        assert!(Date::from_era_year(0, Era::BCE, 1, 1).is_err());
+        assert!(Date::from_era_year(-1, Era::BCE, 1, 1).is_err());
+        assert!(Date::from_era_year(10000, Era::CE, 1, 1).is_err());
+        assert!(Date::from_era_year(10001, Era::BCE, 1, 1).is_err());
+        // 1 BCE is the year 0, which is a leap year.
+        assert_eq!(
+            Date::from_era_year(1, Era::BCE, 2, 29).unwrap(),
+            date(0, 2, 29),
+        );
+        assert!(Date::from_era_year(2, Era::BCE, 2, 29).is_err());
+    }
+
     #[test]
     fn test_month_add() {
         let add =
@@ -4066,4 +4476,109 @@ mod tests {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[test]
+    fn series_weekly() {
+        let got: alloc::vec::Vec<Date> =
+            date(2024, 2, 26).series(7.days()).take(4).collect();
+        assert_eq!(
+            got,
+            [
+                date(2024, 2, 26),
+                date(2024, 3, 4),
+                date(2024, 3, 11),
+                date(2024, 3, 18),
+            ],
+        );
+    }
+
+    #[test]
+    fn series_monthly_from_month_end() {
+        let got: alloc::vec::Vec<Date> =
+            date(2023, 10, 31).series(1.month()).take(6).collect();
+        assert_eq!(
+            got,
+            [
+                date(2023, 10, 31),
+                date(2023, 11, 30),
+                date(2023, 12, 31),
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+            ],
+        );
+
+        // Clamping is relative to the start date, so yearly steps from a
+        // leap day return to the 29th in the next leap year.
+        let got: alloc::vec::Vec<Date> =
+            date(2024, 2, 29).series(1.year()).take(5).collect();
+        assert_eq!(
+            got,
+            [
+                date(2024, 2, 29),
+                date(2025, 2, 28),
+                date(2026, 2, 28),
+                date(2027, 2, 28),
+                date(2028, 2, 29),
+            ],
+        );
+    }
+
+    #[test]
+    fn leap_years_and_days_in_month() {
+        for (year, leap) in
+            [(1900, false), (2000, true), (2023, false), (2024, true)]
+        {
+            assert_eq!(crate::civil::is_leap_year(year), leap, "{year}");
+            assert_eq!(date(year, 1, 1).in_leap_year(), leap, "{year}");
+            let feb = if leap { 29 } else { 28 };
+            assert_eq!(date(year, 2, 1).days_in_month(), feb, "{year}");
+        }
+
+        let days: alloc::vec::Vec<i8> =
+            (1..=12).map(|m| date(2023, m, 1).days_in_month()).collect();
+        assert_eq!(days, [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+    }
+
+    #[test]
+    fn require_year_between() {
+        let (min, max) = (1900, 2100);
+        for d in [date(1900, 1, 1), date(2024, 7, 15), date(2100, 12, 31)] {
+            assert_eq!(d.require_year_between(min, max).unwrap(), d);
+        }
+
+        let err = date(1899, 12, 31).require_year_between(min, max);
+        insta::assert_snapshot!(err.unwrap_err(), @"parameter 'year' with value 1899 is not in the required range of 1900..=2100");
+        let err = date(2101, 1, 1).require_year_between(min, max);
+        insta::assert_snapshot!(err.unwrap_err(), @"parameter 'year' with value 2101 is not in the required range of 1900..=2100");
+        assert!(date(2024, 1, 1).require_year_between(max, min).is_err());
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        let nov = date(2024, 11, 1);
+        let got = nov.nth_weekday_of_month(3, Weekday::Thursday).unwrap();
+        assert_eq!(got, date(2024, 11, 21));
+        // Any date in the month gives the same answer.
+        let got =
+            date(2024, 11, 30).nth_weekday_of_month(3, Weekday::Thursday);
+        assert_eq!(got.unwrap(), date(2024, 11, 21));
+
+        let may = date(2024, 5, 15);
+        let got = may.nth_weekday_of_month(-1, Weekday::Monday).unwrap();
+        assert_eq!(got, date(2024, 5, 27));
+
+        // November 2024 has five Fridays, but February 2024 only has four.
+        let got = nov.nth_weekday_of_month(5, Weekday::Friday).unwrap();
+        assert_eq!(got, date(2024, 11, 29));
+        let feb = date(2024, 2, 1);
+        let got = feb.nth_weekday_of_month(4, Weekday::Friday).unwrap();
+        assert_eq!(got, date(2024, 2, 23));
+        let err = feb.nth_weekday_of_month(5, Weekday::Friday).unwrap_err();
+        insta::assert_snapshot!(err, @"day=30 is out of range for year=2024 and month=2, must be in range 1..=29");
+        let err = feb.nth_weekday_of_month(-5, Weekday::Friday).unwrap_err();
+        insta::assert_snapshot!(err, @"day=-5 is out of range for year=2024 and month=2, must be in range 1..=29");
+        let err = feb.nth_weekday_of_month(0, Weekday::Friday).unwrap_err();
+        insta::assert_snapshot!(err, @"got nth weekday of `0`, but must be non-zero and in range `-5..=5`");
+    }
 }
@@ -444,6 +444,222 @@ impl DateTime {
         DateTimeWith::new(self)
     }
 
+    /// Returns a new datetime with the hour set to the value given.
+    ///
+    /// All other fields of this datetime are left unchanged. This is a
+    /// convenience routine for `self.with().hour(hour).build()`. To set
+    /// multiple fields at once, use [`DateTime::with`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given hour is not in the range
+    /// `0..=23`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 0);
+    /// assert_eq!(dt.with_hour(9)?, date(2024, 6, 19).at(9, 22, 45, 0));
+    /// assert!(dt.with_hour(24).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_hour(self, hour: i8) -> Result<DateTime, Error> {
+        self.with().hour(hour).build()
+    }
+
+    /// Returns a new datetime with the minute set to the value given.
+    ///
+    /// All other fields of this datetime are left unchanged. This is a
+    /// convenience routine for `self.with().minute(minute).build()`. To set
+    /// multiple fields at once, use [`DateTime::with`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given minute is not in the range
+    /// `0..=59`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 0);
+    /// assert_eq!(dt.with_minute(0)?, date(2024, 6, 19).at(15, 0, 45, 0));
+    /// assert!(dt.with_minute(60).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_minute(self, minute: i8) -> Result<DateTime, Error> {
+        self.with().minute(minute).build()
+    }
+
+    /// Returns a new datetime with the second set to the value given.
+    ///
+    /// All other fields of this datetime are left unchanged. This is a
+    /// convenience routine for `self.with().second(second).build()`. To set
+    /// multiple fields at once, use [`DateTime::with`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given second is not in the range
+    /// `0..=59`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 0);
+    /// assert_eq!(dt.with_second(0)?, date(2024, 6, 19).at(15, 22, 0, 0));
+    /// assert!(dt.with_second(60).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_second(self, second: i8) -> Result<DateTime, Error> {
+        self.with().second(second).build()
+    }
+
+    /// Returns a new datetime with the millisecond set to the value given.
+    ///
+    /// The millisecond is the number of whole milliseconds in the fractional
+    /// second. All other fields of this datetime, including the microsecond
+    /// and nanosecond fields, are left unchanged. This is a convenience
+    /// routine for `self.with().millisecond(millisecond).build()`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given millisecond is not in the range
+    /// `0..=999`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 123_456_789);
+    /// assert_eq!(
+    ///     dt.with_millisecond(0)?,
+    ///     date(2024, 6, 19).at(15, 22, 45, 456_789),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_millisecond(
+        self,
+        millisecond: i16,
+    ) -> Result<DateTime, Error> {
+        self.with().millisecond(millisecond).build()
+    }
+
+    /// Returns a new datetime with the microsecond set to the value given.
+    ///
+    /// The microsecond is the number of whole microseconds after the
+    /// millisecond in the fractional second. All other fields of this
+    /// datetime are left unchanged. This is a convenience routine for
+    /// `self.with().microsecond(microsecond).build()`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given microsecond is not in the range
+    /// `0..=999`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 123_456_789);
+    /// assert_eq!(
+    ///     dt.with_microsecond(0)?,
+    ///     date(2024, 6, 19).at(15, 22, 45, 123_000_789),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_microsecond(
+        self,
+        microsecond: i16,
+    ) -> Result<DateTime, Error> {
+        self.with().microsecond(microsecond).build()
+    }
+
+    /// Returns a new datetime with the nanosecond set to the value given.
+    ///
+    /// The nanosecond is the number of whole nanoseconds after the
+    /// microsecond in the fractional second. To set the entire fractional
+    /// second, use [`DateTime::with_subsec_nanosecond`]. All other fields
+    /// of this datetime are left unchanged. This is a convenience routine
+    /// for `self.with().nanosecond(nanosecond).build()`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given nanosecond is not in the range
+    /// `0..=999`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 123_456_789);
+    /// assert_eq!(
+    ///     dt.with_nanosecond(0)?,
+    ///     date(2024, 6, 19).at(15, 22, 45, 123_456_000),
+    /// );
+    /// assert!(dt.with_nanosecond(1_000).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_nanosecond(self, nanosecond: i16) -> Result<DateTime, Error> {
+        self.with().nanosecond(nanosecond).build()
+    }
+
+    /// Returns a new datetime with the fractional second set to the given
+    /// number of nanoseconds.
+    ///
+    /// This replaces the millisecond, microsecond and nanosecond fields of
+    /// this datetime. All other fields are left unchanged. This is a
+    /// convenience routine for
+    /// `self.with().subsec_nanosecond(subsec_nanosecond).build()`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given number of nanoseconds is not in
+    /// the range `0..=999_999_999`.
+    ///
+    /// # Example
+    ///
+    /// This shows how to truncate a datetime to second precision:
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let dt = date(2024, 6, 19).at(15, 22, 45, 123_456_789);
+    /// assert_eq!(
+    ///     dt.with_subsec_nanosecond(0)?,
+    ///     date(2024, 6, 19).at(15, 22, 45, 0),
+    /// );
+    /// assert!(dt.with_subsec_nanosecond(1_000_000_000).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn with_subsec_nanosecond(
+        self,
+        subsec_nanosecond: i32,
+    ) -> Result<DateTime, Error> {
+        self.with().subsec_nanosecond(subsec_nanosecond).build()
+    }
+
     /// Returns the year for this datetime.
     ///
     /// The value returned is guaranteed to be in the range `-9999..=9999`.
@@ -1572,6 +1788,62 @@ impl DateTime {
         Ok(Zoned::from_parts(ts, tz, offset, dt))
     }
 
+    /// Converts a civil datetime to a [`Timestamp`](crate::Timestamp) in the
+    /// given [`TimeZone`].
+    ///
+    /// This is like [`DateTime::to_zoned`], except it returns a `Timestamp`
+    /// instead of a [`Zoned`]. Since a `Timestamp` doesn't carry a time
+    /// zone, this routine only needs to borrow the `TimeZone` given. It is
+    /// equivalent to calling [`TimeZone::to_timestamp`].
+    ///
+    /// In some cases, a civil datetime may be ambiguous in a
+    /// particular time zone. This routine automatically utilizes the
+    /// [`Disambiguation::Compatible`](crate::tz::Disambiguation) strategy
+    /// for resolving ambiguities. That is, if a civil datetime occurs in a
+    /// backward transition (called a fold), then the earlier time is selected.
+    /// Or if a civil datetime occurs in a forward transition (called a gap),
+    /// then the later time is selected.
+    ///
+    /// To convert a datetime to a `Timestamp` using a different
+    /// disambiguation strategy, use [`TimeZone::to_ambiguous_timestamp`].
+    ///
+    /// The inverse of this operation is
+    /// [`Timestamp::to_civil`](crate::Timestamp::to_civil).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if this datetime could not be represented as an
+    /// instant. This can occur in some cases near the minimum and maximum
+    /// boundaries of a `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// let dt = date(2024, 7, 4).at(12, 0, 0, 0);
+    /// let ts = dt.to_timestamp(&tz)?;
+    /// assert_eq!(ts.to_string(), "2024-07-04T16:00:00Z");
+    /// assert_eq!(ts.to_civil(&tz), dt);
+    ///
+    /// // Datetimes in a gap use the later time.
+    /// let ts = date(2024, 3, 10).at(2, 30, 0, 0).to_timestamp(&tz)?;
+    /// assert_eq!(ts.to_string(), "2024-03-10T07:30:00Z");
+    /// // Datetimes in a fold use the earlier time.
+    /// let ts = date(2024, 11, 3).at(1, 30, 0, 0).to_timestamp(&tz)?;
+    /// assert_eq!(ts.to_string(), "2024-11-03T05:30:00Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_timestamp(
+        self,
+        tz: &TimeZone,
+    ) -> Result<crate::Timestamp, Error> {
+        tz.to_timestamp(self)
+    }
+
     /// Add the given span of time to this datetime. If the sum would overflow
     /// the minimum or maximum datetime values, then an error is returned.
     ///
@@ -2816,6 +3088,21 @@ impl<'de> serde::Deserialize<'de> for DateTime {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DateTime {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<DateTime> {
+        let date = Date::arbitrary(u)?;
+        let time = Time::arbitrary(u)?;
+        Ok(DateTime::from_parts(date, time))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Date, Time) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for DateTime {
     fn arbitrary(g: &mut quickcheck::Gen) -> DateTime {
@@ -4373,6 +4660,56 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn with_time_fields() {
+        let dt = date(2024, 6, 19).at(15, 22, 45, 123_456_789);
+        assert_eq!(
+            dt.with_hour(0).unwrap(),
+            date(2024, 6, 19).at(0, 22, 45, 123_456_789),
+        );
+        assert_eq!(
+            dt.with_minute(59).unwrap(),
+            date(2024, 6, 19).at(15, 59, 45, 123_456_789),
+        );
+        assert_eq!(
+            dt.with_second(1).unwrap(),
+            date(2024, 6, 19).at(15, 22, 1, 123_456_789),
+        );
+        assert_eq!(
+            dt.with_millisecond(999).unwrap(),
+            date(2024, 6, 19).at(15, 22, 45, 999_456_789),
+        );
+        assert_eq!(
+            dt.with_microsecond(1).unwrap(),
+            date(2024, 6, 19).at(15, 22, 45, 123_001_789),
+        );
+        assert_eq!(
+            dt.with_nanosecond(999).unwrap(),
+            date(2024, 6, 19).at(15, 22, 45, 123_456_999),
+        );
+        assert_eq!(
+            dt.with_subsec_nanosecond(999_999_999).unwrap(),
+            date(2024, 6, 19).at(15, 22, 45, 999_999_999),
+        );
+
+        insta::assert_snapshot!(
+            dt.with_subsec_nanosecond(1_000_000_000).unwrap_err(),
+            @"parameter 'subsec_nanosecond' with value 1000000000 is not in the required range of 0..=999999999",
+        );
+        insta::assert_snapshot!(
+            dt.with_nanosecond(1_000).unwrap_err(),
+            @"parameter 'nanosecond' with value 1000 is not in the required range of 0..=999",
+        );
+        insta::assert_snapshot!(
+            dt.with_hour(24).unwrap_err(),
+            @"parameter 'hour' with value 24 is not in the required range of 0..=23",
+        );
+        assert!(dt.with_minute(-1).is_err());
+        assert!(dt.with_second(60).is_err());
+        assert!(dt.with_millisecond(1_000).is_err());
+        assert!(dt.with_microsecond(-1).is_err());
+    }
+
     #[test]
     fn from_temporal_docs() {
         let dt = DateTime::from_parts(
@@ -4422,6 +4759,24 @@ mod tests {
         span_eq!(later.since(earlier).unwrap(), -23.hours());
     }
 
+    #[test]
+    fn to_timestamp_roundtrip() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let tz = TimeZone::get("Australia/Sydney").unwrap();
+        let dt = date(2024, 1, 15).at(9, 30, 0, 0);
+        let ts = dt.to_timestamp(&tz).unwrap();
+        assert_eq!(ts, crate::Timestamp::new(1705271400, 0).unwrap());
+        assert_eq!(ts.to_civil(&tz), dt);
+
+        let dt = date(2024, 7, 15).at(9, 30, 0, 0);
+        let ts = dt.to_timestamp(&tz).unwrap();
+        assert_eq!(ts, crate::Timestamp::new(1720999800, 0).unwrap());
+        assert_eq!(ts.to_civil(&tz), dt);
+    }
+
     #[test]
     fn until() {
         let a = date(9999, 12, 30).at(3, 0, 0, 0);
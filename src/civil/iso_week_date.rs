@@ -1,6 +1,7 @@
 use crate::{
     civil::{Date, DateTime, Weekday},
-    error::{err, Error},
+    error::Error,
+    fmt::temporal::{DEFAULT_DATETIME_PARSER, DEFAULT_DATETIME_PRINTER},
     util::{
         rangeint::RInto,
         t::{self, ISOWeek, ISOYear, C},
@@ -57,6 +58,34 @@ use crate::{
 /// assert_eq!(d.iso_week_date(), weekdate);
 /// ```
 ///
+/// # Parsing and printing
+///
+/// The `ISOWeekDate` type provides convenient trait implementations of
+/// [`std::str::FromStr`] and [`std::fmt::Display`]. They use the ISO 8601
+/// week date format, `YYYY-Www-D`, where `D` is the weekday as a number from
+/// `1` (Monday) to `7` (Sunday). The basic format without separators,
+/// `YYYYWwwD`, is also accepted when parsing.
+///
+/// ```
+/// use jiff::civil::{ISOWeekDate, Weekday, date};
+///
+/// let weekdate: ISOWeekDate = "2024-W05-3".parse()?;
+/// assert_eq!(weekdate, ISOWeekDate::new(2024, 5, Weekday::Wednesday)?);
+/// assert_eq!(weekdate.date(), date(2024, 1, 31));
+/// assert_eq!(weekdate.to_string(), "2024-W05-3");
+///
+/// // Week 53 is only valid in long years.
+/// assert!("2020-W53-1".parse::<ISOWeekDate>().is_ok());
+/// assert!("2021-W53-1".parse::<ISOWeekDate>().is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// A [`fmt::temporal::DateTimeParser`](crate::fmt::temporal::DateTimeParser)
+/// and
+/// [`fmt::temporal::DateTimePrinter`](crate::fmt::temporal::DateTimePrinter)
+/// can also be used directly.
+///
 /// # Example: overlapping leap and long years
 ///
 /// A "long" ISO 8601 week year is a year with 53 weeks. That is, it is a year
@@ -665,9 +694,7 @@ impl ISOWeekDate {
         debug_assert_eq!(t::Year::MIN, ISOYear::MIN);
         debug_assert_eq!(t::Year::MAX, ISOYear::MAX);
         if week == 53 && !is_long_year(year) {
-            return Err(err!(
-                "ISO week number `{week}` is invalid for year `{year}`"
-            ));
+            return Err(Error::range("week", week.get(), 1, 52));
         }
         // And also, the maximum Date constrains what we can utter with
         // ISOWeekDate so that we can preserve infallible conversions between
@@ -747,6 +774,24 @@ impl core::fmt::Debug for ISOWeekDate {
     }
 }
 
+impl core::fmt::Display for ISOWeekDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        DEFAULT_DATETIME_PRINTER
+            .print_iso_week_date(self, StdFmtWrite(f))
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
+impl core::str::FromStr for ISOWeekDate {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<ISOWeekDate, Error> {
+        DEFAULT_DATETIME_PARSER.parse_iso_week_date(string)
+    }
+}
+
 impl Eq for ISOWeekDate {}
 
 impl PartialEq for ISOWeekDate {
@@ -871,5 +916,103 @@ mod tests {
             let next_date = wd.date().checked_add(1.days()).unwrap();
             quickcheck::TestResult::from_bool(wd < next_date.iso_week_date())
         }
+
+        fn prop_roundtrip_string(wd: ISOWeekDate) -> bool {
+            use alloc::string::ToString;
+
+            wd.to_string().parse::<ISOWeekDate>().unwrap() == wd
+        }
+    }
+
+    #[test]
+    fn parse_and_print() {
+        use alloc::string::ToString;
+
+        use crate::civil::date;
+
+        let tests = [
+            ("2024-W05-3", date(2024, 1, 31)),
+            ("2024W053", date(2024, 1, 31)),
+            ("2024-w05-3", date(2024, 1, 31)),
+            ("2020-W01-1", date(2019, 12, 30)),
+            ("2020-W53-7", date(2021, 1, 3)),
+            ("1994-W52-7", date(1995, 1, 1)),
+            ("0000-W01-1", date(0, 1, 3)),
+            ("-009999-W01-1", date(-9999, 1, 1)),
+            ("9999-W52-5", date(9999, 12, 31)),
+        ];
+        for (input, expected) in tests {
+            let wd: ISOWeekDate = input.parse().unwrap();
+            assert_eq!(wd.date(), expected, "parsing {input:?}");
+            assert_eq!(
+                wd.to_string(),
+                input.replace("W053", "-W05-3").replace('w', "W"),
+            );
+            #[cfg(feature = "alloc")]
+            assert_eq!(expected.to_iso_week_string(), wd.to_string());
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = |input: &str| {
+            alloc::string::ToString::to_string(
+                &input.parse::<ISOWeekDate>().unwrap_err(),
+            )
+        };
+
+        // 2021 only has 52 weeks.
+        insta::assert_snapshot!(
+            err("2021-W53-1"),
+            @"ISO week date parsed from \"2021-W53-1\" is not valid: parameter 'week' with value 53 is not in the required range of 1..=52",
+        );
+        // Without `alloc`, only the outermost (parse) error is kept.
+        let e = "2021-W53-1".parse::<ISOWeekDate>().unwrap_err();
+        assert_eq!(
+            e.category(),
+            if cfg!(feature = "alloc") {
+                crate::ErrorCategory::Range
+            } else {
+                crate::ErrorCategory::Parse
+            },
+        );
+        let e = ISOWeekDate::new(2021, 53, Weekday::Monday).unwrap_err();
+        assert_eq!(e.category(), crate::ErrorCategory::Range);
+        insta::assert_snapshot!(
+            err("2024-W54-1"),
+            @"failed to parse week in ISO week date \"2024-W54-1\": week is not valid: parameter 'week' with value 54 is not in the required range of 1..=53",
+        );
+        insta::assert_snapshot!(
+            err("2024-W00-1"),
+            @"failed to parse week in ISO week date \"2024-W00-1\": week is not valid: parameter 'week' with value 0 is not in the required range of 1..=53",
+        );
+        insta::assert_snapshot!(
+            err("2024-W05-8"),
+            @"failed to parse weekday in ISO week date \"2024-W05-8\": weekday is not valid: parameter 'weekday' with value 8 is not in the required range of 1..=7",
+        );
+        insta::assert_snapshot!(
+            err("2024-W05-0"),
+            @"failed to parse weekday in ISO week date \"2024-W05-0\": weekday is not valid: parameter 'weekday' with value 0 is not in the required range of 1..=7",
+        );
+        insta::assert_snapshot!(
+            err("2024-05-3"),
+            @"expected 'W' week designator after year in ISO week date \"2024-05-3\", but found \"0\" instead",
+        );
+        insta::assert_snapshot!(
+            err("2024-W05"),
+            @"failed to parse separator after week: expected '-' separator, but found end of input",
+        );
+        insta::assert_snapshot!(
+            err("2024-W053"),
+            @"failed to parse separator after week: expected '-' separator, but found \"3\" instead",
+        );
+        insta::assert_snapshot!(
+            err("2024-W05-3T00"),
+            @"parsed value '2024-W05-3', but unparsed input \"T00\" remains (expected no unparsed input)",
+        );
+        insta::assert_snapshot!(
+            err("9999-W52-6"),
+            @"ISO week date parsed from \"9999-W52-6\" is not valid: parameter 'weekday' with value 6 is not in the required range of 1..=5",
+        );
     }
 }
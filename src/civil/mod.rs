@@ -128,7 +128,8 @@ mod weekday;
 /// the year `1 BCE` is year `0` in this crate. The year `2 BCE` is the year
 /// `-1` in this crate.
 ///
-/// To get the year in its era format, use [`Date::era_year`].
+/// To get the year in its era format, use [`Date::era_year`]. To create a date
+/// from a year in its era format, use [`Date::from_era_year`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Era {
     /// The "before common era" era.
@@ -288,3 +289,36 @@ pub const fn time(
 ) -> Time {
     Time::constant(hour, minute, second, subsec_nanosecond)
 }
+
+/// Returns true if and only if the given year is a leap year.
+///
+/// A leap year has 366 days instead of 365, with the extra day being
+/// February 29. In the proleptic Gregorian calendar used by Jiff, a year is
+/// a leap year when it is divisible by 4, except for years divisible by 100
+/// that are not also divisible by 400.
+///
+/// Unlike [`date`], this accepts any `i16` year, including years outside the
+/// range supported by [`Date`]. The leap year rule is applied as is.
+///
+/// This is also available on [`Date`] via [`Date::in_leap_year`]. And the
+/// number of days in a date's month is available via
+/// [`Date::days_in_month`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(2023));
+/// // Century years are only leap years when divisible by 400.
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// // Negative years use the same rule. Year 0 is 1 BCE.
+/// assert!(is_leap_year(0));
+/// assert!(is_leap_year(-4));
+/// ```
+#[inline]
+pub const fn is_leap_year(year: i16) -> bool {
+    crate::shared::util::itime::is_leap_year(year)
+}
@@ -1527,12 +1527,56 @@ impl Time {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// To detect when this happens, use [`Time::overflowing_round`]. Or, to
+    /// carry the overflow into the date, round a [`DateTime`] instead.
     #[inline]
     pub fn round<R: Into<TimeRound>>(self, options: R) -> Result<Time, Error> {
         let options: TimeRound = options.into();
         options.round(self)
     }
 
+    /// Rounds this time according to the [`TimeRound`] configuration given,
+    /// and reports whether rounding wrapped around to the next day.
+    ///
+    /// This is like [`Time::round`], except it also returns `true` when
+    /// the rounded time would have been at or after midnight of the next
+    /// day. In that case, the time returned has wrapped around, just like
+    /// with `Time::round`. Callers can use this to add a day to a date that
+    /// was paired with this time.
+    ///
+    /// Since a `Time` can never be negative, rounding can never wrap around
+    /// to the previous day.
+    ///
+    /// # Errors
+    ///
+    /// This has the same error conditions as [`Time::round`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::time, Unit};
+    ///
+    /// let t = time(23, 59, 30, 0);
+    /// assert_eq!(t.overflowing_round(Unit::Minute)?, (time(0, 0, 0, 0), true));
+    ///
+    /// let t = time(23, 59, 29, 0);
+    /// assert_eq!(
+    ///     t.overflowing_round(Unit::Minute)?,
+    ///     (time(23, 59, 0, 0), false),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn overflowing_round<R: Into<TimeRound>>(
+        self,
+        options: R,
+    ) -> Result<(Time, bool), Error> {
+        let options: TimeRound = options.into();
+        options.overflowing_round(self)
+    }
+
     /// Return an iterator of periodic times determined by the given span.
     ///
     /// The given span may be negative, in which case, the iterator will move
@@ -2150,6 +2194,26 @@ impl<'de> serde::Deserialize<'de> for Time {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Time {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Time> {
+        let hour = u.int_in_range(Hour::MIN_REPR..=Hour::MAX_REPR)?;
+        let minute = u.int_in_range(Minute::MIN_REPR..=Minute::MAX_REPR)?;
+        let second = u.int_in_range(Second::MIN_REPR..=Second::MAX_REPR)?;
+        let subsec_nanosecond = u.int_in_range(
+            SubsecNanosecond::MIN_REPR..=SubsecNanosecond::MAX_REPR,
+        )?;
+        Time::new(hour, minute, second, subsec_nanosecond)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(i8, i8, i8, i32) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Time {
     fn arbitrary(g: &mut quickcheck::Gen) -> Time {
@@ -2823,6 +2887,15 @@ impl TimeRound {
 
     /// Does the actual rounding.
     pub(crate) fn round(&self, t: Time) -> Result<Time, Error> {
+        self.overflowing_round(t).map(|(t, _)| t)
+    }
+
+    /// Does the actual rounding, and also returns whether the rounded time
+    /// wrapped around to the next day.
+    pub(crate) fn overflowing_round(
+        &self,
+        t: Time,
+    ) -> Result<(Time, bool), Error> {
         let increment = increment::for_time(self.smallest, self.increment)?;
         let nanos = t.to_nanosecond();
         let rounded = self.mode.round_by_unit_in_nanoseconds(
@@ -2832,7 +2905,8 @@ impl TimeRound {
         );
         let limit =
             t::NoUnits128::rfrom(t::CivilDayNanosecond::MAX_SELF) + C(1);
-        Ok(Time::from_nanosecond((rounded % limit).rinto()))
+        let time = Time::from_nanosecond((rounded % limit).rinto());
+        Ok((time, rounded >= limit))
     }
 }
 
@@ -3298,6 +3372,48 @@ mod tests {
         assert!(Time::new(23, 59, 59, -1).is_err());
     }
 
+    #[test]
+    fn overflowing_round_reports_day_carry() {
+        let t = time(23, 59, 30, 0);
+        assert_eq!(
+            t.overflowing_round(Unit::Minute).unwrap(),
+            (time(0, 0, 0, 0), true),
+        );
+        let trunc =
+            TimeRound::new().smallest(Unit::Minute).mode(RoundMode::Trunc);
+        assert_eq!(
+            t.overflowing_round(trunc).unwrap(),
+            (time(23, 59, 0, 0), false),
+        );
+        assert_eq!(
+            time(23, 59, 29, 999_999_999)
+                .overflowing_round(Unit::Minute)
+                .unwrap(),
+            (time(23, 59, 0, 0), false),
+        );
+        assert_eq!(
+            time(12, 0, 0, 0).overflowing_round(Unit::Hour).unwrap(),
+            (time(12, 0, 0, 0), false),
+        );
+        assert_eq!(
+            Time::MIN.overflowing_round(Unit::Hour).unwrap(),
+            (Time::MIN, false),
+        );
+        assert_eq!(
+            Time::MAX.overflowing_round(Unit::Nanosecond).unwrap(),
+            (Time::MAX, false),
+        );
+        assert_eq!(
+            Time::MAX.overflowing_round(Unit::Microsecond).unwrap(),
+            (Time::MIN, true),
+        );
+        assert_eq!(
+            time(23, 50, 0, 0).overflowing_round((Unit::Minute, 20)).unwrap(),
+            (time(0, 0, 0, 0), true),
+        );
+        assert!(t.overflowing_round(Unit::Day).is_err());
+    }
+
     #[test]
     fn rounding_cross_midnight() {
         let t1 = time(23, 59, 59, 999_999_999);
@@ -844,6 +844,56 @@ impl core::iter::FusedIterator for WeekdaysReverse {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn wrap_around() {
+        assert_eq!(Weekday::Saturday.next(), Weekday::Sunday);
+        assert_eq!(Weekday::Sunday.next(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.previous(), Weekday::Sunday);
+        assert_eq!(Weekday::Sunday.previous(), Weekday::Saturday);
+
+        assert_eq!(Weekday::Sunday.wrapping_add(1), Weekday::Monday);
+        assert_eq!(Weekday::Sunday.wrapping_add(-1), Weekday::Saturday);
+        assert_eq!(Weekday::Monday.wrapping_add(7), Weekday::Monday);
+        assert_eq!(Weekday::Monday.wrapping_add(-7), Weekday::Monday);
+        assert_eq!(Weekday::Monday.wrapping_add(i64::MAX), Weekday::Monday);
+        assert_eq!(Weekday::Monday.wrapping_add(i64::MIN), Weekday::Sunday);
+    }
+
+    /// Checks the ISO 8601 (Monday is `1`) and POSIX `tm_wday` (Sunday is
+    /// `0`) numbering schemes against one another.
+    #[test]
+    fn numbering_schemes() {
+        let week = [
+            (Weekday::Monday, 1, 1),
+            (Weekday::Tuesday, 2, 2),
+            (Weekday::Wednesday, 3, 3),
+            (Weekday::Thursday, 4, 4),
+            (Weekday::Friday, 5, 5),
+            (Weekday::Saturday, 6, 6),
+            (Weekday::Sunday, 7, 0),
+        ];
+        for (wd, monday_one, sunday_zero) in week {
+            assert_eq!(wd.to_monday_one_offset(), monday_one, "{wd:?}");
+            assert_eq!(wd.to_sunday_zero_offset(), sunday_zero, "{wd:?}");
+            assert_eq!(wd.to_monday_zero_offset(), monday_one - 1, "{wd:?}");
+            assert_eq!(wd.to_sunday_one_offset(), sunday_zero + 1, "{wd:?}");
+
+            assert_eq!(
+                Weekday::from_monday_one_offset(monday_one).unwrap(),
+                wd
+            );
+            assert_eq!(
+                Weekday::from_sunday_zero_offset(sunday_zero).unwrap(),
+                wd,
+            );
+        }
+
+        assert!(Weekday::from_monday_one_offset(0).is_err());
+        assert!(Weekday::from_monday_one_offset(8).is_err());
+        assert!(Weekday::from_sunday_zero_offset(-1).is_err());
+        assert!(Weekday::from_sunday_zero_offset(7).is_err());
+    }
+
     quickcheck::quickcheck! {
         fn prop_since_add_equals_self(wd1: Weekday, wd2: Weekday) -> bool {
             let days = wd1.since(wd2);
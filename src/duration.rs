@@ -111,6 +111,25 @@ impl Duration {
     }
 }
 
+/// Formats this duration in the ISO 8601 duration format, regardless of which
+/// duration type it wraps.
+///
+/// This is useful for error messages. An unsigned duration that is too big
+/// to be represented as a signed duration falls back to its `Debug`
+/// representation.
+impl core::fmt::Display for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            Duration::Span(ref span) => core::fmt::Display::fmt(span, f),
+            Duration::Signed(ref sdur) => core::fmt::Display::fmt(sdur, f),
+            Duration::Unsigned(udur) => match SignedDuration::try_from(udur) {
+                Ok(sdur) => core::fmt::Display::fmt(&sdur, f),
+                Err(_) => core::fmt::Debug::fmt(&udur, f),
+            },
+        }
+    }
+}
+
 impl From<Span> for Duration {
     #[inline]
     fn from(span: Span) -> Duration {
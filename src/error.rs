@@ -12,6 +12,18 @@ macro_rules! err {
 
 pub(crate) use err;
 
+/// Creates a new ad hoc error describing why some input could not be parsed.
+///
+/// This is like `err!`, except the error returned is categorized as a parse
+/// error. See `ErrorCategory::Parse`.
+macro_rules! parse_err {
+    ($($tt:tt)*) => {{
+        crate::error::Error::parse_from_args(format_args!($($tt)*))
+    }}
+}
+
+pub(crate) use parse_err;
+
 /// An error that can occur in this crate.
 ///
 /// The most common type of error is a result of overflow. But other errors
@@ -29,8 +41,51 @@ pub(crate) use err;
 ///
 /// Other than implementing the [`std::error::Error`] trait when the
 /// `std` feature is enabled, the [`core::fmt::Debug`] trait and the
-/// [`core::fmt::Display`] trait, this error type currently provides no
-/// introspection capabilities.
+/// [`core::fmt::Display`] trait, this error type currently provides very
+/// few introspection capabilities:
+///
+/// * [`Error::root_cause`] returns the innermost error in the causal chain.
+/// * [`Error::time_zone_name`] returns the name of a time zone not found.
+/// * [`Error::io_error_kind`] returns the kind of an I/O error, if any.
+/// * [`Error::is_retryable`] reports whether the error might be transient.
+///
+/// # Formatting
+///
+/// The `Display` implementation prints the error along with its entire
+/// causal chain on a single line, with each cause separated by `: `.
+///
+/// The `Debug` implementation prints the error on the first line, followed
+/// by each cause in the causal chain on its own indented line. This is what
+/// is shown when, e.g., calling `unwrap()` on a `Result` with this error
+/// panics. The alternate `Debug` implementation (`{:#?}`) shows the internal
+/// representation of the error, which is only useful for debugging Jiff
+/// itself.
+///
+/// Some error messages include the input that caused them, which might be
+/// very long. To keep logs readable, any single message in the causal chain
+/// longer than 512 characters is truncated, and followed by a note with the
+/// number of characters omitted. Use the alternate `Display` implementation
+/// (`{:#}`) or [`Error::to_untruncated_string`] to get the complete messages.
+///
+/// ```
+/// use jiff::civil::Date;
+///
+/// let err = "2024-13-01".parse::<Date>().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to parse month in date \"2024-13-01\": \
+///      month is not valid: \
+///      parameter 'month' with value 13 is not in the required range of 1..=12",
+/// );
+/// assert_eq!(
+///     format!("{err:?}"),
+///     "failed to parse month in date \"2024-13-01\"\n\
+///      \n\
+///      Caused by:\n    \
+///      0: month is not valid\n    \
+///      1: parameter 'month' with value 13 is not in the required range of 1..=12",
+/// );
+/// ```
 ///
 /// # Design
 ///
@@ -57,6 +112,89 @@ pub struct Error {
     inner: Option<Arc<ErrorInner>>,
 }
 
+/// A coarse category describing an [`Error`].
+///
+/// This is returned by [`Error::category`]. It is useful for deciding how
+/// to handle an error without inspecting its message, which is not stable.
+///
+/// New categories may be added in semver compatible releases. When that
+/// happens, some errors that were previously categorized as
+/// [`ErrorCategory::Other`] may be categorized differently.
+///
+/// # Example
+///
+/// This shows how to handle a failed time zone lookup differently from all
+/// other errors:
+///
+/// ```
+/// use jiff::{ErrorCategory, Zoned};
+///
+/// let err = "2024-06-19T15:22[America/Nowhere]"
+///     .parse::<Zoned>()
+///     .unwrap_err();
+/// let msg = match err.category() {
+///     ErrorCategory::TimeZoneLookup => "unknown time zone",
+///     _ => "something else went wrong",
+/// };
+/// assert_eq!(msg, "unknown time zone");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorCategory {
+    /// A value was out of its allowed range. This includes arithmetic that
+    /// overflowed Jiff's supported range of datetimes.
+    Range,
+    /// A time zone could not be found in a time zone database. The name of
+    /// the time zone can be retrieved with [`Error::time_zone_name`].
+    TimeZoneLookup,
+    /// An I/O error occurred, e.g., while reading time zone data from the
+    /// file system.
+    Io,
+    /// Some input could not be parsed, e.g., because it did not match the
+    /// expected format. Note that when parsing fails because a value is out
+    /// of range, the error is categorized as [`ErrorCategory::Range`]
+    /// instead.
+    Parse,
+    /// The error was created by [`Error::from_errors`] from a collection of
+    /// independent errors.
+    Multiple,
+    /// All other errors.
+    Other,
+}
+
+impl ErrorCategory {
+    /// Returns a short machine readable code for this category.
+    ///
+    /// Unlike the `Debug` representation, the codes returned are stable.
+    /// An existing code will never be renamed in a semver compatible
+    /// release. The codes are:
+    ///
+    /// * [`ErrorCategory::Range`] is `range`.
+    /// * [`ErrorCategory::TimeZoneLookup`] is `tz-lookup`.
+    /// * [`ErrorCategory::Io`] is `io`.
+    /// * [`ErrorCategory::Parse`] is `parse`.
+    /// * [`ErrorCategory::Multiple`] is `multiple`.
+    /// * [`ErrorCategory::Other`] is `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::ErrorCategory;
+    ///
+    /// assert_eq!(ErrorCategory::TimeZoneLookup.code(), "tz-lookup");
+    /// ```
+    pub fn code(self) -> &'static str {
+        match self {
+            ErrorCategory::Range => "range",
+            ErrorCategory::TimeZoneLookup => "tz-lookup",
+            ErrorCategory::Io => "io",
+            ErrorCategory::Parse => "parse",
+            ErrorCategory::Multiple => "multiple",
+            ErrorCategory::Other => "other",
+        }
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(not(feature = "alloc"), derive(Clone))]
 struct ErrorInner {
@@ -77,6 +215,10 @@ enum ErrorKind {
     /// representation when a lot of different error cases fit neatly into a
     /// structure (like range errors).
     Adhoc(AdhocError),
+    /// An ad hoc error that occurs when some input could not be parsed.
+    ///
+    /// This is just like `Adhoc`, but lets us categorize the error.
+    Parse(AdhocError),
     /// An error that occurs when a number is not within its allowed range.
     ///
     /// This can occur directly as a result of a number provided by the caller
@@ -107,9 +249,350 @@ enum ErrorKind {
     /// When `std` is not enabled, this variant can never be constructed.
     #[allow(dead_code)] // not used in some feature configs
     IO(IOError),
+    /// An error that occurs when a time zone could not be found in a time
+    /// zone database.
+    ///
+    /// This is structured so that callers can retrieve the name of the time
+    /// zone that failed to be found via [`Error::time_zone_name`].
+    TimeZoneLookup(TimeZoneLookupError),
+    /// A collection of independent errors, created by
+    /// [`Error::from_errors`].
+    ///
+    /// This is used to report every failure from a batch operation instead
+    /// of just the first one. Each error has its own causal chain.
+    #[cfg(feature = "alloc")]
+    Multiple(MultipleError),
 }
 
 impl Error {
+    /// Returns the innermost cause in this error's causal chain.
+    ///
+    /// Errors in Jiff are frequently contextualized with additional
+    /// information as they bubble up. For example, a failure to parse a
+    /// date might be caused by a month value that is out of range. The
+    /// [`core::fmt::Display`] implementation for `Error` shows the entire
+    /// chain, where as this returns only the deepest error in that chain.
+    /// That is, the error that was the original source of the failure.
+    ///
+    /// If this error has no cause, then this returns `self`.
+    ///
+    /// When the `alloc` feature is disabled, errors never have a cause, and
+    /// so this always returns `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Date;
+    ///
+    /// let err = "2024-13-01".parse::<Date>().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "failed to parse month in date \"2024-13-01\": \
+    ///      month is not valid: \
+    ///      parameter 'month' with value 13 \
+    ///      is not in the required range of 1..=12",
+    /// );
+    /// assert_eq!(
+    ///     err.root_cause().to_string(),
+    ///     "parameter 'month' with value 13 \
+    ///      is not in the required range of 1..=12",
+    /// );
+    /// ```
+    pub fn root_cause(&self) -> &Error {
+        #[cfg(feature = "alloc")]
+        {
+            let mut err = self;
+            while let Some(cause) =
+                err.inner.as_ref().and_then(|inner| inner.cause.as_ref())
+            {
+                err = cause;
+            }
+            err
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            self
+        }
+    }
+
+    /// Returns the name of the time zone that could not be found, if this
+    /// error was caused by a failed time zone lookup.
+    ///
+    /// This searches the entire causal chain of this error. So even if the
+    /// failed lookup has been contextualized with other errors (for example,
+    /// while parsing a zoned datetime), the name is still returned.
+    ///
+    /// This returns `None` when no time zone lookup failure is present in
+    /// the causal chain. It also always returns `None` when the `alloc`
+    /// feature is disabled, since the name cannot be stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Zoned;
+    ///
+    /// let err = "2024-06-19T15:22[America/Nowhere]"
+    ///     .parse::<Zoned>()
+    ///     .unwrap_err();
+    /// assert_eq!(err.time_zone_name(), Some("America/Nowhere"));
+    ///
+    /// let err = "2024-13-19T15:22[America/New_York]"
+    ///     .parse::<Zoned>()
+    ///     .unwrap_err();
+    /// assert_eq!(err.time_zone_name(), None);
+    /// ```
+    pub fn time_zone_name(&self) -> Option<&str> {
+        #[cfg(feature = "alloc")]
+        {
+            let mut err = self;
+            loop {
+                let inner = err.inner.as_ref()?;
+                if let ErrorKind::TimeZoneLookup(ref lookup) = inner.kind {
+                    return Some(&lookup.name);
+                }
+                err = inner.cause.as_ref()?;
+            }
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the kind of the first I/O error found in this error's causal
+    /// chain, if one exists.
+    ///
+    /// I/O errors can occur, for example, when reading a time zone database
+    /// from the file system via
+    /// [`TimeZoneDatabase::from_dir`](crate::tz::TimeZoneDatabase::from_dir).
+    ///
+    /// This is only available when the `std` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Date;
+    ///
+    /// // Errors that have nothing to do with I/O never have an I/O kind.
+    /// let err = "2024-13-01".parse::<Date>().unwrap_err();
+    /// assert_eq!(err.io_error_kind(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn io_error_kind(&self) -> Option<std::io::ErrorKind> {
+        let mut err = self;
+        loop {
+            let inner = err.inner.as_ref()?;
+            if let ErrorKind::IO(ref io) = inner.kind {
+                return Some(io.err.kind());
+            }
+            err = inner.cause.as_ref()?;
+        }
+    }
+
+    /// Returns true if retrying the operation that produced this error could
+    /// plausibly succeed.
+    ///
+    /// This returns true only when the causal chain contains a transient I/O
+    /// error. That is, one whose kind is
+    /// [`Interrupted`](std::io::ErrorKind::Interrupted),
+    /// [`WouldBlock`](std::io::ErrorKind::WouldBlock) or
+    /// [`TimedOut`](std::io::ErrorKind::TimedOut). All other errors, like
+    /// range errors, parse errors or failing to find a time zone, are
+    /// deterministic. Retrying them will always produce the same error.
+    ///
+    /// When the `std` feature is disabled, this always returns false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Date;
+    ///
+    /// let err = "2024-13-01".parse::<Date>().unwrap_err();
+    /// assert!(!err.is_retryable());
+    ///
+    /// let err = Date::MAX.tomorrow().unwrap_err();
+    /// assert!(!err.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            use std::io::ErrorKind::*;
+
+            matches!(
+                self.io_error_kind(),
+                Some(Interrupted | WouldBlock | TimedOut)
+            )
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            false
+        }
+    }
+
+    /// Returns a short machine readable code describing this error.
+    ///
+    /// Unlike the `Display` implementation, whose wording may change between
+    /// releases, the codes returned are stable. This makes them suitable
+    /// for things like metric labels or alerting rules.
+    ///
+    /// This is a convenience for `err.category().code()`. See
+    /// [`ErrorCategory::code`] for the list of codes that may be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Date, Zoned};
+    ///
+    /// let err = Date::MAX.tomorrow().unwrap_err();
+    /// assert_eq!(err.code(), "range");
+    ///
+    /// let err = "2024-13-01".parse::<Date>().unwrap_err();
+    /// assert_eq!(err.code(), "range");
+    ///
+    /// let err = "2024-06-19T15:22[America/Nowhere]"
+    ///     .parse::<Zoned>()
+    ///     .unwrap_err();
+    /// assert_eq!(err.code(), "tz-lookup");
+    ///
+    /// let err = "2024-06-19T15:22".parse::<Zoned>().unwrap_err();
+    /// assert_eq!(err.code(), "parse");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        self.category().code()
+    }
+
+    /// Returns this error, along with its entire causal chain, as a string
+    /// without truncating any of its messages.
+    ///
+    /// This is the same as the `Display` implementation for this error,
+    /// except that long messages are never truncated. It is equivalent to
+    /// using the alternate `Display` implementation, i.e., `format!("{:#}",
+    /// err)`.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let junk = "x".repeat(1_000);
+    /// let input = format!("2024-06-19T15:22:45Z{junk}");
+    /// let err = input.parse::<Timestamp>().unwrap_err();
+    /// assert!(err.to_string().ends_with("… (583 more chars)"));
+    /// assert!(err.to_untruncated_string().contains(&junk));
+    /// assert_eq!(err.to_untruncated_string(), format!("{err:#}"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_untruncated_string(&self) -> alloc::string::String {
+        alloc::format!("{self:#}")
+    }
+
+    /// Returns the category of this error.
+    ///
+    /// The category is determined by the [root cause](Error::root_cause) of
+    /// this error. When the `alloc` feature is disabled, errors never have a
+    /// cause. So the category is determined by the outermost error, which is
+    /// usually [`ErrorCategory::Other`].
+    ///
+    /// Since [`ErrorCategory`] implements `Eq`, categories can be compared
+    /// directly. To check for one of several categories, use
+    /// `core::matches!`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Date, ErrorCategory, Zoned};
+    ///
+    /// let err = Date::MAX.tomorrow().unwrap_err();
+    /// assert_eq!(err.category(), ErrorCategory::Range);
+    ///
+    /// let err = "2024-06-19T15:22[America/Nowhere]"
+    ///     .parse::<Zoned>()
+    ///     .unwrap_err();
+    /// assert_eq!(err.category(), ErrorCategory::TimeZoneLookup);
+    /// assert!(matches!(
+    ///     err.category(),
+    ///     ErrorCategory::Range | ErrorCategory::TimeZoneLookup,
+    /// ));
+    ///
+    /// let err = "2024-06-19T15:22".parse::<Zoned>().unwrap_err();
+    /// assert_eq!(err.category(), ErrorCategory::Parse);
+    /// ```
+    pub fn category(&self) -> ErrorCategory {
+        let Some(ref inner) = self.root_cause().inner else {
+            return ErrorCategory::Other;
+        };
+        match inner.kind {
+            ErrorKind::Adhoc(_) | ErrorKind::Shared(_) => ErrorCategory::Other,
+            ErrorKind::Parse(_) => ErrorCategory::Parse,
+            ErrorKind::Range(_) => ErrorCategory::Range,
+            ErrorKind::FilePath(_) | ErrorKind::IO(_) => ErrorCategory::Io,
+            ErrorKind::TimeZoneLookup(_) => ErrorCategory::TimeZoneLookup,
+            #[cfg(feature = "alloc")]
+            ErrorKind::Multiple(_) => ErrorCategory::Multiple,
+        }
+    }
+
+    /// Creates a single error from a collection of independent errors.
+    ///
+    /// This is useful for operations over a batch of values, where it is
+    /// more helpful to report every failure instead of just the first one.
+    ///
+    /// The `Display` implementation of the error returned shows the number
+    /// of errors followed by each error (including its causal chain),
+    /// separated by `; `. If exactly one error is given, then it is returned
+    /// unchanged.
+    ///
+    /// If no errors are given, then an error is still returned. Its message
+    /// is `unknown jiff error (no errors were given)` and its category is
+    /// [`ErrorCategory::Multiple`]. Since this is unlikely to be a useful
+    /// error to report, callers should check whether any errors occurred
+    /// before calling this routine. (As is done in the example below.)
+    ///
+    /// Note that the introspection methods on `Error`, like
+    /// [`Error::time_zone_name`], only search the causal chain of the error
+    /// returned. They do not search the errors given here.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::Date, Error};
+    ///
+    /// let inputs = ["2024-01-15", "2024-13-01", "2024-02-30"];
+    /// let mut errs = vec![];
+    /// for input in inputs {
+    ///     if let Err(err) = input.parse::<Date>() {
+    ///         errs.push(err);
+    ///     }
+    /// }
+    /// assert!(!errs.is_empty());
+    /// let err = Error::from_errors(errs);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "2 errors occurred: \
+    ///      failed to parse month in date \"2024-13-01\": \
+    ///      month is not valid: \
+    ///      parameter 'month' with value 13 \
+    ///      is not in the required range of 1..=12; \
+    ///      date parsed from \"2024-02-30\" is not valid: \
+    ///      parameter 'day' with value 30 \
+    ///      is not in the required range of 1..=29",
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_errors<I: IntoIterator<Item = Error>>(errors: I) -> Error {
+        let mut errors: alloc::vec::Vec<Error> = errors.into_iter().collect();
+        if errors.len() == 1 {
+            return errors.pop().unwrap();
+        }
+        Error::from(ErrorKind::Multiple(MultipleError {
+            errors: errors.into_boxed_slice(),
+        }))
+    }
+
     /// Creates a new "ad hoc" error value.
     ///
     /// An ad hoc error value is just an opaque string. In theory we should
@@ -143,6 +626,16 @@ impl Error {
         Error::from(ErrorKind::Adhoc(AdhocError::from_static_str(message)))
     }
 
+    /// Like `Error::adhoc_from_args`, but the error returned is categorized
+    /// as a parse error.
+    ///
+    /// This is used with the `parse_err!` macro.
+    pub(crate) fn parse_from_args<'a>(
+        message: core::fmt::Arguments<'a>,
+    ) -> Error {
+        Error::from(ErrorKind::Parse(AdhocError::from_args(message)))
+    }
+
     /// Creates a new error indicating that a `given` value is out of the
     /// specified `min..=max` range. The given `what` label is used in the
     /// error message as a human readable description of what exactly is out
@@ -156,6 +649,12 @@ impl Error {
         Error::from(ErrorKind::Range(RangeError::new(what, given, min, max)))
     }
 
+    /// Creates a new error indicating that the time zone with the given name
+    /// could not be found in a time zone database.
+    pub(crate) fn time_zone_lookup(name: &str) -> Error {
+        Error::from(ErrorKind::TimeZoneLookup(TimeZoneLookupError::new(name)))
+    }
+
     /// Creates a new error from the special "shared" error type.
     pub(crate) fn shared(err: SharedError) -> Error {
         Error::from(ErrorKind::Shared(err))
@@ -179,7 +678,7 @@ impl Error {
     /// `FilePathError`.
     ///
     /// This is only available when the `std` feature is enabled.
-    #[cfg(feature = "tzdb-zoneinfo")]
+    #[cfg(feature = "std")]
     pub(crate) fn path(self, path: impl Into<std::path::PathBuf>) -> Error {
         let err = Error::from(ErrorKind::FilePath(FilePathError {
             path: path.into(),
@@ -217,7 +716,9 @@ impl core::fmt::Display for Error {
                     write!(f, "unknown jiff error")?;
                     break;
                 };
-                write!(f, "{}", inner.kind)?;
+                // Formatting with `f` directly passes along the alternate
+                // flag, which disables truncation of long messages.
+                core::fmt::Display::fmt(&inner.kind, f)?;
                 err = match inner.cause.as_ref() {
                     None => break,
                     Some(err) => err,
@@ -230,7 +731,7 @@ impl core::fmt::Display for Error {
         {
             match self.inner {
                 None => write!(f, "unknown jiff error"),
-                Some(ref inner) => write!(f, "{}", inner.kind),
+                Some(ref inner) => core::fmt::Display::fmt(&inner.kind, f),
             }
         }
     }
@@ -239,7 +740,35 @@ impl core::fmt::Display for Error {
 impl core::fmt::Debug for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if !f.alternate() {
-            core::fmt::Display::fmt(self, f)
+            let Some(ref inner) = self.inner else {
+                return write!(f, "unknown jiff error");
+            };
+            write!(f, "{}", inner.kind)?;
+            #[cfg(feature = "alloc")]
+            {
+                let Some(ref cause) = inner.cause else { return Ok(()) };
+                write!(f, "\n\nCaused by:")?;
+                let numbered = cause
+                    .inner
+                    .as_ref()
+                    .is_some_and(|inner| inner.cause.is_some());
+                let mut err = Some(cause);
+                let mut index = 0;
+                while let Some(cause) = err {
+                    write!(f, "\n    ")?;
+                    if numbered {
+                        write!(f, "{index}: ")?;
+                    }
+                    let Some(ref inner) = cause.inner else {
+                        write!(f, "unknown jiff error")?;
+                        break;
+                    };
+                    write!(f, "{}", inner.kind)?;
+                    err = inner.cause.as_ref();
+                    index += 1;
+                }
+            }
+            Ok(())
         } else {
             let Some(ref inner) = self.inner else {
                 return f
@@ -262,14 +791,54 @@ impl core::fmt::Debug for Error {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Error {
+    /// Attaches the given error as the cause of the innermost error in this
+    /// error's causal chain.
+    ///
+    /// Error values in a chain are usually uniquely owned, in which case
+    /// they are updated in place. If any of them are shared (because the
+    /// error was cloned), then that link in the chain is rebuilt as an ad
+    /// hoc error with the same message. This means structured information
+    /// like [`Error::time_zone_name`] may be lost for shared links, but the
+    /// message is always preserved.
+    fn attach_cause(self, cause: Error) -> Error {
+        let Some(inner) = self.inner else {
+            return err!("unknown jiff error").attach_cause(cause);
+        };
+        let (kind, existing) = match Arc::try_unwrap(inner) {
+            Ok(ErrorInner { kind, cause: existing }) => (kind, existing),
+            Err(inner) => {
+                let msg = AdhocError::from_display(&inner.kind);
+                let kind = match inner.kind {
+                    ErrorKind::Parse(_) => ErrorKind::Parse(msg),
+                    _ => ErrorKind::Adhoc(msg),
+                };
+                (kind, inner.cause.clone())
+            }
+        };
+        let cause = match existing {
+            None => cause,
+            Some(existing) => existing.attach_cause(cause),
+        };
+        Error {
+            inner: Some(Arc::new(ErrorInner { kind, cause: Some(cause) })),
+        }
+    }
+}
+
 impl core::fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
             ErrorKind::Adhoc(ref msg) => msg.fmt(f),
+            ErrorKind::Parse(ref msg) => msg.fmt(f),
             ErrorKind::Range(ref err) => err.fmt(f),
             ErrorKind::Shared(ref err) => err.fmt(f),
             ErrorKind::FilePath(ref err) => err.fmt(f),
             ErrorKind::IO(ref err) => err.fmt(f),
+            ErrorKind::TimeZoneLookup(ref err) => err.fmt(f),
+            #[cfg(feature = "alloc")]
+            ErrorKind::Multiple(ref err) => err.fmt(f),
         }
     }
 }
@@ -302,6 +871,10 @@ struct AdhocError {
 }
 
 impl AdhocError {
+    /// The maximum number of characters of a message that are printed by
+    /// the (non-alternate) `Display` implementation.
+    const MAX_DISPLAY_CHARS: usize = 512;
+
     #[cfg(feature = "alloc")]
     fn from_display<'a>(message: impl core::fmt::Display + 'a) -> AdhocError {
         use alloc::string::ToString;
@@ -342,7 +915,16 @@ impl std::error::Error for AdhocError {}
 
 impl core::fmt::Display for AdhocError {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        core::fmt::Display::fmt(&self.message, f)
+        if f.alternate() {
+            return f.write_str(&self.message);
+        }
+        match self.message.char_indices().nth(AdhocError::MAX_DISPLAY_CHARS) {
+            None => f.write_str(&self.message),
+            Some((end, _)) => {
+                let rest = self.message[end..].chars().count();
+                write!(f, "{}… ({rest} more chars)", &self.message[..end])
+            }
+        }
     }
 }
 
@@ -496,6 +1078,78 @@ impl core::fmt::Debug for FilePathError {
     }
 }
 
+/// An error that occurs when a time zone name could not be found in a time
+/// zone database.
+///
+/// When `alloc` is not enabled, the name is not stored.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "alloc"), derive(Clone))]
+struct TimeZoneLookupError {
+    #[cfg(feature = "alloc")]
+    name: alloc::boxed::Box<str>,
+}
+
+impl TimeZoneLookupError {
+    fn new(_name: &str) -> TimeZoneLookupError {
+        TimeZoneLookupError {
+            #[cfg(feature = "alloc")]
+            name: _name.into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeZoneLookupError {}
+
+impl core::fmt::Display for TimeZoneLookupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        #[cfg(feature = "alloc")]
+        {
+            write!(
+                f,
+                "failed to find time zone `{}` in time zone database",
+                self.name,
+            )
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            write!(f, "failed to find time zone in time zone database")
+        }
+    }
+}
+
+/// A collection of independent errors.
+///
+/// Unlike a causal chain, none of these errors caused any of the others.
+/// The `Error` containing this is already behind an `Arc`, so storing the
+/// errors inline here doesn't increase the size of `Error`.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct MultipleError {
+    errors: alloc::boxed::Box<[Error]>,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for MultipleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.errors.is_empty() {
+            return write!(f, "unknown jiff error (no errors were given)");
+        }
+        write!(f, "{} errors occurred: ", self.errors.len())?;
+        for (i, err) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            if f.alternate() {
+                write!(f, "{err:#}")?;
+            } else {
+                write!(f, "{err}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// A simple trait to encapsulate automatic conversion to `Error`.
 ///
 /// This trait basically exists to make `Error::context` work without needing
@@ -540,8 +1194,10 @@ pub(crate) trait ErrorContext {
     ///
     /// This is equivalent to saying that "consequent is caused by self."
     ///
-    /// Note that if an `Error` is given for `kind`, then this panics if it has
-    /// a cause. (Because the cause would otherwise be dropped. An error causal
+    /// If the consequent error already has its own cause (or chain of
+    /// causes), then `self` is attached as the cause of the innermost error
+    /// in the consequent's chain. That is, the consequent's chain is
+    /// preserved and `self` is appended to the end of it. (An error causal
     /// chain is just a linked list, not a tree.)
     fn context(self, consequent: impl IntoError) -> Self;
 
@@ -565,19 +1221,7 @@ impl ErrorContext for Error {
     fn context(self, consequent: impl IntoError) -> Error {
         #[cfg(feature = "alloc")]
         {
-            let mut err = consequent.into_error();
-            if err.inner.is_none() {
-                err = err!("unknown jiff error");
-            }
-            let inner = err.inner.as_mut().unwrap();
-            assert!(
-                inner.cause.is_none(),
-                "cause of consequence must be `None`"
-            );
-            // OK because we just created this error so the Arc
-            // has one reference.
-            Arc::get_mut(inner).unwrap().cause = Some(self);
-            err
+            consequent.into_error().attach_cause(self)
         }
         #[cfg(not(feature = "alloc"))]
         {
@@ -593,19 +1237,7 @@ impl ErrorContext for Error {
     ) -> Error {
         #[cfg(feature = "alloc")]
         {
-            let mut err = consequent().into_error();
-            if err.inner.is_none() {
-                err = err!("unknown jiff error");
-            }
-            let inner = err.inner.as_mut().unwrap();
-            assert!(
-                inner.cause.is_none(),
-                "cause of consequence must be `None`"
-            );
-            // OK because we just created this error so the Arc
-            // has one reference.
-            Arc::get_mut(inner).unwrap().cause = Some(self);
-            err
+            consequent().into_error().attach_cause(self)
         }
         #[cfg(not(feature = "alloc"))]
         {
@@ -632,6 +1264,8 @@ impl<T> ErrorContext for Result<T, Error> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{string::ToString, vec};
+
     use super::*;
 
     // We test that our 'Error' type is the size we expect. This isn't an API
@@ -665,4 +1299,326 @@ mod tests {
         }
         assert_eq!(expected_size, core::mem::size_of::<Error>());
     }
+
+    #[test]
+    fn root_cause() {
+        let err = err!("innermost");
+        assert_eq!(err.root_cause().to_string(), "innermost");
+
+        let err = Error::range("month", 13, 1, 12)
+            .context(err!("middle"))
+            .context(err!("outermost"));
+        if cfg!(feature = "alloc") {
+            assert_eq!(
+                err.to_string(),
+                "outermost: middle: parameter 'month' with value 13 \
+                 is not in the required range of 1..=12",
+            );
+            assert_eq!(
+                err.root_cause().to_string(),
+                "parameter 'month' with value 13 \
+                 is not in the required range of 1..=12",
+            );
+        } else {
+            assert_eq!(err.root_cause().to_string(), "outermost");
+        }
+    }
+
+    #[test]
+    fn debug_shows_causal_chain() {
+        let err = err!("innermost");
+        assert_eq!(alloc::format!("{err:?}"), "innermost");
+
+        let err = err!("inner").context(err!("outer"));
+        if cfg!(feature = "alloc") {
+            assert_eq!(
+                alloc::format!("{err:?}"),
+                "outer\n\nCaused by:\n    inner",
+            );
+        } else {
+            assert_eq!(alloc::format!("{err:?}"), "outer");
+        }
+
+        let err = err!("innermost")
+            .context(err!("middle"))
+            .context(err!("outermost"));
+        if cfg!(feature = "alloc") {
+            assert_eq!(
+                alloc::format!("{err:?}"),
+                "outermost\n\n\
+                 Caused by:\n    \
+                 0: middle\n    \
+                 1: innermost",
+            );
+            // The `Display` impl still shows everything on one line.
+            assert_eq!(err.to_string(), "outermost: middle: innermost");
+        } else {
+            assert_eq!(alloc::format!("{err:?}"), "outermost");
+        }
+    }
+
+    #[test]
+    fn context_with_chained_consequent() {
+        let consequent = err!("middle").context(err!("outermost"));
+        let err = err!("innermost").context(consequent);
+        if cfg!(feature = "alloc") {
+            assert_eq!(err.to_string(), "outermost: middle: innermost");
+            assert_eq!(err.root_cause().to_string(), "innermost");
+        } else {
+            assert_eq!(err.to_string(), "outermost");
+        }
+
+        // A shared consequent is left untouched.
+        let consequent =
+            err!("middle").context(Error::time_zone_lookup("Foo"));
+        let err = err!("innermost").context(consequent.clone());
+        if cfg!(feature = "alloc") {
+            assert_eq!(
+                err.to_string(),
+                "failed to find time zone `Foo` in time zone database: \
+                 middle: innermost",
+            );
+            assert_eq!(err.root_cause().to_string(), "innermost");
+            assert_eq!(
+                consequent.to_string(),
+                "failed to find time zone `Foo` in time zone database: \
+                 middle",
+            );
+            assert_eq!(consequent.time_zone_name(), Some("Foo"));
+        }
+    }
+
+    #[test]
+    fn time_zone_name() {
+        let err = err!("not a lookup failure");
+        assert_eq!(err.time_zone_name(), None);
+
+        let err = Error::time_zone_lookup("Mars/Olympus_Mons");
+        let err = err.context(err!("middle")).context(err!("outermost"));
+        if cfg!(feature = "alloc") {
+            assert_eq!(err.time_zone_name(), Some("Mars/Olympus_Mons"));
+            assert_eq!(
+                err.to_string(),
+                "outermost: middle: failed to find time zone \
+                 `Mars/Olympus_Mons` in time zone database",
+            );
+        } else {
+            assert_eq!(err.time_zone_name(), None);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let err = Error::time_zone_lookup("Mars/Olympus_Mons")
+                .path("/usr/share/zoneinfo")
+                .context(err!("outermost"));
+            assert_eq!(err.time_zone_name(), Some("Mars/Olympus_Mons"));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn retryable() {
+        use std::io;
+
+        let io_err = |kind: io::ErrorKind| {
+            Error::io(io::Error::from(kind))
+                .path("/usr/share/zoneinfo/America/New_York")
+                .context(err!("failed to read time zone"))
+        };
+        for kind in [
+            io::ErrorKind::Interrupted,
+            io::ErrorKind::WouldBlock,
+            io::ErrorKind::TimedOut,
+        ] {
+            let err = io_err(kind);
+            assert_eq!(err.io_error_kind(), Some(kind));
+            assert!(err.is_retryable(), "{kind:?}");
+        }
+        for kind in [
+            io::ErrorKind::NotFound,
+            io::ErrorKind::PermissionDenied,
+            io::ErrorKind::InvalidData,
+        ] {
+            let err = io_err(kind);
+            assert_eq!(err.io_error_kind(), Some(kind));
+            assert!(!err.is_retryable(), "{kind:?}");
+        }
+
+        let err = Error::range("month", 13, 1, 12).context(err!("outer"));
+        assert_eq!(err.io_error_kind(), None);
+        assert!(!err.is_retryable());
+
+        let err = Error::time_zone_lookup("America/Nowhere");
+        assert!(!err.is_retryable());
+    }
+
+    /// Tests that every kind of error maps to its documented code.
+    #[test]
+    fn code() {
+        let shared = crate::shared::util::error::err!("shared");
+        let mut table = vec![
+            (err!("ad hoc"), "other"),
+            (parse_err!("parse"), "parse"),
+            (Error::range("month", 13, 1, 12), "range"),
+            (Error::shared(shared), "other"),
+            (Error::time_zone_lookup("America/Nowhere"), "tz-lookup"),
+            (Error { inner: None }, "other"),
+        ];
+        #[cfg(feature = "std")]
+        {
+            let io = std::io::Error::from(std::io::ErrorKind::NotFound);
+            table.push((Error::io(io), "io"));
+            let path = FilePathError { path: "/usr/share/zoneinfo".into() };
+            table.push((Error::from(ErrorKind::FilePath(path)), "io"));
+        }
+        #[cfg(feature = "alloc")]
+        {
+            let errs = [err!("first"), err!("second")];
+            table.push((Error::from_errors(errs), "multiple"));
+        }
+        for (err, code) in table {
+            assert_eq!(err.code(), code, "{err:?}");
+            // Context never changes the code, since it is determined by
+            // the root cause.
+            if cfg!(feature = "alloc") {
+                assert_eq!(err.context(err!("outer")).code(), code);
+            }
+        }
+
+        let err = "2024-06-19T15:22".parse::<crate::Zoned>().unwrap_err();
+        assert_eq!(err.code(), "parse");
+        let err = "2024-13-01".parse::<crate::civil::Date>().unwrap_err();
+        assert_eq!(
+            err.code(),
+            if cfg!(feature = "alloc") { "range" } else { "parse" }
+        );
+    }
+
+    #[test]
+    fn category() {
+        use self::ErrorCategory::*;
+
+        let all = [Range, TimeZoneLookup, Io, Parse, Multiple, Other];
+        for (i, &cat1) in all.iter().enumerate() {
+            for (j, &cat2) in all.iter().enumerate() {
+                assert_eq!(i == j, cat1 == cat2, "{cat1:?} vs {cat2:?}");
+                assert_eq!(i == j, cat1.code() == cat2.code());
+            }
+        }
+
+        assert_eq!(err!("ad hoc").category(), Other);
+        assert_eq!(Error::range("month", 13, 1, 12).category(), Range);
+        assert_eq!(
+            Error::time_zone_lookup("America/Nowhere").category(),
+            TimeZoneLookup,
+        );
+        let shared = crate::shared::util::error::err!("shared");
+        assert_eq!(Error::shared(shared).category(), Other);
+        assert_eq!(Error { inner: None }.category(), Other);
+
+        #[cfg(feature = "std")]
+        {
+            let err =
+                Error::io(std::io::Error::from(std::io::ErrorKind::NotFound))
+                    .context(err!("failed to read time zone"));
+            assert_eq!(err.category(), Io);
+            assert_ne!(err.category(), Other);
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let err = Error::from_errors([err!("first"), err!("second")]);
+            assert_eq!(err.category(), Multiple);
+
+            let err = Error::time_zone_lookup("America/Nowhere")
+                .context(err!("outer"));
+            assert_eq!(err.category(), TimeZoneLookup);
+            assert!(matches!(err.category(), Range | TimeZoneLookup));
+            assert!(!matches!(err.category(), Range | Other));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn category_parse_trailing_input() {
+        use crate::{
+            civil::{Date, ISOWeekDate},
+            tz::Offset,
+            Span,
+        };
+
+        let err = "2024-01-01x".parse::<Date>().unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Parse);
+        let err = "+05:30 ".parse::<Offset>().unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Parse);
+        let err = Span::parse_friendly("2h 30m!").unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Parse);
+        let err = "2024-W05-3T00".parse::<ISOWeekDate>().unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Parse);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_errors() {
+        let err = Error::from_errors([
+            err!("first"),
+            Error::range("month", 13, 1, 12).context(err!("second")),
+            Error::time_zone_lookup("America/Nowhere"),
+        ]);
+        assert_eq!(
+            err.to_string(),
+            "3 errors occurred: first; \
+             second: parameter 'month' with value 13 \
+             is not in the required range of 1..=12; \
+             failed to find time zone `America/Nowhere` \
+             in time zone database",
+        );
+
+        // The aggregate error can itself be contextualized.
+        let err = err.context(err!("batch failed"));
+        assert!(err.to_string().starts_with("batch failed: 3 errors"));
+
+        // A single error is returned unchanged.
+        let err = Error::from_errors([err!("only")]);
+        assert_eq!(err.to_string(), "only");
+
+        let err = Error::from_errors([]);
+        assert_eq!(
+            err.to_string(),
+            "unknown jiff error (no errors were given)",
+        );
+        assert_eq!(err.category(), ErrorCategory::Multiple);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn long_adhoc_message_is_truncated() {
+        let message = "x".repeat(1_000);
+        let err = err!("{message}");
+        let got = err.to_string();
+        assert_eq!(
+            got.chars().count(),
+            512 + "… (488 more chars)".chars().count()
+        );
+        assert!(got.starts_with(&message[..512]));
+        assert!(got.ends_with("x… (488 more chars)"));
+        assert_eq!(err.to_untruncated_string(), message);
+        assert_eq!(alloc::format!("{err:#}"), message);
+
+        // Each message in the causal chain is truncated independently.
+        let err = err!("{message}").context(err!("outer"));
+        assert_eq!(
+            err.to_string(),
+            alloc::format!("outer: {}… (488 more chars)", &message[..512]),
+        );
+        assert_eq!(
+            err.to_untruncated_string(),
+            alloc::format!("outer: {message}")
+        );
+
+        // Messages at the limit are left alone.
+        let message = "☃".repeat(512);
+        let err = err!("{message}");
+        assert_eq!(err.to_string(), message);
+    }
 }
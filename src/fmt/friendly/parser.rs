@@ -1,5 +1,5 @@
 use crate::{
-    error::{err, ErrorContext},
+    error::{parse_err, ErrorContext},
     fmt::{
         friendly::parser_label,
         util::{
@@ -183,13 +183,13 @@ impl SpanParser {
     pub fn parse_span<I: AsRef<[u8]>>(&self, input: I) -> Result<Span, Error> {
         let input = input.as_ref();
         let parsed = self.parse_to_span(input).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {input:?} in the \"friendly\" format",
                 input = escape::Bytes(input)
             )
         })?;
-        let span = parsed.into_full().with_context(|| {
-            err!(
+        let span = parsed.into_full_from(input).with_context(|| {
+            parse_err!(
                 "failed to parse {input:?} in the \"friendly\" format",
                 input = escape::Bytes(input)
             )
@@ -238,13 +238,13 @@ impl SpanParser {
     ) -> Result<SignedDuration, Error> {
         let input = input.as_ref();
         let parsed = self.parse_to_duration(input).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {input:?} in the \"friendly\" format",
                 input = escape::Bytes(input)
             )
         })?;
-        let sdur = parsed.into_full().with_context(|| {
-            err!(
+        let sdur = parsed.into_full_from(input).with_context(|| {
+            parse_err!(
                 "failed to parse {input:?} in the \"friendly\" format",
                 input = escape::Bytes(input)
             )
@@ -257,8 +257,9 @@ impl SpanParser {
         &self,
         input: &'i [u8],
     ) -> Result<Parsed<'i, Span>, Error> {
+        let full = input;
         if input.is_empty() {
-            return Err(err!("an empty string is not a valid duration"));
+            return Err(parse_err!("an empty string is not a valid duration"));
         }
         // Guard prefix sign parsing to avoid the function call, which is
         // marked unlineable to keep the fast path tighter.
@@ -273,14 +274,14 @@ impl SpanParser {
 
         let Parsed { value, input } = self.parse_unit_value(input)?;
         let Some(first_unit_value) = value else {
-            return Err(err!(
+            return Err(parse_err!(
                 "parsing a friendly duration requires it to start \
                  with a unit value (a decimal integer) after an \
                  optional sign, but no integer was found",
             ));
         };
         let Parsed { value: span, input } =
-            self.parse_units_to_span(input, first_unit_value)?;
+            self.parse_units_to_span(full, input, first_unit_value)?;
 
         // As with the prefix sign parsing, guard it to avoid calling the
         // function.
@@ -298,8 +299,9 @@ impl SpanParser {
         &self,
         input: &'i [u8],
     ) -> Result<Parsed<'i, SignedDuration>, Error> {
+        let full = input;
         if input.is_empty() {
-            return Err(err!("an empty string is not a valid duration"));
+            return Err(parse_err!("an empty string is not a valid duration"));
         }
         // Guard prefix sign parsing to avoid the function call, which is
         // marked unlineable to keep the fast path tighter.
@@ -314,14 +316,14 @@ impl SpanParser {
 
         let Parsed { value, input } = self.parse_unit_value(input)?;
         let Some(first_unit_value) = value else {
-            return Err(err!(
+            return Err(parse_err!(
                 "parsing a friendly duration requires it to start \
                  with a unit value (a decimal integer) after an \
                  optional sign, but no integer was found",
             ));
         };
         let Parsed { value: mut sdur, input } =
-            self.parse_units_to_duration(input, first_unit_value)?;
+            self.parse_units_to_duration(full, input, first_unit_value)?;
 
         // As with the prefix sign parsing, guard it to avoid calling the
         // function.
@@ -341,6 +343,7 @@ impl SpanParser {
     #[inline(always)]
     fn parse_units_to_span<'i>(
         &self,
+        full: &[u8],
         mut input: &'i [u8],
         first_unit_value: t::NoUnits,
     ) -> Result<Parsed<'i, Span>, Error> {
@@ -354,7 +357,7 @@ impl SpanParser {
             if let Some(hms) = parsed.value {
                 if let Some(prev_unit) = prev_unit {
                     if prev_unit <= Unit::Hour {
-                        return Err(err!(
+                        return Err(parse_err!(
                             "found 'HH:MM:SS' after unit {prev_unit}, \
                              but 'HH:MM:SS' can only appear after \
                              years, months, weeks or days",
@@ -390,7 +393,7 @@ impl SpanParser {
             input = self.parse_optional_whitespace(input).input;
 
             // Parse the actual unit label/designator.
-            let parsed = self.parse_unit_designator(input)?;
+            let parsed = self.parse_unit_designator(full, input)?;
             input = parsed.input;
             let unit = parsed.value;
 
@@ -405,7 +408,7 @@ impl SpanParser {
 
             if let Some(prev_unit) = prev_unit {
                 if prev_unit <= unit {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found value {value:?} with unit {unit} \
                          after unit {prev_unit}, but units must be \
                          written from largest to smallest \
@@ -440,7 +443,7 @@ impl SpanParser {
             parsed_any_after_comma = true;
         }
         if !parsed_any_after_comma {
-            return Err(err!(
+            return Err(parse_err!(
                 "found comma at the end of duration, \
                  but a comma indicates at least one more \
                  unit follows and none were found after \
@@ -456,6 +459,7 @@ impl SpanParser {
     #[inline(always)]
     fn parse_units_to_duration<'i>(
         &self,
+        full: &[u8],
         mut input: &'i [u8],
         first_unit_value: t::NoUnits,
     ) -> Result<Parsed<'i, SignedDuration>, Error> {
@@ -469,7 +473,7 @@ impl SpanParser {
             if let Some(hms) = parsed.value {
                 if let Some(prev_unit) = prev_unit {
                     if prev_unit <= Unit::Hour {
-                        return Err(err!(
+                        return Err(parse_err!(
                             "found 'HH:MM:SS' after unit {prev_unit}, \
                              but 'HH:MM:SS' can only appear after \
                              years, months, weeks or days",
@@ -480,7 +484,7 @@ impl SpanParser {
                 sdur = sdur
                     .checked_add(duration_unit_value(Unit::Hour, hms.hour)?)
                     .ok_or_else(|| {
-                        err!(
+                        parse_err!(
                             "accumulated `SignedDuration` overflowed when \
                              adding {value} of unit hour",
                         )
@@ -491,7 +495,7 @@ impl SpanParser {
                         hms.minute,
                     )?)
                     .ok_or_else(|| {
-                        err!(
+                        parse_err!(
                             "accumulated `SignedDuration` overflowed when \
                              adding {value} of unit minute",
                         )
@@ -502,7 +506,7 @@ impl SpanParser {
                         hms.second,
                     )?)
                     .ok_or_else(|| {
-                        err!(
+                        parse_err!(
                             "accumulated `SignedDuration` overflowed when \
                              adding {value} of unit second",
                         )
@@ -510,7 +514,8 @@ impl SpanParser {
                 if let Some(f) = hms.fraction {
                     // nanos += fractional_time_to_nanos(Unit::Second, fraction)?;
                     let f = fractional_time_to_duration(Unit::Second, f)?;
-                    sdur = sdur.checked_add(f).ok_or_else(|| err!(""))?;
+                    sdur =
+                        sdur.checked_add(f).ok_or_else(|| parse_err!(""))?;
                 };
                 break;
             }
@@ -528,7 +533,7 @@ impl SpanParser {
             input = self.parse_optional_whitespace(input).input;
 
             // Parse the actual unit label/designator.
-            let parsed = self.parse_unit_designator(input)?;
+            let parsed = self.parse_unit_designator(full, input)?;
             input = parsed.input;
             let unit = parsed.value;
 
@@ -543,7 +548,7 @@ impl SpanParser {
 
             if let Some(prev_unit) = prev_unit {
                 if prev_unit <= unit {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found value {value:?} with unit {unit} \
                          after unit {prev_unit}, but units must be \
                          written from largest to smallest \
@@ -558,7 +563,7 @@ impl SpanParser {
             sdur = sdur
                 .checked_add(duration_unit_value(unit, value)?)
                 .ok_or_else(|| {
-                    err!(
+                    parse_err!(
                         "accumulated `SignedDuration` overflowed when adding \
                          {value} of unit {unit}",
                         unit = unit.singular(),
@@ -566,7 +571,7 @@ impl SpanParser {
                 })?;
             if let Some(f) = fraction {
                 let f = fractional_time_to_duration(unit, f)?;
-                sdur = sdur.checked_add(f).ok_or_else(|| err!(""))?;
+                sdur = sdur.checked_add(f).ok_or_else(|| parse_err!(""))?;
                 // Once we see a fraction, we are done. We don't permit parsing
                 // any more units. That is, a fraction can only occur on the
                 // lowest unit of time.
@@ -586,7 +591,7 @@ impl SpanParser {
             parsed_any_after_comma = true;
         }
         if !parsed_any_after_comma {
-            return Err(err!(
+            return Err(parse_err!(
                 "found comma at the end of duration, \
                  but a comma indicates at least one more \
                  unit follows and none were found after \
@@ -634,13 +639,13 @@ impl SpanParser {
     ) -> Result<Parsed<'i, HMS>, Error> {
         let Parsed { input, value } = self.parse_unit_value(input)?;
         let Some(minute) = value else {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to parse minute in 'HH:MM:SS' format \
                  following parsed hour of {hour}",
             ));
         };
         if !input.first().map_or(false, |&b| b == b':') {
-            return Err(err!(
+            return Err(parse_err!(
                 "when parsing 'HH:MM:SS' format, expected to \
                  see a ':' after the parsed minute of {minute}",
             ));
@@ -648,7 +653,7 @@ impl SpanParser {
         let input = &input[1..];
         let Parsed { input, value } = self.parse_unit_value(input)?;
         let Some(second) = value else {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to parse second in 'HH:MM:SS' format \
                  following parsed minute of {minute}",
             ));
@@ -696,13 +701,13 @@ impl SpanParser {
             // `util::parse::i64` could be better designed? Meh.)
             let digit = match byte.checked_sub(b'0') {
                 None => {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "invalid digit, expected 0-9 but got {}",
                         escape::Byte(byte),
                     ));
                 }
                 Some(digit) if digit > 9 => {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "invalid digit, expected 0-9 but got {}",
                         escape::Byte(byte),
                     ))
@@ -716,7 +721,7 @@ impl SpanParser {
                 .checked_mul(10)
                 .and_then(|n| n.checked_add(digit))
                 .ok_or_else(|| {
-                    err!(
+                    parse_err!(
                         "number '{}' too big to parse into 64-bit integer",
                         escape::Bytes(&input[..digit_count]),
                     )
@@ -741,21 +746,24 @@ impl SpanParser {
     #[inline(always)]
     fn parse_unit_designator<'i>(
         &self,
+        full: &[u8],
         input: &'i [u8],
     ) -> Result<Parsed<'i, Unit>, Error> {
         let Some((unit, len)) = parser_label::find(input) else {
             if input.is_empty() {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected to find unit designator suffix \
                      (e.g., 'years' or 'secs'), \
                      but found end of input",
                 ));
             } else {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected to find unit designator suffix \
                      (e.g., 'years' or 'secs'), \
-                     but found input beginning with {found:?} instead",
+                     but found input beginning with {found:?} \
+                     at byte offset {offset} instead",
                     found = escape::Bytes(&input[..input.len().min(20)]),
+                    offset = full.len() - input.len(),
                 ));
             }
         };
@@ -816,7 +824,7 @@ impl SpanParser {
         };
         let sign = match (prefix_sign, suffix_sign) {
             (Some(_), Some(_)) => {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected to find either a prefix sign (+/-) or \
                      a suffix sign (ago), but found both",
                 ))
@@ -847,12 +855,12 @@ impl SpanParser {
         }
         input = &input[1..];
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected whitespace after comma, but found end of input"
             ));
         }
         if !is_whitespace(&input[0]) {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected whitespace after comma, but found {found:?}",
                 found = escape::Byte(input[0]),
             ));
@@ -894,7 +902,7 @@ fn set_span_unit_value(
 ) -> Result<Span, Error> {
     if unit <= Unit::Hour {
         let result = span.try_units_ranged(unit, value).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to set value {value:?} \
                  as {unit} unit on span",
                 unit = Unit::from(unit).singular(),
@@ -915,7 +923,7 @@ fn set_span_unit_value(
         };
     } else {
         span = span.try_units_ranged(unit, value).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to set value {value:?} \
                  as {unit} unit on span",
                 unit = Unit::from(unit).singular(),
@@ -948,7 +956,9 @@ fn duration_unit_value(
         Unit::Hour => {
             let seconds =
                 value.checked_mul(t::SECONDS_PER_HOUR).ok_or_else(|| {
-                    err!("converting {value} hours to seconds overflows i64")
+                    parse_err!(
+                        "converting {value} hours to seconds overflows i64"
+                    )
                 })?;
             SignedDuration::from_secs(seconds.get())
         }
@@ -964,7 +974,7 @@ fn duration_unit_value(
         Unit::Microsecond => SignedDuration::from_micros(value.get()),
         Unit::Nanosecond => SignedDuration::from_nanos(value.get()),
         unsupported => {
-            return Err(err!(
+            return Err(parse_err!(
                 "parsing {unit} units into a `SignedDuration` is not supported \
                  (perhaps try parsing into a `Span` instead)",
                 unit = unsupported.singular(),
@@ -1086,7 +1096,7 @@ mod tests {
         );
         insta::assert_snapshot!(
             p("1 year 1 mont"),
-            @r###"failed to parse "1 year 1 mont" in the "friendly" format: parsed value 'P1Y1M', but unparsed input "nt" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1 year 1 mont" in the "friendly" format: parsed value 'P1Y1M', but unparsed input "nt" remains at byte offset 11 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("2 months,"),
@@ -1098,7 +1108,7 @@ mod tests {
         );
         insta::assert_snapshot!(
             p("2 months ,"),
-            @r###"failed to parse "2 months ," in the "friendly" format: parsed value 'P2M', but unparsed input "," remains (expected no unparsed input)"###,
+            @r###"failed to parse "2 months ," in the "friendly" format: parsed value 'P2M', but unparsed input "," remains at byte offset 9 (expected no unparsed input)"###,
         );
     }
 
@@ -1108,11 +1118,11 @@ mod tests {
 
         insta::assert_snapshot!(
             p("1yago"),
-            @r###"failed to parse "1yago" in the "friendly" format: parsed value 'P1Y', but unparsed input "ago" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1yago" in the "friendly" format: parsed value 'P1Y', but unparsed input "ago" remains at byte offset 2 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("1 year 1 monthago"),
-            @r###"failed to parse "1 year 1 monthago" in the "friendly" format: parsed value 'P1Y1M', but unparsed input "ago" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1 year 1 monthago" in the "friendly" format: parsed value 'P1Y1M', but unparsed input "ago" remains at byte offset 14 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("+1 year 1 month ago"),
@@ -1382,7 +1392,7 @@ mod tests {
         );
         insta::assert_snapshot!(
             p("1 hour 1 minut"),
-            @r###"failed to parse "1 hour 1 minut" in the "friendly" format: parsed value 'PT1H1M', but unparsed input "ut" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1 hour 1 minut" in the "friendly" format: parsed value 'PT1H1M', but unparsed input "ut" remains at byte offset 12 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("2 minutes,"),
@@ -1394,7 +1404,7 @@ mod tests {
         );
         insta::assert_snapshot!(
             p("2 minutes ,"),
-            @r###"failed to parse "2 minutes ," in the "friendly" format: parsed value 'PT2M', but unparsed input "," remains (expected no unparsed input)"###,
+            @r###"failed to parse "2 minutes ," in the "friendly" format: parsed value 'PT2M', but unparsed input "," remains at byte offset 10 (expected no unparsed input)"###,
         );
     }
 
@@ -1404,11 +1414,11 @@ mod tests {
 
         insta::assert_snapshot!(
             p("1hago"),
-            @r###"failed to parse "1hago" in the "friendly" format: parsed value 'PT1H', but unparsed input "ago" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1hago" in the "friendly" format: parsed value 'PT1H', but unparsed input "ago" remains at byte offset 2 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("1 hour 1 minuteago"),
-            @r###"failed to parse "1 hour 1 minuteago" in the "friendly" format: parsed value 'PT1H1M', but unparsed input "ago" remains (expected no unparsed input)"###,
+            @r###"failed to parse "1 hour 1 minuteago" in the "friendly" format: parsed value 'PT1H1M', but unparsed input "ago" remains at byte offset 15 (expected no unparsed input)"###,
         );
         insta::assert_snapshot!(
             p("+1 hour 1 minute ago"),
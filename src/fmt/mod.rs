@@ -166,20 +166,22 @@ and features.)
 */
 
 use crate::{
-    error::{err, Error},
+    error::{err, parse_err, Error},
     util::escape,
 };
 
 use self::util::{Decimal, DecimalFormatter, Fractional, FractionalFormatter};
 
 pub mod friendly;
-mod offset;
+pub(crate) mod offset;
 pub mod rfc2822;
 mod rfc9557;
 #[cfg(feature = "serde")]
 pub mod serde;
 pub mod strtime;
 pub mod temporal;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 mod util;
 
 /// The result of parsing a value out of a slice of bytes.
@@ -207,13 +209,31 @@ impl<'i, V: core::fmt::Display> Parsed<'i, V> {
         if self.input.is_empty() {
             return Ok(self.value);
         }
-        Err(err!(
+        Err(parse_err!(
             "parsed value '{value}', but unparsed input {unparsed:?} \
              remains (expected no unparsed input)",
             value = self.value,
             unparsed = escape::Bytes(self.input),
         ))
     }
+
+    /// Like `into_full`, except the error reports the byte offset into
+    /// `full` at which the unparsed input begins.
+    ///
+    /// `full` must be the entire input that this value was parsed from.
+    #[inline]
+    fn into_full_from(self, full: &[u8]) -> Result<V, Error> {
+        if self.input.is_empty() {
+            return Ok(self.value);
+        }
+        Err(parse_err!(
+            "parsed value '{value}', but unparsed input {unparsed:?} \
+             remains at byte offset {offset} (expected no unparsed input)",
+            value = self.value,
+            unparsed = escape::Bytes(self.input),
+            offset = full.len() - self.input.len(),
+        ))
+    }
 }
 
 impl<'i, V: core::fmt::Debug> core::fmt::Debug for Parsed<'i, V> {
@@ -104,7 +104,7 @@ from [Temporal's hybrid grammar].
 // support a span of time of about 52 hours or so.)
 
 use crate::{
-    error::{err, Error, ErrorContext},
+    error::{parse_err, Error, ErrorContext},
     fmt::{
         temporal::{PiecesNumericOffset, PiecesOffset},
         util::{parse_temporal_fraction, FractionalFormatter},
@@ -185,6 +185,19 @@ impl ParsedOffset {
     }
 }
 
+// This impl is just used for error messages when a parsed offset is followed
+// by unexpected input.
+impl core::fmt::Display for ParsedOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.kind {
+            ParsedOffsetKind::Zulu => write!(f, "Z"),
+            ParsedOffsetKind::Numeric(ref numeric) => {
+                core::fmt::Display::fmt(numeric, f)
+            }
+        }
+    }
+}
+
 /// The kind of a parsed offset.
 #[derive(Debug)]
 enum ParsedOffsetKind {
@@ -232,7 +245,7 @@ impl Numeric {
                 seconds = seconds
                     .try_checked_add("offset-seconds", C(1))
                     .with_context(|| {
-                        err!(
+                        parse_err!(
                             "due to precision loss, UTC offset '{}' is \
                              rounded to a value that is out of bounds",
                             self,
@@ -368,12 +381,14 @@ impl Parser {
         mut input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedOffset>, Error> {
         if input.is_empty() {
-            return Err(err!("expected UTC offset, but found end of input"));
+            return Err(parse_err!(
+                "expected UTC offset, but found end of input"
+            ));
         }
 
         if input[0] == b'Z' || input[0] == b'z' {
             if !self.zulu {
-                return Err(err!(
+                return Err(parse_err!(
                     "found {z:?} in {original:?} where a numeric UTC offset \
                      was expected (this context does not permit \
                      the Zulu offset)",
@@ -390,6 +405,16 @@ impl Parser {
         Ok(Parsed { value, input })
     }
 
+    /// Like `parse`, but returns an error if the offset parsed isn't the
+    /// entire input. The error reports the byte offset at which the unparsed
+    /// input begins.
+    pub(crate) fn parse_full(
+        &self,
+        input: &[u8],
+    ) -> Result<ParsedOffset, Error> {
+        self.parse(input)?.into_full_from(input)
+    }
+
     /// Like `parse`, but will return `None` if `input` cannot possibly start
     /// with an offset.
     ///
@@ -424,13 +449,15 @@ impl Parser {
         // Parse sign component.
         let Parsed { value: sign, input } =
             self.parse_sign(input).with_context(|| {
-                err!("failed to parse sign in UTC numeric offset {original:?}")
+                parse_err!(
+                    "failed to parse sign in UTC numeric offset {original:?}"
+                )
             })?;
 
         // Parse hours component.
         let Parsed { value: hours, input } =
             self.parse_hours(input).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse hours in UTC numeric offset {original:?}"
                 )
             })?;
@@ -448,7 +475,7 @@ impl Parser {
         // Parse optional separator after hours.
         let Parsed { value: has_minutes, input } =
             self.parse_separator(input, extended).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse separator after hours in \
                      UTC numeric offset {original:?}"
                 )
@@ -460,7 +487,7 @@ impl Parser {
         // Parse minutes component.
         let Parsed { value: minutes, input } =
             self.parse_minutes(input).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse minutes in UTC numeric offset \
                      {original:?}"
                 )
@@ -476,7 +503,7 @@ impl Parser {
             // If this winds up being problematic, we can make this error
             // configuration or remove it altogether (unfortunate).
             if input.get(0).map_or(false, |&b| b == b':') {
-                return Err(err!(
+                return Err(parse_err!(
                     "subminute precision for UTC numeric offset {original:?} \
                      is not enabled in this context (must provide only \
                      integral minutes)",
@@ -488,7 +515,7 @@ impl Parser {
         // Parse optional separator after minutes.
         let Parsed { value: has_seconds, input } =
             self.parse_separator(input, extended).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse separator after minutes in \
                      UTC numeric offset {original:?}"
                 )
@@ -500,7 +527,7 @@ impl Parser {
         // Parse seconds component.
         let Parsed { value: seconds, input } =
             self.parse_seconds(input).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse seconds in UTC numeric offset \
                      {original:?}"
                 )
@@ -510,7 +537,7 @@ impl Parser {
         // If subsecond resolution is not supported, then we're done here.
         if !self.subsecond {
             if input.get(0).map_or(false, |&b| b == b'.' || b == b',') {
-                return Err(err!(
+                return Err(parse_err!(
                     "subsecond precision for UTC numeric offset {original:?} \
                      is not enabled in this context (must provide only \
                      integral minutes or seconds)",
@@ -522,7 +549,7 @@ impl Parser {
         // Parse an optional fractional component.
         let Parsed { value: nanoseconds, input } =
             parse_temporal_fraction(input).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse fractional nanoseconds in \
                      UTC numeric offset {original:?}",
                 )
@@ -537,14 +564,14 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Sign>, Error> {
         let sign = input.get(0).copied().ok_or_else(|| {
-            err!("expected UTC numeric offset, but found end of input")
+            parse_err!("expected UTC numeric offset, but found end of input")
         })?;
         let sign = if sign == b'+' {
             t::Sign::N::<1>()
         } else if sign == b'-' {
             t::Sign::N::<-1>()
         } else {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected '+' or '-' sign at start of UTC numeric offset, \
                  but found {found:?} instead",
                 found = escape::Byte(sign),
@@ -559,10 +586,12 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedOffsetHours>, Error> {
         let (hours, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit hour after sign, but found end of input",)
+            parse_err!(
+                "expected two digit hour after sign, but found end of input",
+            )
         })?;
         let hours = parse::i64(hours).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {hours:?} as hours (a two digit integer)",
                 hours = escape::Bytes(hours),
             )
@@ -583,13 +612,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedOffsetMinutes>, Error> {
         let (minutes, input) = parse::split(input, 2).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected two digit minute after hours, \
                  but found end of input",
             )
         })?;
         let minutes = parse::i64(minutes).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {minutes:?} as minutes (a two digit integer)",
                 minutes = escape::Bytes(minutes),
             )
@@ -605,13 +634,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedOffsetSeconds>, Error> {
         let (seconds, input) = parse::split(input, 2).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected two digit second after hours, \
                  but found end of input",
             )
         })?;
         let seconds = parse::i64(seconds).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {seconds:?} as seconds (a two digit integer)",
                 seconds = escape::Bytes(seconds),
             )
@@ -43,7 +43,7 @@ general interchange format for new applications.
 
 use crate::{
     civil::{Date, DateTime, Time, Weekday},
-    error::{err, ErrorContext},
+    error::{err, parse_err, ErrorContext},
     fmt::{util::DecimalFormatter, Parsed, Write, WriteExt},
     tz::{Offset, TimeZone},
     util::{
@@ -425,13 +425,13 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, DateTime>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected RFC 2822 datetime, but got empty string"
             ));
         }
         let Parsed { input, .. } = self.skip_whitespace(input);
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected RFC 2822 datetime, but got empty string after \
                  trimming whitespace",
             ));
@@ -451,9 +451,10 @@ impl DateTimeParser {
             let Parsed { value: second, input } = self.parse_second(input)?;
             (second, input)
         };
-        let Parsed { input, .. } = self
-            .parse_whitespace(input)
-            .with_context(|| err!("expected whitespace after parsing time"))?;
+        let Parsed { input, .. } =
+            self.parse_whitespace(input).with_context(|| {
+                parse_err!("expected whitespace after parsing time")
+            })?;
 
         let date =
             Date::new_ranged(year, month, day).context("invalid date")?;
@@ -466,7 +467,7 @@ impl DateTimeParser {
         let dt = DateTime::from_parts(date, time);
         if let Some(wd) = wd {
             if !self.relaxed_weekday && wd != dt.weekday() {
-                return Err(err!(
+                return Err(parse_err!(
                     "found parsed weekday of {parsed}, \
                      but parsed datetime of {dt} has weekday \
                      {has}",
@@ -504,7 +505,7 @@ impl DateTimeParser {
             return Ok(Parsed { value: None, input });
         }
         if input.len() < 4 {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected day at beginning of RFC 2822 datetime \
                  since first non-whitespace byte, {first:?}, \
                  is not a digit, but given string is too short \
@@ -525,7 +526,7 @@ impl DateTimeParser {
             b"fri" => Weekday::Friday,
             b"sat" => Weekday::Saturday,
             _ => {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected day at beginning of RFC 2822 datetime \
                      since first non-whitespace byte, {first:?}, \
                      is not a digit, but did not recognize {got:?} \
@@ -536,7 +537,7 @@ impl DateTimeParser {
             }
         };
         if input[3] != b',' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected day at beginning of RFC 2822 datetime \
                  since first non-whitespace byte, {first:?}, \
                  is not a digit, but found {got:?} after parsed \
@@ -548,7 +549,7 @@ impl DateTimeParser {
         }
         let Parsed { input, .. } =
             self.parse_whitespace(&input[4..]).with_context(|| {
-                err!(
+                parse_err!(
                     "expected whitespace after parsing {got:?}",
                     got = escape::Bytes(&input[..4]),
                 )
@@ -569,7 +570,7 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Day>, Error> {
         if input.is_empty() {
-            return Err(err!("expected day, but found end of input"));
+            return Err(parse_err!("expected day, but found end of input"));
         }
         let mut digits = 1;
         if input.len() >= 2 && matches!(input[1], b'0'..=b'9') {
@@ -577,12 +578,15 @@ impl DateTimeParser {
         }
         let (day, input) = input.split_at(digits);
         let day = parse::i64(day).with_context(|| {
-            err!("failed to parse {day:?} as day", day = escape::Bytes(day))
+            parse_err!(
+                "failed to parse {day:?} as day",
+                day = escape::Bytes(day)
+            )
         })?;
         let day = t::Day::try_new("day", day).context("day is not valid")?;
         let Parsed { input, .. } =
             self.parse_whitespace(input).with_context(|| {
-                err!("expected whitespace after parsing day {day}")
+                parse_err!("expected whitespace after parsing day {day}")
             })?;
         Ok(Parsed { value: day, input })
     }
@@ -600,12 +604,12 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Month>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected abbreviated month name, but found end of input"
             ));
         }
         if input.len() < 3 {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected abbreviated month name, but remaining input \
                  is too short (remaining bytes is {length})",
                 length = input.len(),
@@ -628,7 +632,7 @@ impl DateTimeParser {
             b"nov" => 11,
             b"dec" => 12,
             _ => {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected abbreviated month name, \
                      but did not recognize {got:?} \
                      as a valid month",
@@ -641,7 +645,7 @@ impl DateTimeParser {
         let month = t::Month::new(month).unwrap();
         let Parsed { input, .. } =
             self.parse_whitespace(&input[3..]).with_context(|| {
-                err!("expected whitespace after parsing month name")
+                parse_err!("expected whitespace after parsing month name")
             })?;
         Ok(Parsed { value: month, input })
     }
@@ -676,14 +680,14 @@ impl DateTimeParser {
             digits += 1;
         }
         if digits <= 1 {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected at least two ASCII digits for parsing \
                  a year, but only found {digits}",
             ));
         }
         let (year, input) = input.split_at(digits);
         let year = parse::i64(year).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {year:?} as year \
                  (a two, three or four digit integer)",
                 year = escape::Bytes(year),
@@ -697,9 +701,10 @@ impl DateTimeParser {
         };
         let year =
             t::Year::try_new("year", year).context("year is not valid")?;
-        let Parsed { input, .. } = self
-            .parse_whitespace(input)
-            .with_context(|| err!("expected whitespace after parsing year"))?;
+        let Parsed { input, .. } =
+            self.parse_whitespace(input).with_context(|| {
+                parse_err!("expected whitespace after parsing year")
+            })?;
         Ok(Parsed { value: year, input })
     }
 
@@ -714,10 +719,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Hour>, Error> {
         let (hour, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit hour, but found end of input")
+            parse_err!("expected two digit hour, but found end of input")
         })?;
         let hour = parse::i64(hour).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {hour:?} as hour (a two digit integer)",
                 hour = escape::Bytes(hour),
             )
@@ -735,10 +740,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Minute>, Error> {
         let (minute, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit minute, but found end of input")
+            parse_err!("expected two digit minute, but found end of input")
         })?;
         let minute = parse::i64(minute).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {minute:?} as minute (a two digit integer)",
                 minute = escape::Bytes(minute),
             )
@@ -756,10 +761,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Second>, Error> {
         let (second, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit second, but found end of input")
+            parse_err!("expected two digit second, but found end of input")
         })?;
         let mut second = parse::i64(second).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {second:?} as second (a two digit integer)",
                 second = escape::Bytes(second),
             )
@@ -785,7 +790,7 @@ impl DateTimeParser {
         type ParsedOffsetMinutes = ri8<0, { t::SpanZoneOffsetMinutes::MAX }>;
 
         let sign = input.get(0).copied().ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected sign for time zone offset, \
                  (or a legacy time zone name abbreviation), \
                  but found end of input",
@@ -800,7 +805,7 @@ impl DateTimeParser {
         };
         let input = &input[1..];
         let (hhmm, input) = parse::split(input, 4).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected at least 4 digits for time zone offset \
                  after sign, but found only {len} bytes remaining",
                 len = input.len(),
@@ -808,7 +813,7 @@ impl DateTimeParser {
         })?;
 
         let hh = parse::i64(&hhmm[0..2]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse hours from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -818,7 +823,7 @@ impl DateTimeParser {
         let hh = t::SpanZoneOffset::rfrom(hh);
 
         let mm = parse::i64(&hhmm[2..4]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse minutes from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -848,7 +853,7 @@ impl DateTimeParser {
             len += 1;
         }
         if len == 0 {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected obsolete RFC 2822 time zone abbreviation, \
                  but found no remaining non-whitespace characters \
                  after time",
@@ -900,7 +905,7 @@ impl DateTimeParser {
                     Offset::UTC
                 } else {
                     // But anything else we throw our hands up I guess.
-                    return Err(err!(
+                    return Err(parse_err!(
                         "expected obsolete RFC 2822 time zone abbreviation, \
                          but found {found:?}",
                         found = escape::Bytes(&input[..len]),
@@ -919,12 +924,12 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected time separator of ':', but found end of input",
             ));
         }
         if input[0] != b':' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected time separator of ':', but found {got}",
                 got = escape::Byte(input[0]),
             ));
@@ -943,7 +948,7 @@ impl DateTimeParser {
         let parsed = self.skip_whitespace(input);
         let newlen = parsed.input.len();
         if oldlen == newlen {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected at least one whitespace character (space or tab), \
                  but found none",
             ));
@@ -993,7 +998,7 @@ impl DateTimeParser {
                 // soon as we hit 0, we break out. If there is more "comment,"
                 // then it will flag an error as unparsed input.
                 depth = depth.checked_sub(1).ok_or_else(|| {
-                    err!(
+                    parse_err!(
                         "found closing parenthesis in comment with \
                          no matching opening parenthesis"
                     )
@@ -1003,12 +1008,12 @@ impl DateTimeParser {
                 }
             } else if byte == b'(' {
                 depth = depth.checked_add(1).ok_or_else(|| {
-                    err!("found too many nested parenthesis in comment")
+                    parse_err!("found too many nested parenthesis in comment")
                 })?;
             }
         }
         if depth > 0 {
-            return Err(err!(
+            return Err(parse_err!(
                 "found opening parenthesis in comment with \
                  no matching closing parenthesis"
             ));
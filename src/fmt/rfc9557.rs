@@ -17,6 +17,10 @@ and criticality are still respected. So for example,
 of the `!` indicating that consumers must take action on the annotation,
 including by returning an error if it isn't supported.
 
+The exception to the above is when the caller asks for all annotations (see
+`ParsedAnnotations::to_annotations`). In that case, critical annotations
+are permitted since the caller becomes responsible for acting on them.
+
 [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
 [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557.html
 */
@@ -94,8 +98,11 @@ including by returning an error if it isn't supported.
 // TimeZoneUTCOffsetName :::
 //   UTCOffsetMinutePrecision
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use crate::{
-    error::{err, Error},
+    error::{parse_err, Error},
     fmt::{
         offset::{self, ParsedOffset},
         temporal::{TimeZoneAnnotation, TimeZoneAnnotationKind},
@@ -104,18 +111,22 @@ use crate::{
     util::{escape, parse},
 };
 
+#[cfg(feature = "alloc")]
+use crate::fmt::temporal::Annotation;
+
 /// The result of parsing RFC 9557 annotations.
 ///
-/// Currently, this only provides access to a parsed time zone annotation, if
-/// present. While the parser does validate all other key/value annotations,
-/// Jiff doesn't make use of them and thus does not expose them here. They are
-/// only validated at a syntax level.
+/// This provides direct access to a parsed time zone annotation, if present.
+/// While the parser does validate all other key/value annotations, Jiff
+/// doesn't make use of them and thus does not store them here. They are only
+/// validated at a syntax level. Callers that need them anyway can get them
+/// via `ParsedAnnotations::to_annotations`, which re-parses the input.
 #[derive(Debug)]
 pub(crate) struct ParsedAnnotations<'i> {
     /// The original input that all of the annotations were parsed from.
     ///
-    /// N.B. This is currently unused, but potentially useful, so we leave it.
-    #[allow(dead_code)]
+    /// This is used to re-parse the generic key/value annotations on demand.
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
     input: escape::Bytes<'i>,
     /// An optional time zone annotation that was extracted from the input.
     time_zone: Option<ParsedTimeZone<'i>>,
@@ -141,6 +152,34 @@ impl<'i> ParsedAnnotations<'i> {
         let Some(ref parsed) = self.time_zone else { return Ok(None) };
         Ok(Some(parsed.to_time_zone_annotation()?))
     }
+
+    /// Returns all of the generic key/value annotations (i.e., everything
+    /// except for the time zone annotation) in the order in which they
+    /// appeared.
+    ///
+    /// This re-parses the input that these annotations were originally
+    /// parsed from. This is okay since it's expected to be rare for callers
+    /// to need anything other than the time zone annotation.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn to_annotations(&self) -> Result<Vec<Annotation>, Error> {
+        const P: Parser = Parser::new().allow_unsupported_critical(true);
+
+        let mut annotations = Vec::new();
+        let Parsed { mut input, .. } =
+            P.parse_time_zone_annotation(self.input.0)?;
+        loop {
+            let Parsed { value: ann, input: unconsumed } =
+                P.parse_annotation(input)?;
+            let Some(ann) = ann else { break };
+            annotations.push(Annotation::new(
+                ann.critical,
+                ann.key.into(),
+                ann.value.into(),
+            ));
+            input = unconsumed;
+        }
+        Ok(annotations)
+    }
 }
 
 /// The result of parsing a time zone annotation.
@@ -187,17 +226,40 @@ impl<'i> ParsedTimeZone<'i> {
     }
 }
 
+/// The result of parsing a generic key/value annotation.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+struct ParsedAnnotation<'i> {
+    /// Whether the critical flag was seen.
+    critical: bool,
+    /// The annotation key. This is guaranteed to be ASCII.
+    key: &'i str,
+    /// The annotation value, including any `-` separators between its
+    /// components. This is guaranteed to be ASCII.
+    value: &'i str,
+}
+
 /// A parser for RFC 9557 annotations.
 #[derive(Debug)]
 pub(crate) struct Parser {
-    /// There are currently no configuration options for this parser.
-    _priv: (),
+    /// Whether to permit the critical flag on generic key/value annotations.
+    ///
+    /// Since Jiff doesn't support any of them, this is disabled by default.
+    /// It is only enabled when the caller asks for all annotations, in which
+    /// case, the caller is responsible for handling the critical flag.
+    allow_unsupported_critical: bool,
 }
 
 impl Parser {
     /// Create a new RFC 9557 annotation parser with the default configuration.
     pub(crate) const fn new() -> Parser {
-        Parser { _priv: () }
+        Parser { allow_unsupported_critical: false }
+    }
+
+    /// Whether to permit the critical flag on annotations that Jiff doesn't
+    /// support. When disabled (the default), an error is returned.
+    pub(crate) const fn allow_unsupported_critical(self, yes: bool) -> Parser {
+        Parser { allow_unsupported_critical: yes }
     }
 
     /// Parse RFC 9557 annotations from the start of `input`.
@@ -221,9 +283,9 @@ impl Parser {
             // a time zone, but we do parse them to ensure validity and to
             // be able to fail when a critical flag is set. Otherwise, we know
             // we're done if parsing an annotation doesn't consume any input.
-            let Parsed { value: did_consume, input: unconsumed } =
+            let Parsed { value: ann, input: unconsumed } =
                 self.parse_annotation(input)?;
-            if !did_consume {
+            if ann.is_none() {
                 break;
             }
             input = unconsumed;
@@ -305,9 +367,9 @@ impl Parser {
     fn parse_annotation<'i>(
         &self,
         mut input: &'i [u8],
-    ) -> Result<Parsed<'i, bool>, Error> {
+    ) -> Result<Parsed<'i, Option<ParsedAnnotation<'i>>>, Error> {
         if input.is_empty() || input[0] != b'[' {
-            return Ok(Parsed { value: false, input });
+            return Ok(Parsed { value: None, input });
         }
         input = &input[1..];
 
@@ -318,22 +380,27 @@ impl Parser {
 
         let Parsed { value: key, input } = self.parse_annotation_key(input)?;
         let Parsed { input, .. } = self.parse_annotation_separator(input)?;
-        let Parsed { input, .. } = self.parse_annotation_values(input)?;
+        let Parsed { value, input } = self.parse_annotation_values(input)?;
         let Parsed { input, .. } = self.parse_annotation_close(input)?;
 
         // If the critical flag is set, then we automatically return an error
         // because we don't support any non-time-zone annotations. When the
         // critical flag isn't set, we're "permissive" and just validate that
         // the syntax is correct (as we've already done at this point).
-        if critical {
-            return Err(err!(
+        if critical && !self.allow_unsupported_critical {
+            return Err(parse_err!(
                 "found unsupported RFC 9557 annotation with key {key:?} \
                  with the critical flag ('!') set",
                 key = escape::Bytes(key),
             ));
         }
 
-        Ok(Parsed { value: true, input })
+        // These are OK because all bytes in keys and values are guaranteed to
+        // be ASCII, or else we wouldn't be here.
+        let key = core::str::from_utf8(key).expect("ASCII");
+        let value = core::str::from_utf8(value).expect("ASCII");
+        let value = Some(ParsedAnnotation { critical, key, value });
+        Ok(Parsed { value, input })
     }
 
     fn parse_tz_annotation_iana_name<'i>(
@@ -372,14 +439,15 @@ impl Parser {
         Ok(Parsed { value: mkkey(input), input })
     }
 
-    // N.B. If we ever actually need the values, this should probably return a
-    // `Vec<&'i [u8]>`. (Well, no, because that wouldn't be good for core-only
-    // configurations. So it will probably need to be something else. But,
-    // probably Jiff will never care about other values.)
+    // N.B. This returns all of the values as a single slice, including the
+    // `-` separators between them. This avoids needing to allocate in
+    // core-only configurations, and it's up to callers to split it apart
+    // if they need to.
     fn parse_annotation_values<'i>(
         &self,
         input: &'i [u8],
-    ) -> Result<Parsed<'i, ()>, Error> {
+    ) -> Result<Parsed<'i, &'i [u8]>, Error> {
+        let mkvalues = parse::slicer(input);
         let Parsed { mut input, .. } = self.parse_annotation_value(input)?;
         while input.starts_with(b"-") {
             input = &input[1..];
@@ -387,7 +455,7 @@ impl Parser {
                 self.parse_annotation_value(input)?;
             input = unconsumed;
         }
-        Ok(Parsed { value: (), input })
+        Ok(Parsed { value: mkvalues(input), input })
     }
 
     fn parse_annotation_value<'i>(
@@ -414,13 +482,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected the start of an RFC 9557 annotation or IANA \
                  time zone component name, but found end of input instead",
             ));
         }
         if !matches!(input[0], b'_' | b'.' | b'A'..=b'Z' | b'a'..=b'z') {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected ASCII alphabetic byte (or underscore or period) \
                  at the start of an RFC 9557 annotation or time zone \
                  component name, but found {:?} instead",
@@ -451,13 +519,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected the start of an RFC 9557 annotation key, \
                  but found end of input instead",
             ));
         }
         if !matches!(input[0], b'_' | b'a'..=b'z') {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected lowercase alphabetic byte (or underscore) \
                  at the start of an RFC 9557 annotation key, \
                  but found {:?} instead",
@@ -484,13 +552,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected the start of an RFC 9557 annotation value, \
                  but found end of input instead",
             ));
         }
         if !matches!(input[0], b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z') {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected alphanumeric ASCII byte \
                  at the start of an RFC 9557 annotation value, \
                  but found {:?} instead",
@@ -517,7 +585,7 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected an '=' after parsing an RFC 9557 annotation key, \
                  but found end of input instead",
             ));
@@ -526,13 +594,13 @@ impl Parser {
             // If we see a /, then it's likely the user was trying to insert a
             // time zone annotation in the wrong place.
             return Err(if input[0] == b'/' {
-                err!(
+                parse_err!(
                     "expected an '=' after parsing an RFC 9557 annotation \
                      key, but found / instead (time zone annotations must \
                      come first)",
                 )
             } else {
-                err!(
+                parse_err!(
                     "expected an '=' after parsing an RFC 9557 annotation \
                      key, but found {:?} instead",
                     escape::Byte(input[0]),
@@ -547,13 +615,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected an ']' after parsing an RFC 9557 annotation key \
                  and value, but found end of input instead",
             ));
         }
         if input[0] != b']' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected an ']' after parsing an RFC 9557 annotation key \
                  and value, but found {:?} instead",
                 escape::Byte(input[0]),
@@ -567,13 +635,13 @@ impl Parser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected an ']' after parsing an RFC 9557 time zone \
                  annotation, but found end of input instead",
             ));
         }
         if input[0] != b']' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected an ']' after parsing an RFC 9557 time zone \
                  annotation, but found {:?} instead",
                 escape::Byte(input[0]),
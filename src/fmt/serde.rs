@@ -442,9 +442,8 @@ pub mod timestamp {
             &self,
             f: &mut core::fmt::Formatter,
         ) -> core::fmt::Result {
-            f.write_str(
-                "an integer number of seconds from the Unix epoch or `None`",
-            )
+            self.0.expecting(f)?;
+            f.write_str(" or `None`")
         }
 
         #[inline]
@@ -1607,4 +1606,49 @@ mod tests {
         );
         assert_eq!(serde_json::to_string(&got).unwrap(), json);
     }
+
+    #[test]
+    fn timestamp_optional_null_and_overflow() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Data {
+            #[serde(with = "crate::fmt::serde::timestamp::second::optional")]
+            second: Option<Timestamp>,
+            #[serde(
+                with = "crate::fmt::serde::timestamp::millisecond::optional"
+            )]
+            millisecond: Option<Timestamp>,
+            #[serde(
+                with = "crate::fmt::serde::timestamp::microsecond::optional"
+            )]
+            microsecond: Option<Timestamp>,
+        }
+
+        let json = r#"{"second":null,"millisecond":null,"microsecond":null}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.second, None);
+        assert_eq!(got.millisecond, None);
+        assert_eq!(got.microsecond, None);
+        assert_eq!(serde_json::to_string(&got).unwrap(), json);
+
+        let json = r#"{"second":-1,"millisecond":null,"microsecond":1}"#;
+        let got: Data = serde_json::from_str(&json).unwrap();
+        assert_eq!(got.second, Some(Timestamp::from_second(-1).unwrap()));
+        assert_eq!(got.millisecond, None);
+        assert_eq!(
+            got.microsecond,
+            Some(Timestamp::from_microsecond(1).unwrap())
+        );
+        assert_eq!(serde_json::to_string(&got).unwrap(), json);
+
+        let json =
+            r#"{"second":253402207201,"millisecond":null,"microsecond":null}"#;
+        let err = serde_json::from_str::<Data>(&json).unwrap_err();
+        insta::assert_snapshot!(err, @"parameter 'second' with value 253402207201 is not in the required range of -377705023201..=253402207200 at line 1 column 22");
+        let json = r#"{"second":null,"millisecond":-377705116800001,"microsecond":null}"#;
+        let err = serde_json::from_str::<Data>(&json).unwrap_err();
+        insta::assert_snapshot!(err, @"parameter 'millisecond timestamp' with value -377705116800001 is not in the required range of -377705023201000..=253402207200999 at line 1 column 45");
+        let json = r#"{"second":null,"millisecond":null,"microsecond":"1"}"#;
+        let err = serde_json::from_str::<Data>(&json).unwrap_err();
+        insta::assert_snapshot!(err, @"invalid type: string \"1\", expected an integer number of microseconds from the Unix epoch at line 1 column 51");
+    }
 }
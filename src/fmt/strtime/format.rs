@@ -13,127 +13,116 @@ use crate::{
     Error,
 };
 
-pub(super) struct Formatter<'f, 't, 'w, W> {
+/// A single parsed piece of a `strftime` format string.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Item {
+    /// A literal character that is written as-is.
+    Literal(char),
+    /// A conversion specifier, like `%Y` or `%:z`.
+    Directive(Directive),
+}
+
+/// A conversion specifier whose flags, width and specifier byte have
+/// already been validated.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Directive {
+    ext: Extension,
+    /// The `:` or `.` that precedes specifiers like `%:z` and `%.f`.
+    prefix: Option<u8>,
+    spec: u8,
+}
+
+/// A parser for the items in a `strftime` format string.
+///
+/// Items are parsed one at a time, so that formatting can stop at the first
+/// error. This means that, just like `strftime`, output may have been written
+/// before an invalid directive is found.
+pub(super) struct ItemParser<'f> {
     pub(super) fmt: &'f [u8],
-    pub(super) tm: &'t BrokenDownTime,
-    pub(super) wtr: &'w mut W,
 }
 
-impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
-    pub(super) fn format(&mut self) -> Result<(), Error> {
-        while !self.fmt.is_empty() {
-            if self.f() != b'%' {
-                if self.f().is_ascii() {
-                    self.wtr.write_char(char::from(self.f()))?;
-                    self.bump_fmt();
-                } else {
-                    let ch = self.utf8_decode_and_bump()?;
-                    self.wtr.write_char(ch)?;
+impl<'f> ItemParser<'f> {
+    /// Parses the next item from the format string. When the format string
+    /// has been fully consumed, this returns `None`.
+    pub(super) fn next(&mut self) -> Result<Option<Item>, Error> {
+        if self.fmt.is_empty() {
+            return Ok(None);
+        }
+        if self.f() != b'%' {
+            let ch = if self.f().is_ascii() {
+                let ch = char::from(self.f());
+                self.bump_fmt();
+                ch
+            } else {
+                self.utf8_decode_and_bump()?
+            };
+            return Ok(Some(Item::Literal(ch)));
+        }
+        if !self.bump_fmt() {
+            return Err(err!(
+                "invalid format string, expected byte after '%', \
+                 but found end of format string",
+            ));
+        }
+        // Parse extensions like padding/case options and padding width.
+        let ext = self.parse_extension()?;
+        let directive = match self.f() {
+            spec @ (b'%' | b'A' | b'a' | b'B' | b'b' | b'C' | b'D' | b'd'
+            | b'e' | b'F' | b'f' | b'G' | b'g' | b'H' | b'h'
+            | b'I' | b'j' | b'k' | b'l' | b'M' | b'm' | b'n'
+            | b'P' | b'p' | b'Q' | b'R' | b'S' | b's' | b'T'
+            | b't' | b'U' | b'u' | b'V' | b'W' | b'w' | b'Y'
+            | b'y' | b'Z' | b'z') => Directive { ext, prefix: None, spec },
+            b':' => {
+                if !self.bump_fmt() {
+                    return Err(err!(
+                        "invalid format string, expected directive \
+                         after '%:'",
+                    ));
                 }
-                continue;
-            }
-            if !self.bump_fmt() {
-                return Err(err!(
-                    "invalid format string, expected byte after '%', \
-                     but found end of format string",
-                ));
-            }
-            // Parse extensions like padding/case options and padding width.
-            let ext = self.parse_extension()?;
-            match self.f() {
-                b'%' => self.wtr.write_str("%").context("%% failed")?,
-                b'A' => self.fmt_weekday_full(ext).context("%A failed")?,
-                b'a' => self.fmt_weekday_abbrev(ext).context("%a failed")?,
-                b'B' => self.fmt_month_full(ext).context("%B failed")?,
-                b'b' => self.fmt_month_abbrev(ext).context("%b failed")?,
-                b'C' => self.fmt_century(ext).context("%C failed")?,
-                b'D' => self.fmt_american_date(ext).context("%D failed")?,
-                b'd' => self.fmt_day_zero(ext).context("%d failed")?,
-                b'e' => self.fmt_day_space(ext).context("%e failed")?,
-                b'F' => self.fmt_iso_date(ext).context("%F failed")?,
-                b'f' => self.fmt_fractional(ext).context("%f failed")?,
-                b'G' => self.fmt_iso_week_year(ext).context("%G failed")?,
-                b'g' => self.fmt_iso_week_year2(ext).context("%g failed")?,
-                b'H' => self.fmt_hour24_zero(ext).context("%H failed")?,
-                b'h' => self.fmt_month_abbrev(ext).context("%b failed")?,
-                b'I' => self.fmt_hour12_zero(ext).context("%H failed")?,
-                b'j' => self.fmt_day_of_year(ext).context("%j failed")?,
-                b'k' => self.fmt_hour24_space(ext).context("%k failed")?,
-                b'l' => self.fmt_hour12_space(ext).context("%l failed")?,
-                b'M' => self.fmt_minute(ext).context("%M failed")?,
-                b'm' => self.fmt_month(ext).context("%m failed")?,
-                b'n' => self.fmt_literal("\n").context("%n failed")?,
-                b'P' => self.fmt_ampm_lower(ext).context("%P failed")?,
-                b'p' => self.fmt_ampm_upper(ext).context("%p failed")?,
-                b'Q' => self.fmt_iana_nocolon().context("%Q failed")?,
-                b'R' => self.fmt_clock_nosecs(ext).context("%R failed")?,
-                b'S' => self.fmt_second(ext).context("%S failed")?,
-                b's' => self.fmt_timestamp(ext).context("%s failed")?,
-                b'T' => self.fmt_clock_secs(ext).context("%T failed")?,
-                b't' => self.fmt_literal("\t").context("%t failed")?,
-                b'U' => self.fmt_week_sun(ext).context("%U failed")?,
-                b'u' => self.fmt_weekday_mon(ext).context("%u failed")?,
-                b'V' => self.fmt_week_iso(ext).context("%V failed")?,
-                b'W' => self.fmt_week_mon(ext).context("%W failed")?,
-                b'w' => self.fmt_weekday_sun(ext).context("%w failed")?,
-                b'Y' => self.fmt_year(ext).context("%Y failed")?,
-                b'y' => self.fmt_year2(ext).context("%y failed")?,
-                b'Z' => self.fmt_tzabbrev(ext).context("%Z failed")?,
-                b'z' => self.fmt_offset_nocolon().context("%z failed")?,
-                b':' => {
-                    if !self.bump_fmt() {
-                        return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%:'",
-                        ));
-                    }
-                    match self.f() {
-                        b'Q' => self.fmt_iana_colon().context("%:Q failed")?,
-                        b'z' => {
-                            self.fmt_offset_colon().context("%:z failed")?
-                        }
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %:",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
+                match self.f() {
+                    spec @ (b'Q' | b'z') => {
+                        Directive { ext, prefix: Some(b':'), spec }
                     }
-                }
-                b'.' => {
-                    if !self.bump_fmt() {
+                    unk => {
                         return Err(err!(
-                            "invalid format string, expected directive \
-                             after '%.'",
+                            "found unrecognized directive %{unk} \
+                             following %:",
+                            unk = escape::Byte(unk),
                         ));
                     }
-                    // Parse precision settings after the `.`, effectively
-                    // overriding any digits that came before it.
-                    let ext = Extension { width: self.parse_width()?, ..ext };
-                    match self.f() {
-                        b'f' => self
-                            .fmt_dot_fractional(ext)
-                            .context("%.f failed")?,
-                        unk => {
-                            return Err(err!(
-                                "found unrecognized directive %{unk} \
-                                 following %.",
-                                unk = escape::Byte(unk),
-                            ));
-                        }
-                    }
                 }
-                unk => {
+            }
+            b'.' => {
+                if !self.bump_fmt() {
                     return Err(err!(
-                        "found unrecognized specifier directive %{unk}",
-                        unk = escape::Byte(unk),
+                        "invalid format string, expected directive \
+                         after '%.'",
                     ));
                 }
+                // Parse precision settings after the `.`, effectively
+                // overriding any digits that came before it.
+                let ext = Extension { width: self.parse_width()?, ..ext };
+                match self.f() {
+                    spec @ b'f' => Directive { ext, prefix: Some(b'.'), spec },
+                    unk => {
+                        return Err(err!(
+                            "found unrecognized directive %{unk} \
+                             following %.",
+                            unk = escape::Byte(unk),
+                        ));
+                    }
+                }
             }
-            self.bump_fmt();
-        }
-        Ok(())
+            unk => {
+                return Err(err!(
+                    "found unrecognized specifier directive %{unk}",
+                    unk = escape::Byte(unk),
+                ));
+            }
+        };
+        self.bump_fmt();
+        Ok(Some(Item::Directive(directive)))
     }
 
     /// Returns the byte at the current position of the format string.
@@ -214,6 +203,107 @@ impl<'f, 't, 'w, W: Write> Formatter<'f, 't, 'w, W> {
         self.fmt = fmt;
         Ok(width)
     }
+}
+
+pub(super) struct Formatter<'t, 'w, W> {
+    pub(super) tm: &'t BrokenDownTime,
+    pub(super) wtr: &'w mut W,
+}
+
+impl<'t, 'w, W: Write> Formatter<'t, 'w, W> {
+    /// Parses the given format string and formats each item as it is parsed.
+    pub(super) fn format(&mut self, fmt: &[u8]) -> Result<(), Error> {
+        let mut items = ItemParser { fmt };
+        while let Some(item) = items.next()? {
+            self.format_item(item)?;
+        }
+        Ok(())
+    }
+
+    /// Formats each of the given items, which were parsed ahead of time.
+    pub(super) fn format_items(
+        &mut self,
+        items: &[Item],
+    ) -> Result<(), Error> {
+        for &item in items {
+            self.format_item(item)?;
+        }
+        Ok(())
+    }
+
+    fn format_item(&mut self, item: Item) -> Result<(), Error> {
+        let d = match item {
+            Item::Literal(ch) => return self.wtr.write_char(ch),
+            Item::Directive(d) => d,
+        };
+        let ext = d.ext;
+        match (d.prefix, d.spec) {
+            (None, b'%') => self.wtr.write_str("%").context("%% failed")?,
+            (None, b'A') => self.fmt_weekday_full(ext).context("%A failed")?,
+            (None, b'a') => {
+                self.fmt_weekday_abbrev(ext).context("%a failed")?
+            }
+            (None, b'B') => self.fmt_month_full(ext).context("%B failed")?,
+            (None, b'b') => self.fmt_month_abbrev(ext).context("%b failed")?,
+            (None, b'C') => self.fmt_century(ext).context("%C failed")?,
+            (None, b'D') => {
+                self.fmt_american_date(ext).context("%D failed")?
+            }
+            (None, b'd') => self.fmt_day_zero(ext).context("%d failed")?,
+            (None, b'e') => self.fmt_day_space(ext).context("%e failed")?,
+            (None, b'F') => self.fmt_iso_date(ext).context("%F failed")?,
+            (None, b'f') => self.fmt_fractional(ext).context("%f failed")?,
+            (None, b'G') => {
+                self.fmt_iso_week_year(ext).context("%G failed")?
+            }
+            (None, b'g') => {
+                self.fmt_iso_week_year2(ext).context("%g failed")?
+            }
+            (None, b'H') => self.fmt_hour24_zero(ext).context("%H failed")?,
+            (None, b'h') => self.fmt_month_abbrev(ext).context("%b failed")?,
+            (None, b'I') => self.fmt_hour12_zero(ext).context("%H failed")?,
+            (None, b'j') => self.fmt_day_of_year(ext).context("%j failed")?,
+            (None, b'k') => self.fmt_hour24_space(ext).context("%k failed")?,
+            (None, b'l') => self.fmt_hour12_space(ext).context("%l failed")?,
+            (None, b'M') => self.fmt_minute(ext).context("%M failed")?,
+            (None, b'm') => self.fmt_month(ext).context("%m failed")?,
+            (None, b'n') => self.fmt_literal("\n").context("%n failed")?,
+            (None, b'P') => self.fmt_ampm_lower(ext).context("%P failed")?,
+            (None, b'p') => self.fmt_ampm_upper(ext).context("%p failed")?,
+            (None, b'Q') => self.fmt_iana_nocolon().context("%Q failed")?,
+            (None, b'R') => self.fmt_clock_nosecs(ext).context("%R failed")?,
+            (None, b'S') => self.fmt_second(ext).context("%S failed")?,
+            (None, b's') => self.fmt_timestamp(ext).context("%s failed")?,
+            (None, b'T') => self.fmt_clock_secs(ext).context("%T failed")?,
+            (None, b't') => self.fmt_literal("\t").context("%t failed")?,
+            (None, b'U') => self.fmt_week_sun(ext).context("%U failed")?,
+            (None, b'u') => self.fmt_weekday_mon(ext).context("%u failed")?,
+            (None, b'V') => self.fmt_week_iso(ext).context("%V failed")?,
+            (None, b'W') => self.fmt_week_mon(ext).context("%W failed")?,
+            (None, b'w') => self.fmt_weekday_sun(ext).context("%w failed")?,
+            (None, b'Y') => self.fmt_year(ext).context("%Y failed")?,
+            (None, b'y') => self.fmt_year2(ext).context("%y failed")?,
+            (None, b'Z') => self.fmt_tzabbrev(ext).context("%Z failed")?,
+            (None, b'z') => self.fmt_offset_nocolon().context("%z failed")?,
+            (Some(b':'), b'Q') => {
+                self.fmt_iana_colon().context("%:Q failed")?
+            }
+            (Some(b':'), b'z') => {
+                self.fmt_offset_colon().context("%:z failed")?
+            }
+            (Some(b'.'), b'f') => {
+                self.fmt_dot_fractional(ext).context("%.f failed")?
+            }
+            // `ItemParser` only produces the directives above.
+            (_, unk) => {
+                return Err(err!(
+                    "found unrecognized specifier directive %{unk}",
+                    unk = escape::Byte(unk),
+                ));
+            }
+        }
+        Ok(())
+    }
 
     // These are the formatting functions. They are pretty much responsible
     // for getting what they need for the broken down time and reporting a
@@ -1196,4 +1286,90 @@ mod tests {
             @"strftime formatting failed: %s failed: requires instant (a date, time and offset) to format Unix timestamp",
         );
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ok_format_compiled_matches_format() {
+        use crate::fmt::strtime::Format;
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let formats = [
+            "%Y-%m-%dT%H:%M:%S%.f%:z",
+            "%a, %-d %b %Y %T %z",
+            "%A %B %e %I:%M:%S %p %Z %Y",
+            "%^a %#b %_5d %-j %G-W%V-%u %U %W %w",
+            "%C%y %D %F %R %k %l %P %h",
+            "%s %3f %.6f %n%t%% %Q %:Q",
+            "日付: %Y年%m月%d日",
+            "",
+        ];
+        let zdts = [
+            date(2024, 7, 15)
+                .at(16, 24, 59, 123_456_789)
+                .in_tz("America/New_York")
+                .unwrap(),
+            date(1969, 12, 31).at(0, 0, 0, 0).in_tz("Asia/Kolkata").unwrap(),
+            Zoned::new(Timestamp::MIN, crate::tz::TimeZone::UTC),
+            Zoned::new(Timestamp::MAX, crate::tz::TimeZone::UTC),
+        ];
+        for fmt in formats {
+            let compiled = Format::compile(fmt).unwrap();
+            for zdt in &zdts {
+                let expected = format(fmt, zdt);
+                let got = compiled.format(zdt);
+                match (expected, got) {
+                    (Ok(expected), Ok(got)) => {
+                        assert_eq!(expected, got, "{fmt:?} with {zdt}")
+                    }
+                    (Err(expected), Err(got)) => assert_eq!(
+                        alloc::string::ToString::to_string(&expected),
+                        alloc::string::ToString::to_string(&got),
+                        "{fmt:?} with {zdt}",
+                    ),
+                    (expected, got) => panic!(
+                        "mismatch for {fmt:?} with {zdt}: \
+                         expected {expected:?}, got {got:?}",
+                    ),
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn err_format_compiled() {
+        use crate::fmt::strtime::Format;
+
+        let f = |fmt: &str| Format::compile(fmt).unwrap_err();
+
+        insta::assert_snapshot!(
+            f("%Y-%m-%d %"),
+            @"strftime format string is invalid: invalid format string, expected byte after '%', but found end of format string",
+        );
+        insta::assert_snapshot!(
+            f("%Y %!"),
+            @"strftime format string is invalid: found unrecognized specifier directive %!",
+        );
+        insta::assert_snapshot!(
+            f("%:Y"),
+            @"strftime format string is invalid: found unrecognized directive %Y following %:",
+        );
+        insta::assert_snapshot!(
+            f("%.3Y"),
+            @"strftime format string is invalid: found unrecognized directive %Y following %.",
+        );
+        insta::assert_snapshot!(
+            f("%_"),
+            @"strftime format string is invalid: expected to find specifier directive after flag \"_\", but found end of format string",
+        );
+
+        let fmt = Format::compile("%Y %z").unwrap();
+        insta::assert_snapshot!(
+            fmt.format(date(2024, 7, 15)).unwrap_err(),
+            @"strftime formatting failed: %z failed: requires offset to format time zone offset",
+        );
+    }
 }
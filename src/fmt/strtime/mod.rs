@@ -262,7 +262,10 @@ use crate::{
     civil::{Date, DateTime, ISOWeekDate, Time, Weekday},
     error::{err, ErrorContext},
     fmt::{
-        strtime::{format::Formatter, parse::Parser},
+        strtime::{
+            format::{Formatter, Item, ItemParser},
+            parse::Parser,
+        },
         Write,
     },
     tz::{Offset, OffsetConflict, TimeZone, TimeZoneDatabase},
@@ -353,6 +356,10 @@ pub fn parse(
 /// it. This includes, for example, [`Zoned`], [`Timestamp`], [`DateTime`],
 /// [`Date`] and [`Time`].
 ///
+/// The format string is parsed every time this is called. When formatting
+/// many datetimes with the same format string, use [`Format`] to parse it
+/// only once.
+///
 /// # Errors
 ///
 /// This returns an error when formatting failed. Formatting can fail either
@@ -700,8 +707,8 @@ impl BrokenDownTime {
         mut wtr: W,
     ) -> Result<(), Error> {
         let fmt = format.as_ref();
-        let mut formatter = Formatter { fmt, tm: self, wtr: &mut wtr };
-        formatter.format().context("strftime formatting failed")?;
+        let mut formatter = Formatter { tm: self, wtr: &mut wtr };
+        formatter.format(fmt).context("strftime formatting failed")?;
         Ok(())
     }
 
@@ -2619,6 +2626,167 @@ impl<'f> core::fmt::Debug for Display<'f> {
     }
 }
 
+/// A `strftime` format string that has been parsed ahead of time.
+///
+/// Formatting with a string, e.g., via [`strtime::format`](format()) or
+/// [`Zoned::strftime`], parses the format string every time a datetime is
+/// formatted. When the same format string is used to format many datetimes,
+/// this type can be used to parse and validate it once instead.
+///
+/// All conversion specifiers (and their flags) are validated when a `Format`
+/// is compiled. So formatting with a `Format` can only fail when the datetime
+/// being formatted is missing information required by the format string (for
+/// example, `%z` when formatting a [`DateTime`]), or when writing to the
+/// underlying writer fails.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::date, fmt::strtime::Format};
+///
+/// let fmt = Format::compile("%a, %-d %b %Y %T %z")?;
+///
+/// let zdt = date(2024, 7, 15).at(16, 24, 59, 0).in_tz("America/New_York")?;
+/// assert_eq!(fmt.format(&zdt)?, "Mon, 15 Jul 2024 16:24:59 -0400");
+///
+/// let zdt = date(2024, 12, 25).at(8, 0, 0, 0).in_tz("America/New_York")?;
+/// assert_eq!(fmt.format(&zdt)?, "Wed, 25 Dec 2024 08:00:00 -0500");
+///
+/// // Invalid format strings are rejected up front.
+/// assert!(Format::compile("%Y-%m-%d %!").is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Format {
+    pattern: alloc::boxed::Box<[u8]>,
+    items: alloc::boxed::Box<[Item]>,
+}
+
+#[cfg(feature = "alloc")]
+impl Format {
+    /// Parses and validates the given `strftime` format string.
+    ///
+    /// See the [module documentation](self) for details on what's
+    /// supported.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the format string is invalid. For example,
+    /// if it contains an unrecognized conversion specifier or if it isn't
+    /// valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::strtime::Format;
+    ///
+    /// assert!(Format::compile("%Y-%m-%dT%H:%M:%S%.f%:z").is_ok());
+    /// assert!(Format::compile("%Y-%m-%d %").is_err());
+    /// assert!(Format::compile("%Y-%m-%d %:Y").is_err());
+    /// ```
+    pub fn compile(pattern: impl AsRef<[u8]>) -> Result<Format, Error> {
+        let pattern = pattern.as_ref();
+        let mut parser = ItemParser { fmt: pattern };
+        let mut items = alloc::vec::Vec::new();
+        while let Some(item) =
+            parser.next().context("strftime format string is invalid")?
+        {
+            items.push(item);
+        }
+        Ok(Format { pattern: pattern.into(), items: items.into() })
+    }
+
+    /// Format the given broken down time using this format into a new
+    /// `String`.
+    ///
+    /// Note that `broken_down_time` can be _anything_ that can be converted
+    /// into it. This includes, for example, [`Zoned`], [`Timestamp`],
+    /// [`DateTime`], [`Date`] and [`Time`].
+    ///
+    /// The output is always identical to formatting with
+    /// [`strtime::format`](format()) using the same format string.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when formatting requires a field in
+    /// `BrokenDownTime` to be set that isn't. For example, trying to format
+    /// a [`DateTime`] with the `%z` specifier will fail because a `DateTime`
+    /// has no time zone or offset information associated with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::strtime::Format};
+    ///
+    /// let fmt = Format::compile("%Y-%m-%d %H:%M")?;
+    /// let dt = date(2024, 7, 15).at(16, 24, 59, 0);
+    /// assert_eq!(fmt.format(dt)?, "2024-07-15 16:24");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format(
+        &self,
+        broken_down_time: impl Into<BrokenDownTime>,
+    ) -> Result<alloc::string::String, Error> {
+        let mut buf = alloc::string::String::new();
+        self.format_into(broken_down_time, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Format the given broken down time using this format into the given
+    /// writer.
+    ///
+    /// This is like [`Format::format`], but accepts a
+    /// [`fmt::Write`](super::Write) trait implementation instead of always
+    /// returning a new `String`. This makes it possible to reuse an
+    /// allocation when formatting many datetimes.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when formatting requires a field in
+    /// `BrokenDownTime` to be set that isn't. Formatting also fails if
+    /// writing to the given writer fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{fmt::strtime::Format, Timestamp};
+    ///
+    /// let fmt = Format::compile("%s,")?;
+    /// let mut buf = String::new();
+    /// for second in [0, 60, 3600] {
+    ///     let ts = Timestamp::from_second(second)?;
+    ///     fmt.format_into(ts, &mut buf)?;
+    /// }
+    /// assert_eq!(buf, "0,60,3600,");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_into<W: Write>(
+        &self,
+        broken_down_time: impl Into<BrokenDownTime>,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        let tm: BrokenDownTime = broken_down_time.into();
+        let mut formatter = Formatter { tm: &tm, wtr: &mut wtr };
+        formatter
+            .format_items(&self.items)
+            .context("strftime formatting failed")?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for Format {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("Format").field(&escape::Bytes(&self.pattern)).finish()
+    }
+}
+
 /// A label to disambiguate hours on a 12-hour clock.
 ///
 /// This can be accessed on a [`BrokenDownTime`] via
@@ -2,7 +2,7 @@ use core::fmt::Write;
 
 use crate::{
     civil::Weekday,
-    error::{err, ErrorContext},
+    error::{parse_err, ErrorContext},
     fmt::strtime::{BrokenDownTime, Extension, Flag, Meridiem},
     tz::Offset,
     util::{
@@ -34,7 +34,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 continue;
             }
             if !self.bump_fmt() {
-                return Err(err!(
+                return Err(parse_err!(
                     "invalid format string, expected byte after '%', \
                      but found end of format string",
                 ));
@@ -42,7 +42,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
             // We don't check this for `%.` since that currently always
             // must lead to `%.f` which can actually parse the empty string!
             if self.inp.is_empty() && self.f() != b'.' {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected non-empty input for directive %{directive}, \
                      but found end of input",
                     directive = escape::Byte(self.f()),
@@ -91,7 +91,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 b'z' => self.parse_offset_nocolon().context("%z failed")?,
                 b':' => {
                     if !self.bump_fmt() {
-                        return Err(err!(
+                        return Err(parse_err!(
                             "invalid format string, expected directive \
                              after '%:'",
                         ));
@@ -104,7 +104,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                             self.parse_offset_colon().context("%:z failed")?
                         }
                         unk => {
-                            return Err(err!(
+                            return Err(parse_err!(
                                 "found unrecognized directive %{unk} \
                                  following %:",
                                 unk = escape::Byte(unk),
@@ -113,11 +113,13 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                     }
                 }
                 b'Z' => {
-                    return Err(err!("cannot parse time zone abbreviations"));
+                    return Err(parse_err!(
+                        "cannot parse time zone abbreviations"
+                    ));
                 }
                 b'.' => {
                     if !self.bump_fmt() {
-                        return Err(err!(
+                        return Err(parse_err!(
                             "invalid format string, expected directive \
                              after '%.'",
                         ));
@@ -132,7 +134,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                             .parse_dot_fractional(ext)
                             .context("%.f failed")?,
                         unk => {
-                            return Err(err!(
+                            return Err(parse_err!(
                                 "found unrecognized directive %{unk} \
                                  following %.",
                                 unk = escape::Byte(unk),
@@ -141,7 +143,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                     }
                 }
                 unk => {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found unrecognized directive %{unk}",
                         unk = escape::Byte(unk),
                     ));
@@ -214,13 +216,13 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 while self.i().is_ascii_whitespace() && self.bump_input() {}
             }
         } else if self.inp.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to match literal byte {byte:?} from \
                  format string, but found end of input",
                 byte = escape::Byte(self.fmt[0]),
             ));
         } else if self.f() != self.i() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to match literal byte {expect:?} from \
                  format string, but found byte {found:?} in input",
                 expect = escape::Byte(self.f()),
@@ -247,7 +249,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
     /// Parses a literal '%' from the input.
     fn parse_percent(&mut self) -> Result<(), Error> {
         if self.i() != b'%' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected '%' due to '%%' in format string, \
                  but found {byte:?} in input",
                 byte = escape::Byte(self.inp[0]),
@@ -394,7 +396,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
     fn parse_iana_nocolon(&mut self) -> Result<(), Error> {
         #[cfg(not(feature = "alloc"))]
         {
-            Err(err!(
+            Err(parse_err!(
                 "cannot parse `%Q` without Jiff's `alloc` feature enabled"
             ))
         }
@@ -418,7 +420,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
     fn parse_iana_colon(&mut self) -> Result<(), Error> {
         #[cfg(not(feature = "alloc"))]
         {
-            Err(err!(
+            Err(parse_err!(
                 "cannot parse `%:Q` without Jiff's `alloc` feature enabled"
             ))
         }
@@ -442,7 +444,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let (sign, inp) = parse_required_sign(self.inp)
             .context("sign is required for time zone offset")?;
         let (hhmm, inp) = parse::split(inp, 4).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected at least 4 digits for time zone offset \
                  after sign, but found only {len} bytes remaining",
                 len = inp.len(),
@@ -450,7 +452,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         })?;
 
         let hh = parse::i64(&hhmm[0..2]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse hours from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -460,7 +462,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let hh = t::SpanZoneOffset::rfrom(hh);
 
         let mm = parse::i64(&hhmm[2..4]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse minutes from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -476,7 +478,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         } else {
             let (ss, inp) = parse::split(inp, 2).unwrap();
             let ss = parse::i64(ss).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse seconds from time zone offset {ss}",
                     ss = escape::Bytes(ss)
                 )
@@ -488,7 +490,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 // (At time of writing, the precision of tz::Offset is
                 // seconds. If that improves to nanoseconds, then yes, let's
                 // parse fractional seconds here.)
-                return Err(err!(
+                return Err(parse_err!(
                     "parsing fractional seconds in time zone offset \
                      is not supported",
                 ));
@@ -510,14 +512,14 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let (sign, inp) = parse_required_sign(self.inp)
             .context("sign is required for time zone offset")?;
         let (hhmm, inp) = parse::split(inp, 5).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "expected at least HH:MM digits for time zone offset \
                  after sign, but found only {len} bytes remaining",
                 len = inp.len(),
             )
         })?;
         if hhmm[2] != b':' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected colon after between HH and MM in time zone \
                  offset, but found {found:?} instead",
                 found = escape::Byte(hhmm[2]),
@@ -525,7 +527,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         }
 
         let hh = parse::i64(&hhmm[0..2]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse hours from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -535,7 +537,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         let hh = t::SpanZoneOffset::rfrom(hh);
 
         let mm = parse::i64(&hhmm[3..5]).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse minutes from time zone offset {hhmm}",
                 hhmm = escape::Bytes(hhmm)
             )
@@ -552,7 +554,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         } else {
             let (ss, inp) = parse::split(&inp[1..], 2).unwrap();
             let ss = parse::i64(ss).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse seconds from time zone offset {ss}",
                     ss = escape::Bytes(ss)
                 )
@@ -564,7 +566,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
                 // (At time of writing, the precision of tz::Offset is
                 // seconds. If that improves to nanoseconds, then yes, let's
                 // parse fractional seconds here.)
-                return Err(err!(
+                return Err(parse_err!(
                     "parsing fractional seconds in time zone offset \
                      is not supported",
                 ));
@@ -612,14 +614,14 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         // is guaranteed to be positive, and negating any positive `i64` will
         // always result in a valid `i64`.
         let timestamp = timestamp.checked_mul(sign).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "parsed Unix timestamp `{timestamp}` with a \
                  leading `-` sign, which causes overflow",
             )
         })?;
         let timestamp =
             Timestamp::from_second(timestamp).with_context(|| {
-                err!(
+                parse_err!(
                     "parsed Unix timestamp `{timestamp}`, \
                      but out of range of valid Jiff `Timestamp`",
                 )
@@ -661,7 +663,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         }
         let digits = mkdigits(self.inp);
         if digits.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected at least one fractional decimal digit, \
                  but did not find any",
             ));
@@ -670,7 +672,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         // than 9 ASCII digits. Any sequence of 9 ASCII digits can be parsed
         // into an `i64`.
         let nanoseconds = parse::fraction(digits, 9).map_err(|err| {
-            err!(
+            parse_err!(
                 "failed to parse {digits:?} as fractional second component \
                  (up to 9 digits, nanosecond precision): {err}",
                 digits = escape::Bytes(digits),
@@ -682,7 +684,9 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         // possible expressible value here is in range.
         let nanoseconds =
             t::SubsecNanosecond::try_new("nanoseconds", nanoseconds).map_err(
-                |err| err!("fractional nanoseconds are not valid: {err}"),
+                |err| {
+                    parse_err!("fractional nanoseconds are not valid: {err}")
+                },
             )?;
         self.tm.subsec = Some(nanoseconds);
         self.bump_fmt();
@@ -801,7 +805,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         self.inp = inp;
 
         let weekday = i8::try_from(weekday).map_err(|_| {
-            err!("parsed weekday number `{weekday}` is invalid")
+            parse_err!("parsed weekday number `{weekday}` is invalid")
         })?;
         let weekday = Weekday::from_monday_one_offset(weekday)
             .context("weekday number is invalid")?;
@@ -818,7 +822,7 @@ impl<'f, 'i, 't> Parser<'f, 'i, 't> {
         self.inp = inp;
 
         let weekday = i8::try_from(weekday).map_err(|_| {
-            err!("parsed weekday number `{weekday}` is invalid")
+            parse_err!("parsed weekday number `{weekday}` is invalid")
         })?;
         let weekday = Weekday::from_sunday_zero_offset(weekday)
             .context("weekday number is invalid")?;
@@ -1035,14 +1039,14 @@ impl Extension {
                 .checked_mul(10)
                 .and_then(|n| n.checked_add(digit))
                 .ok_or_else(|| {
-                    err!(
+                    parse_err!(
                         "number '{}' too big to parse into 64-bit integer",
                         escape::Bytes(&inp[..digits]),
                     )
                 })?;
         }
         if digits == 0 {
-            return Err(err!("invalid number, no digits found"));
+            return Err(parse_err!("invalid number, no digits found"));
         }
         Ok((n, &inp[digits..]))
     }
@@ -1074,13 +1078,13 @@ fn parse_required_sign<'i>(
     input: &'i [u8],
 ) -> Result<(t::Sign, &'i [u8]), Error> {
     if input.is_empty() {
-        Err(err!("expected +/- sign, but found end of input"))
+        Err(parse_err!("expected +/- sign, but found end of input"))
     } else if input[0] == b'-' {
         Ok((t::Sign::N::<-1>(), &input[1..]))
     } else if input[0] == b'+' {
         Ok((t::Sign::N::<1>(), &input[1..]))
     } else {
-        Err(err!(
+        Err(parse_err!(
             "expected +/- sign, but found {found:?} instead",
             found = escape::Byte(input[0])
         ))
@@ -1123,7 +1127,7 @@ fn parse_choice<'i>(
     }
     #[cfg(not(feature = "alloc"))]
     {
-        Err(err!(
+        Err(parse_err!(
             "failed to find expected value from a set of allowed choices"
         ))
     }
@@ -1136,7 +1140,7 @@ fn parse_choice<'i>(
 #[inline(always)]
 fn parse_ampm<'i>(input: &'i [u8]) -> Result<(usize, &'i [u8]), Error> {
     if input.len() < 2 {
-        return Err(err!(
+        return Err(parse_err!(
             "expected to find AM or PM, \
              but the remaining input, {input:?}, is too short \
              to contain one",
@@ -1149,7 +1153,7 @@ fn parse_ampm<'i>(input: &'i [u8]) -> Result<(usize, &'i [u8]), Error> {
         b"am" => 0,
         b"pm" => 1,
         _ => {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find AM or PM, but found \
                 {candidate:?} instead",
                 candidate = escape::Bytes(x),
@@ -1168,7 +1172,7 @@ fn parse_weekday_abbrev<'i>(
     input: &'i [u8],
 ) -> Result<(usize, &'i [u8]), Error> {
     if input.len() < 3 {
-        return Err(err!(
+        return Err(parse_err!(
             "expected to find a weekday abbreviation, \
              but the remaining input, {input:?}, is too short \
              to contain one",
@@ -1190,7 +1194,7 @@ fn parse_weekday_abbrev<'i>(
         b"fri" => 5,
         b"sat" => 6,
         _ => {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find weekday abbreviation, but found \
                 {candidate:?} instead",
                 candidate = escape::Bytes(x),
@@ -1209,7 +1213,7 @@ fn parse_month_name_abbrev<'i>(
     input: &'i [u8],
 ) -> Result<(usize, &'i [u8]), Error> {
     if input.len() < 3 {
-        return Err(err!(
+        return Err(parse_err!(
             "expected to find a month name abbreviation, \
              but the remaining input, {input:?}, is too short \
              to contain one",
@@ -1236,7 +1240,7 @@ fn parse_month_name_abbrev<'i>(
         b"nov" => 10,
         b"dec" => 11,
         _ => {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find month name abbreviation, but found \
                  {candidate:?} instead",
                 candidate = escape::Bytes(x),
@@ -1272,13 +1276,13 @@ fn parse_iana_component<'i>(
 ) -> Result<(&'i [u8], &'i [u8]), Error> {
     let mkname = parse::slicer(input);
     if input.is_empty() {
-        return Err(err!(
+        return Err(parse_err!(
             "expected the start of an IANA time zone identifier \
              name or component, but found end of input instead",
         ));
     }
     if !matches!(input[0], b'_' | b'.' | b'A'..=b'Z' | b'a'..=b'z') {
-        return Err(err!(
+        return Err(parse_err!(
             "expected the start of an IANA time zone identifier \
              name or component, but found {:?} instead",
             escape::Byte(input[0]),
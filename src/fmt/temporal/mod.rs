@@ -46,6 +46,9 @@ the later time is selected in a forward transition.
 in the datetime string are handled via the [`DateTimeParser::offset_conflict`]
 configuration. By default, any inconsistency between the offset and the time
 zone results in a parse error.
+* Fractional seconds with more than 9 digits (i.e., beyond nanosecond
+precision) are rejected by default. The [`DateTimeParser::subsecond`]
+configuration can be used to truncate the excess digits instead.
 * When parsing civil types like `civil::DateTime`, it's always an error if the
 datetime string has a `Z` (Zulu) offset. It's an error since interpreting such
 strings as civil time is usually a bug.
@@ -161,6 +164,27 @@ range precisely.
 * Jiff doesn't support RFC 9557 calendar annotations because Jiff only supports
 the Gregorian calendar.
 
+More generally, Jiff only makes use of time zone annotations. Any other RFC
+9557 annotation, like `[u-ca=hebrew]`, is checked for syntactic validity and
+then ignored. However, if such an annotation has its critical flag set, like
+`[!u-ca=hebrew]`, then parsing fails. This is because RFC 9557 requires that
+consumers reject critical annotations that they don't support:
+
+```
+use jiff::{Timestamp, Zoned};
+
+let zdt: Zoned =
+    "2024-01-01T00:00:00-05[America/New_York][u-ca=hebrew]".parse()?;
+assert_eq!(zdt.to_string(), "2024-01-01T00:00:00-05:00[America/New_York]");
+
+let ts: Timestamp = "2024-01-01T00:00:00Z[u-ca=hebrew]".parse()?;
+assert_eq!(ts.to_string(), "2024-01-01T00:00:00Z");
+
+assert!("2024-01-01T00:00:00Z[!u-ca=hebrew]".parse::<Timestamp>().is_err());
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
 There is some more [background on Temporal's format] available.
 
 [Temporal ISO 8601 grammar]: https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar
@@ -173,12 +197,15 @@ There is some more [background on Temporal's format] available.
 use crate::{
     civil,
     error::Error,
-    fmt::Write,
+    fmt::{Parsed, Write},
     span::Span,
     tz::{Disambiguation, Offset, OffsetConflict, TimeZone, TimeZoneDatabase},
+    util::parse,
     SignedDuration, Timestamp, Zoned,
 };
 
+#[cfg(feature = "alloc")]
+pub use self::pieces::{Annotation, Annotations};
 pub use self::pieces::{
     Pieces, PiecesNumericOffset, PiecesOffset, TimeZoneAnnotation,
     TimeZoneAnnotationKind, TimeZoneAnnotationName,
@@ -508,6 +535,44 @@ impl DateTimeParser {
         DateTimeParser { disambiguation: strategy, ..self }
     }
 
+    /// Set the policy for fractional seconds with more than nanosecond
+    /// precision.
+    ///
+    /// Jiff only supports nanosecond precision, so at most 9 fractional
+    /// digits can be represented. By default, a datetime string with more
+    /// than 9 fractional second digits is rejected, as prescribed by the
+    /// Temporal ISO 8601 grammar. When this is set to
+    /// [`SubsecondPolicy::Truncate`], the extra digits are accepted and
+    /// dropped. They are never used for rounding.
+    ///
+    /// This applies to the fractional seconds in the time of day, which
+    /// means it applies when parsing [`Zoned`], [`Timestamp`],
+    /// [`civil::DateTime`] and [`civil::Time`] values. It does not apply to
+    /// fractional seconds in UTC offsets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::{DateTimeParser, SubsecondPolicy};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new()
+    ///     .subsecond(SubsecondPolicy::Truncate);
+    ///
+    /// let ts = PARSER.parse_timestamp("2021-07-30T21:20:04.123456789999Z")?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04.123456789Z");
+    ///
+    /// // The default rejects it.
+    /// let result = DateTimeParser::new()
+    ///     .parse_timestamp("2021-07-30T21:20:04.123456789999Z");
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub const fn subsecond(self, policy: SubsecondPolicy) -> DateTimeParser {
+        DateTimeParser { p: self.p.subsecond(policy), ..self }
+    }
+
     /// Parse a datetime string with a time zone annotation into a [`Zoned`]
     /// value using the system time zone database.
     ///
@@ -714,6 +779,122 @@ impl DateTimeParser {
         Ok(timestamp)
     }
 
+    /// Parse a datetime string at the beginning of the given input into a
+    /// [`Timestamp`], and return it along with the number of bytes parsed.
+    ///
+    /// This is like [`DateTimeParser::parse_timestamp`], except that it
+    /// permits unparsed input after the timestamp. This is useful for
+    /// parsing a sequence of timestamps (or a timestamp followed by other
+    /// data) from a larger buffer, without needing to find where each
+    /// timestamp ends first.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the beginning of the input is not a valid
+    /// datetime string with an offset, or if it doesn't fit in the datetime
+    /// range supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// This shows how to parse timestamps from the beginning of log lines:
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::DateTimeParser;
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let line = "2024-06-19T15:22:45Z GET /index.html";
+    /// let (timestamp, len) = PARSER.parse_timestamp_prefix(line)?;
+    /// assert_eq!(timestamp.to_string(), "2024-06-19T15:22:45Z");
+    /// assert_eq!(&line[len..], " GET /index.html");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_timestamp_prefix<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<(Timestamp, usize), Error> {
+        let input = input.as_ref();
+        let mkoffset = parse::offseter(input);
+        let Parsed { value: dt, input } =
+            self.p.parse_temporal_datetime(input)?;
+        let timestamp = dt.to_timestamp()?;
+        Ok((timestamp, mkoffset(input)))
+    }
+
+    /// Parse a datetime string with a time zone annotation at the beginning
+    /// of the given input into a [`Zoned`] value, and return it along with
+    /// the number of bytes parsed.
+    ///
+    /// This is like [`DateTimeParser::parse_zoned`], except that it
+    /// permits unparsed input after the zoned datetime. Time zone
+    /// annotations are resolved using the default time zone database via
+    /// [`tz::db()`](crate::tz::db()).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as
+    /// [`DateTimeParser::parse_zoned`], except it is not an error for the
+    /// input to contain more data after the zoned datetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::fmt::temporal::DateTimeParser;
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let input = "2024-06-19T15:22-04[America/New_York],next";
+    /// let (zdt, len) = PARSER.parse_zoned_prefix(input)?;
+    /// assert_eq!(
+    ///     zdt.to_string(),
+    ///     "2024-06-19T15:22:00-04:00[America/New_York]",
+    /// );
+    /// assert_eq!(&input[len..], ",next");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_zoned_prefix<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<(Zoned, usize), Error> {
+        let input = input.as_ref();
+        let mkoffset = parse::offseter(input);
+        let Parsed { value: dt, input } =
+            self.p.parse_temporal_datetime(input)?;
+        let zoned = dt.to_zoned(
+            crate::tz::db(),
+            self.offset_conflict,
+            self.disambiguation,
+        )?;
+        Ok((zoned, mkoffset(input)))
+    }
+
+    /// Parse a zoned datetime string along with all of its annotations.
+    ///
+    /// Unlike the other parsing routines, annotations marked as critical
+    /// are not rejected. Instead, they are returned to the caller.
+    ///
+    /// This is used to implement [`Zoned::parse_full`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn parse_zoned_full<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<(Zoned, Annotations), Error> {
+        const P: parser::DateTimeParser =
+            parser::DateTimeParser::new().allow_unsupported_critical(true);
+
+        let input = input.as_ref();
+        let parsed = P.parse_temporal_datetime(input)?;
+        let dt = parsed.into_full()?;
+        let zoned = dt.to_zoned(
+            crate::tz::db(),
+            self.offset_conflict,
+            self.disambiguation,
+        )?;
+        Ok((zoned, dt.to_annotations()?))
+    }
+
     /// Parse a civil datetime string into a [`civil::DateTime`].
     ///
     /// A civil datetime can be parsed from anything that contains a datetime.
@@ -832,6 +1013,52 @@ impl DateTimeParser {
         Ok(date)
     }
 
+    /// Parse an ISO 8601 week date string into a [`civil::ISOWeekDate`].
+    ///
+    /// The format accepted is `YYYY-Www-D` (for example, `2024-W05-3`) or
+    /// its basic form without separators, `YYYYWwwD`. Like dates, years
+    /// outside of `0000..=9999` must be written with a sign and six digits.
+    /// The weekday is a number from `1` (Monday) to `7` (Sunday).
+    ///
+    /// Note that the year of an ISO week date is the ISO week year, which
+    /// is not always the same as the Gregorian year of the corresponding
+    /// date.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the week date string given is invalid or
+    /// if it is valid but doesn't correspond to a valid ISO week date. For
+    /// example, `2021-W53-1` is invalid because the ISO week year 2021 only
+    /// has 52 weeks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimeParser};
+    ///
+    /// static PARSER: DateTimeParser = DateTimeParser::new();
+    ///
+    /// let weekdate = PARSER.parse_iso_week_date("2024-W05-3")?;
+    /// assert_eq!(weekdate.date(), date(2024, 1, 31));
+    ///
+    /// // The ISO week year can differ from the Gregorian year.
+    /// let weekdate = PARSER.parse_iso_week_date("2020W011")?;
+    /// assert_eq!(weekdate.date(), date(2019, 12, 30));
+    ///
+    /// assert!(PARSER.parse_iso_week_date("2021-W53-1").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_iso_week_date<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+    ) -> Result<civil::ISOWeekDate, Error> {
+        let input = input.as_ref();
+        let parsed = self.p.parse_iso_week_date(input)?;
+        let weekdate = parsed.into_full()?;
+        Ok(weekdate)
+    }
+
     /// Parse a civil time string into a [`civil::Time`].
     ///
     /// A civil time can be parsed from anything that contains a time.
@@ -1112,6 +1339,41 @@ impl DateTimeParser {
     }
 }
 
+/// The policy for handling fractional seconds beyond nanosecond precision.
+///
+/// This is used to configure [`DateTimeParser::subsecond`]. The default is
+/// [`SubsecondPolicy::Reject`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::fmt::temporal::{DateTimeParser, SubsecondPolicy};
+///
+/// static PARSER: DateTimeParser = DateTimeParser::new()
+///     .subsecond(SubsecondPolicy::Truncate);
+///
+/// // Note that the extra digits are truncated and not rounded.
+/// let time = PARSER.parse_time("12:30:00.9999999999")?;
+/// assert_eq!(time.to_string(), "12:30:00.999999999");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SubsecondPolicy {
+    /// Return an error when more than 9 fractional second digits are found.
+    ///
+    /// This is the default.
+    #[default]
+    Reject,
+    /// Accept any number of fractional second digits, but drop all digits
+    /// after the ninth.
+    ///
+    /// No rounding is performed. For example, `0.9999999999` is parsed as
+    /// `0.999999999`.
+    Truncate,
+}
+
 /// A printer for Temporal datetimes.
 ///
 /// This printer converts an in memory representation of a datetime related
@@ -1496,6 +1758,32 @@ impl DateTimePrinter {
         buf
     }
 
+    /// Format a `civil::ISOWeekDate` into a string.
+    ///
+    /// This is a convenience routine for
+    /// [`DateTimePrinter::print_iso_week_date`] with a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// let weekdate = date(2024, 1, 31).iso_week_date();
+    /// assert_eq!(PRINTER.iso_week_date_to_string(&weekdate), "2024-W05-3");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn iso_week_date_to_string(
+        &self,
+        weekdate: &civil::ISOWeekDate,
+    ) -> alloc::string::String {
+        let mut buf = alloc::string::String::with_capacity(4);
+        // OK because writing to `String` never fails.
+        self.print_iso_week_date(weekdate, &mut buf).unwrap();
+        buf
+    }
+
     /// Format a `civil::Time` into a string.
     ///
     /// This is a convenience routine for [`DateTimePrinter::print_time`]
@@ -1819,6 +2107,43 @@ impl DateTimePrinter {
         self.p.print_date(date, wtr)
     }
 
+    /// Print a `civil::ISOWeekDate` to the given writer.
+    ///
+    /// The format used is `YYYY-Www-D`. For example, `2024-W05-3`. The
+    /// weekday is written as a number from `1` (Monday) to `7` (Sunday).
+    ///
+    /// # Errors
+    ///
+    /// This only returns an error when writing to the given [`Write`]
+    /// implementation would fail. Some such implementations, like for `String`
+    /// and `Vec<u8>`, never fail (unless memory allocation fails). In such
+    /// cases, it would be appropriate to call `unwrap()` on the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, fmt::temporal::DateTimePrinter};
+    ///
+    /// const PRINTER: DateTimePrinter = DateTimePrinter::new();
+    ///
+    /// // The ISO week year can differ from the Gregorian year.
+    /// let weekdate = date(2019, 12, 30).iso_week_date();
+    ///
+    /// let mut buf = String::new();
+    /// // Printing to a `String` can never fail.
+    /// PRINTER.print_iso_week_date(&weekdate, &mut buf).unwrap();
+    /// assert_eq!(buf, "2020-W01-1");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn print_iso_week_date<W: Write>(
+        &self,
+        weekdate: &civil::ISOWeekDate,
+        wtr: W,
+    ) -> Result<(), Error> {
+        self.p.print_iso_week_date(weekdate, wtr)
+    }
+
     /// Print a `civil::Time` to the given writer.
     ///
     /// # Errors
@@ -2187,6 +2512,51 @@ impl SpanPrinter {
         SpanPrinter { p: self.p.lowercase(yes) }
     }
 
+    /// Set the precision to use for formatting the fractional second
+    /// component of a span or duration.
+    ///
+    /// The precision setting is used whenever a seconds component is
+    /// written. When set, the fractional component is written with exactly
+    /// that many digits, padding with trailing zeros or truncating as
+    /// needed. A precision of `0` means the fractional component is always
+    /// omitted, and values greater than `9` are clamped to `9`.
+    ///
+    /// By default (`None`), the fractional component is written with the
+    /// minimal number of digits required to represent it losslessly, and it
+    /// is omitted entirely when it is zero.
+    ///
+    /// This setting doesn't change which components are written. Units with
+    /// a zero value are always omitted, and sub-second units are always
+    /// folded into the seconds component.
+    ///
+    /// # Example
+    ///
+    /// This shows how to always write milliseconds, which is useful when
+    /// interoperating with systems that expect a fixed number of fractional
+    /// digits:
+    ///
+    /// ```
+    /// use jiff::{fmt::temporal::SpanPrinter, ToSpan};
+    ///
+    /// const PRINTER: SpanPrinter = SpanPrinter::new().precision(Some(3));
+    ///
+    /// assert_eq!(PRINTER.span_to_string(&5.seconds()), "PT5.000S");
+    /// assert_eq!(
+    ///     PRINTER.span_to_string(&1.hour().milliseconds(1_500)),
+    ///     "PT1H1.500S",
+    /// );
+    /// assert_eq!(
+    ///     PRINTER.span_to_string(&1.second().nanoseconds(123_456_789)),
+    ///     "PT1.123S",
+    /// );
+    /// // No seconds component is written when it would be zero.
+    /// assert_eq!(PRINTER.span_to_string(&1.day()), "P1D");
+    /// ```
+    #[inline]
+    pub const fn precision(self, precision: Option<u8>) -> SpanPrinter {
+        SpanPrinter { p: self.p.precision(precision) }
+    }
+
     /// Format a `Span` into a string.
     ///
     /// This is a convenience routine for [`SpanPrinter::print_span`] with
@@ -2323,7 +2693,7 @@ impl SpanPrinter {
 mod tests {
     use alloc::string::ToString;
 
-    use crate::Unit;
+    use crate::{ToSpan, Unit};
 
     use super::*;
 
@@ -2341,6 +2711,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unsupported_annotations() {
+        let p = DateTimeParser::new();
+
+        let ts = p.parse_timestamp("2024-01-01T00:00:00Z[u-ca=hebrew]");
+        assert_eq!(ts.unwrap().to_string(), "2024-01-01T00:00:00Z");
+        let ts = p.parse_timestamp("2024-01-01T00:00:00Z[UTC][foo=bar-baz]");
+        assert_eq!(ts.unwrap().to_string(), "2024-01-01T00:00:00Z");
+        let dt = p.parse_datetime("2024-01-01T00:00:00[u-ca=hebrew]");
+        assert_eq!(dt.unwrap().to_string(), "2024-01-01T00:00:00");
+        let zdt = p.parse_zoned("2024-01-01T00:00:00+01[+01][u-ca=iso8601]");
+        assert_eq!(
+            zdt.unwrap().to_string(),
+            "2024-01-01T00:00:00+01:00[+01:00]"
+        );
+
+        insta::assert_snapshot!(
+            p.parse_timestamp("2024-01-01T00:00:00Z[!u-ca=hebrew]").unwrap_err(),
+            @r###"found unsupported RFC 9557 annotation with key "u-ca" with the critical flag ('!') set"###,
+        );
+        insta::assert_snapshot!(
+            p.parse_zoned("2024-01-01T00:00:00+01[+01][!u-ca=hebrew]").unwrap_err(),
+            @r###"found unsupported RFC 9557 annotation with key "u-ca" with the critical flag ('!') set"###,
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_zoned_full() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let input = "2024-01-01T00:00:00-05[America/New_York][u-ca=iso8601]";
+        let (zdt, ann) = Zoned::parse_full(input).unwrap();
+        assert_eq!(zdt, input.parse::<Zoned>().unwrap());
+        assert_eq!(
+            ann.time_zone_annotation(),
+            Some(&TimeZoneAnnotation::from("America/New_York")),
+        );
+        assert_eq!(
+            ann.offset().map(|o| o.to_numeric_offset()),
+            Some(crate::tz::offset(-5)),
+        );
+        assert_eq!(
+            ann.others(),
+            [Annotation::new(false, "u-ca".into(), "iso8601".into())],
+        );
+
+        let input = "2024-01-01T05:00:00Z[!UTC][!u-ca=hebrew][foo=bar-baz]";
+        let (zdt, ann) = Zoned::parse_full(input).unwrap();
+        assert_eq!(zdt.to_string(), "2024-01-01T05:00:00+00:00[UTC]");
+        assert!(ann.time_zone_annotation().unwrap().is_critical());
+        assert_eq!(ann.offset(), Some(PiecesOffset::Zulu));
+        assert_eq!(
+            ann.others(),
+            [
+                Annotation::new(true, "u-ca".into(), "hebrew".into()),
+                Annotation::new(false, "foo".into(), "bar-baz".into()),
+            ],
+        );
+
+        let (_, ann) = Zoned::parse_full("2024-01-01[+01]").unwrap();
+        assert_eq!(ann.offset(), None);
+        assert!(ann.others().is_empty());
+
+        // Syntax errors and missing time zones are still rejected.
+        assert!(Zoned::parse_full("2024-01-01T00:00:00Z").is_err());
+        assert!(
+            Zoned::parse_full("2024-01-01T00:00:00Z[UTC][U-CA=x]").is_err()
+        );
+    }
+
     #[test]
     fn year_zero() {
         insta::assert_snapshot!(
@@ -2367,4 +2810,166 @@ mod tests {
             span2.total(Unit::Hour).unwrap()
         );
     }
+
+    /// Tests that timestamps and zoned datetimes can be printed into a
+    /// fixed size buffer via `core::fmt::Write`, without any allocation.
+    #[test]
+    fn print_into_fixed_buffer() {
+        struct Buf {
+            bytes: [u8; 64],
+            len: usize,
+        }
+
+        impl Buf {
+            fn as_str(&self) -> &str {
+                core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+            }
+        }
+
+        impl core::fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                let dst = self
+                    .bytes
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?;
+                dst.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        use core::fmt::Write;
+
+        let mut buf = Buf { bytes: [0; 64], len: 0 };
+        let ts = crate::Timestamp::new(1_718_810_565, 123_000_000).unwrap();
+        write!(buf, "{ts}").unwrap();
+        assert_eq!(buf.as_str(), "2024-06-19T15:22:45.123Z");
+
+        buf.len = 0;
+        let zdt =
+            ts.to_zoned(crate::tz::TimeZone::fixed(crate::tz::offset(-4)));
+        write!(buf, "{zdt}").unwrap();
+        assert_eq!(buf.as_str(), "2024-06-19T11:22:45.123-04:00[-04:00]");
+
+        // `format_into` writes the same thing as `Display`.
+        buf.len = 0;
+        ts.format_into(&mut buf).unwrap();
+        assert_eq!(buf.as_str(), "2024-06-19T15:22:45.123Z");
+        buf.len = 0;
+        zdt.format_into(&mut buf).unwrap();
+        assert_eq!(buf.as_str(), "2024-06-19T11:22:45.123-04:00[-04:00]");
+
+        // A buffer that is too small reports an error instead of panicking.
+        let mut small = Buf { bytes: [0; 64], len: 60 };
+        assert!(write!(small, "{ts}").is_err());
+        assert!(ts.format_into(&mut small).is_err());
+    }
+
+    #[test]
+    fn parse_timestamp_prefix() {
+        let parser = DateTimeParser::new();
+
+        let input = b"2024-06-19T15:22:45Z2024-06-19T11:22:46.5-04:00 tail";
+        let (ts1, len1) = parser.parse_timestamp_prefix(input).unwrap();
+        assert_eq!(ts1, Timestamp::new(1_718_810_565, 0).unwrap());
+        assert_eq!(len1, 20);
+        let rest = &input[len1..];
+        let (ts2, len2) = parser.parse_timestamp_prefix(rest).unwrap();
+        assert_eq!(ts2, Timestamp::new(1_718_810_566, 500_000_000).unwrap());
+        assert_eq!(&rest[len2..], b" tail");
+
+        // Annotations are consumed as part of the prefix.
+        let input = "2024-06-19T15:22:45Z[UTC] x";
+        let (_, len) = parser.parse_timestamp_prefix(input).unwrap();
+        assert_eq!(&input[len..], " x");
+
+        insta::assert_snapshot!(
+            parser.parse_timestamp_prefix("2024-06-19T15:22:45 x").unwrap_err(),
+            @"failed to find offset component in \"2024-06-19T15:22:45\", which is required for parsing a timestamp",
+        );
+        assert!(parser.parse_timestamp_prefix("nope").is_err());
+    }
+
+    #[test]
+    fn parse_subsecond_policy() {
+        let reject = DateTimeParser::new();
+        let truncate =
+            DateTimeParser::new().subsecond(SubsecondPolicy::Truncate);
+
+        let input = "2021-07-30T21:20:04.123456789999Z";
+        let expected = Timestamp::new(1_627_680_004, 123_456_789).unwrap();
+        assert_eq!(truncate.parse_timestamp(input).unwrap(), expected);
+        insta::assert_snapshot!(
+            reject.parse_timestamp(input).unwrap_err(),
+            @"failed to parse fractional nanoseconds in time \"21:20:04.123456789999Z\": found 12 digits after decimal, but at most 9 are allowed (fractional seconds beyond nanosecond precision are rejected unless truncation is enabled)",
+        );
+
+        // Truncation never rounds.
+        let input = "2021-07-30T21:20:04.999999999999-04[-04]";
+        let zdt = truncate.parse_zoned(input).unwrap();
+        assert_eq!(
+            zdt.datetime(),
+            civil::date(2021, 7, 30).at(21, 20, 4, 999_999_999),
+        );
+        assert!(reject.parse_zoned(input).is_err());
+
+        let input = "2021-07-30 21:20:04,000000000999";
+        assert_eq!(
+            truncate.parse_datetime(input).unwrap(),
+            civil::date(2021, 7, 30).at(21, 20, 4, 0),
+        );
+        insta::assert_snapshot!(
+            reject.parse_datetime(input).unwrap_err(),
+            @"failed to parse fractional nanoseconds in time \"21:20:04,000000000999\": found 12 digits after decimal, but at most 9 are allowed (fractional seconds beyond nanosecond precision are rejected unless truncation is enabled)",
+        );
+        assert_eq!(
+            truncate.parse_time("21:20:04.1234567891").unwrap(),
+            civil::time(21, 20, 4, 123_456_789),
+        );
+        assert!(reject.parse_time("21:20:04.1234567891").is_err());
+
+        // Exactly 9 digits is always fine.
+        let input = "2021-07-30T21:20:04.123456789Z";
+        assert_eq!(reject.parse_timestamp(input).unwrap(), expected);
+        assert_eq!(truncate.parse_timestamp(input).unwrap(), expected);
+
+        // Excess digits are consumed as part of a prefix.
+        let input = "2021-07-30T21:20:04.123456789999Z tail";
+        let (ts, len) = truncate.parse_timestamp_prefix(input).unwrap();
+        assert_eq!(ts, expected);
+        assert_eq!(&input[len..], " tail");
+        assert!(reject.parse_timestamp_prefix(input).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn print_span_precision() {
+        let span = 1.day().hours(2).seconds(3).milliseconds(450);
+        let dur = SignedDuration::new(7_203, 450_000_000);
+
+        let printer = SpanPrinter::new();
+        assert_eq!(printer.span_to_string(&span), "P1DT2H3.45S");
+        assert_eq!(printer.duration_to_string(&dur), "PT2H3.45S");
+
+        let printer = SpanPrinter::new().precision(Some(3));
+        assert_eq!(printer.span_to_string(&span), "P1DT2H3.450S");
+        assert_eq!(printer.duration_to_string(&dur), "PT2H3.450S");
+        assert_eq!(printer.span_to_string(&-span), "-P1DT2H3.450S");
+        assert_eq!(printer.span_to_string(&2.hours()), "PT2H");
+        assert_eq!(printer.span_to_string(&Span::new()), "PT0.000S");
+        assert_eq!(printer.span_to_string(&1.nanosecond()), "PT0.000S");
+
+        let printer = SpanPrinter::new().precision(Some(9));
+        assert_eq!(printer.span_to_string(&span), "P1DT2H3.450000000S");
+        assert_eq!(printer.duration_to_string(&dur), "PT2H3.450000000S");
+        assert_eq!(printer.span_to_string(&1.second()), "PT1.000000000S");
+
+        let printer = SpanPrinter::new().precision(Some(0));
+        assert_eq!(printer.span_to_string(&span), "P1DT2H3S");
+        assert_eq!(printer.duration_to_string(&dur), "PT2H3S");
+
+        let printer = SpanPrinter::new().precision(Some(255));
+        assert_eq!(printer.span_to_string(&span), "P1DT2H3.450000000S");
+    }
 }
@@ -1,10 +1,10 @@
 use crate::{
-    civil::{Date, DateTime, Time},
-    error::{err, Error, ErrorContext},
+    civil::{Date, DateTime, ISOWeekDate, Time, Weekday},
+    error::{parse_err, Error, ErrorContext},
     fmt::{
         offset::{self, ParsedOffset},
         rfc9557::{self, ParsedAnnotations},
-        temporal::Pieces,
+        temporal::{Pieces, SubsecondPolicy},
         util::{
             fractional_time_to_duration, fractional_time_to_span,
             parse_temporal_fraction,
@@ -20,6 +20,9 @@ use crate::{
     SignedDuration, Timestamp, Unit, Zoned,
 };
 
+#[cfg(feature = "alloc")]
+use crate::fmt::temporal::Annotations;
+
 /// The datetime components parsed from a string.
 #[derive(Debug)]
 pub(super) struct ParsedDateTime<'i> {
@@ -54,6 +57,21 @@ impl<'i> ParsedDateTime<'i> {
         Ok(pieces)
     }
 
+    /// Returns all of the annotations (and the offset) parsed.
+    #[cfg(feature = "alloc")]
+    pub(super) fn to_annotations(&self) -> Result<Annotations, Error> {
+        let time_zone = self
+            .annotations
+            .to_time_zone_annotation()?
+            .map(|ann| ann.into_owned());
+        let offset = match self.offset {
+            None => None,
+            Some(ref offset) => Some(offset.to_pieces_offset()?),
+        };
+        let others = self.annotations.to_annotations()?;
+        Ok(Annotations { time_zone, offset, others })
+    }
+
     #[inline(always)]
     pub(super) fn to_zoned(
         &self,
@@ -77,7 +95,7 @@ impl<'i> ParsedDateTime<'i> {
         // We always require a time zone when parsing a zoned instant.
         let tz_annotation =
             self.annotations.to_time_zone_annotation()?.ok_or_else(|| {
-                err!(
+                parse_err!(
                     "failed to find time zone in square brackets \
                      in {:?}, which is required for parsing a zoned instant",
                     self.input,
@@ -104,7 +122,7 @@ impl<'i> ParsedDateTime<'i> {
             return OffsetConflict::AlwaysOffset
                 .resolve(dt, Offset::UTC, tz)
                 .with_context(|| {
-                    err!("parsing {input:?} failed", input = self.input)
+                    parse_err!("parsing {input:?} failed", input = self.input)
                 });
         }
         let offset = parsed_offset.to_offset()?;
@@ -127,21 +145,21 @@ impl<'i> ParsedDateTime<'i> {
             parsed == candidate
         };
         offset_conflict.resolve_with(dt, offset, tz, is_equal).with_context(
-            || err!("parsing {input:?} failed", input = self.input),
+            || parse_err!("parsing {input:?} failed", input = self.input),
         )
     }
 
     #[inline(always)]
     pub(super) fn to_timestamp(&self) -> Result<Timestamp, Error> {
         let time = self.time.as_ref().map(|p| p.time).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "failed to find time component in {:?}, \
                  which is required for parsing a timestamp",
                 self.input,
             )
         })?;
         let parsed_offset = self.offset.as_ref().ok_or_else(|| {
-            err!(
+            parse_err!(
                 "failed to find offset component in {:?}, \
                  which is required for parsing a timestamp",
                 self.input,
@@ -150,7 +168,7 @@ impl<'i> ParsedDateTime<'i> {
         let offset = parsed_offset.to_offset()?;
         let dt = DateTime::from_parts(self.date.date, time);
         let timestamp = offset.to_timestamp(dt).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to convert civil datetime to timestamp \
                  with offset {offset}",
             )
@@ -161,7 +179,7 @@ impl<'i> ParsedDateTime<'i> {
     #[inline(always)]
     pub(super) fn to_datetime(&self) -> Result<DateTime, Error> {
         if self.offset.as_ref().map_or(false, |o| o.is_zulu()) {
-            return Err(err!(
+            return Err(parse_err!(
                 "cannot parse civil date from string with a Zulu \
                  offset, parse as a `Timestamp` and convert to a civil \
                  datetime instead",
@@ -173,7 +191,7 @@ impl<'i> ParsedDateTime<'i> {
     #[inline(always)]
     pub(super) fn to_date(&self) -> Result<Date, Error> {
         if self.offset.as_ref().map_or(false, |o| o.is_zulu()) {
-            return Err(err!(
+            return Err(parse_err!(
                 "cannot parse civil date from string with a Zulu \
                  offset, parse as a `Timestamp` and convert to a civil \
                  date instead",
@@ -262,7 +280,7 @@ impl<'i> ParsedTimeZone<'i> {
         match self.kind {
             ParsedTimeZoneKind::Named(iana_name) => {
                 let tz = db.get(iana_name).with_context(|| {
-                    err!(
+                    parse_err!(
                         "parsed apparent IANA time zone identifier \
                          {iana_name} from {input}, but the tzdb lookup \
                          failed",
@@ -273,7 +291,7 @@ impl<'i> ParsedTimeZone<'i> {
             }
             ParsedTimeZoneKind::Offset(poff) => {
                 let offset = poff.to_offset().with_context(|| {
-                    err!(
+                    parse_err!(
                         "offset successfully parsed from {input}, \
                          but failed to convert to numeric `Offset`",
                         input = self.input,
@@ -292,14 +310,43 @@ impl<'i> ParsedTimeZone<'i> {
 /// A parser for Temporal datetimes.
 #[derive(Debug)]
 pub(super) struct DateTimeParser {
-    /// There are currently no configuration options for this parser.
-    _priv: (),
+    /// Whether to permit the critical flag on RFC 9557 annotations that Jiff
+    /// doesn't support.
+    allow_unsupported_critical: bool,
+    /// What to do with fractional second digits beyond nanosecond precision.
+    subsecond: SubsecondPolicy,
 }
 
 impl DateTimeParser {
     /// Create a new Temporal datetime parser with the default configuration.
     pub(super) const fn new() -> DateTimeParser {
-        DateTimeParser { _priv: () }
+        DateTimeParser {
+            allow_unsupported_critical: false,
+            subsecond: SubsecondPolicy::Reject,
+        }
+    }
+
+    /// Set the policy for fractional second digits beyond nanosecond
+    /// precision.
+    pub(super) const fn subsecond(
+        self,
+        policy: SubsecondPolicy,
+    ) -> DateTimeParser {
+        DateTimeParser { subsecond: policy, ..self }
+    }
+
+    /// Whether to permit the critical flag on RFC 9557 annotations that Jiff
+    /// doesn't support. When disabled (the default), an error is returned.
+    ///
+    /// This should only be enabled when the caller gets access to all
+    /// annotations, since it's then the caller's responsibility to handle
+    /// critical annotations.
+    #[cfg(feature = "alloc")]
+    pub(super) const fn allow_unsupported_critical(
+        self,
+        yes: bool,
+    ) -> DateTimeParser {
+        DateTimeParser { allow_unsupported_critical: yes, ..self }
     }
 
     // TemporalDateTimeString[Zoned] :::
@@ -382,7 +429,7 @@ impl DateTimeParser {
             let Parsed { value: time, input } = self.parse_time_spec(input)?;
             let Parsed { value: offset, input } = self.parse_offset(input)?;
             if offset.map_or(false, |o| o.is_zulu()) {
-                return Err(err!(
+                return Err(parse_err!(
                     "cannot parse civil time from string with a Zulu \
                      offset, parse as a `Timestamp` and convert to a civil \
                      time instead",
@@ -403,14 +450,14 @@ impl DateTimeParser {
         if let Ok(parsed) = self.parse_temporal_datetime(input) {
             let Parsed { value: dt, input } = parsed;
             if dt.offset.map_or(false, |o| o.is_zulu()) {
-                return Err(err!(
+                return Err(parse_err!(
                     "cannot parse plain time from full datetime string with a \
                      Zulu offset, parse as a `Timestamp` and convert to a \
                      plain time instead",
                 ));
             }
             let Some(time) = dt.time else {
-                return Err(err!(
+                return Err(parse_err!(
                     "successfully parsed date from {parsed:?}, but \
                      no time component was found",
                     parsed = dt.input,
@@ -425,7 +472,7 @@ impl DateTimeParser {
         let Parsed { value: time, input } = self.parse_time_spec(input)?;
         let Parsed { value: offset, input } = self.parse_offset(input)?;
         if offset.map_or(false, |o| o.is_zulu()) {
-            return Err(err!(
+            return Err(parse_err!(
                 "cannot parse plain time from string with a Zulu \
                  offset, parse as a `Timestamp` and convert to a plain \
                  time instead",
@@ -441,14 +488,14 @@ impl DateTimeParser {
         if !time.extended {
             let possibly_ambiguous = mkslice(input);
             if self.parse_month_day(possibly_ambiguous).is_ok() {
-                return Err(err!(
+                return Err(parse_err!(
                     "parsed time from {parsed:?} is ambiguous \
                              with a month-day date",
                     parsed = escape::Bytes(possibly_ambiguous),
                 ));
             }
             if self.parse_year_month(possibly_ambiguous).is_ok() {
-                return Err(err!(
+                return Err(parse_err!(
                     "parsed time from {parsed:?} is ambiguous \
                              with a year-month date",
                     parsed = escape::Bytes(possibly_ambiguous),
@@ -460,13 +507,93 @@ impl DateTimeParser {
         Ok(Parsed { value: time, input })
     }
 
+    // This isn't part of Temporal's grammar, but it follows ISO 8601's
+    // format for week dates:
+    //
+    // DateSpecWeek :::
+    //   DateYear - DateWeekDesignator DateWeek - DateWeekday
+    //   DateYear DateWeekDesignator DateWeek DateWeekday
+    //
+    // DateWeekDesignator ::: one of
+    //   W w
+    #[inline(always)]
+    pub(super) fn parse_iso_week_date<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, ISOWeekDate>, Error> {
+        let original = escape::Bytes(input);
+
+        // Parse year component.
+        let Parsed { value: year, input } =
+            self.parse_year(input).with_context(|| {
+                parse_err!(
+                    "failed to parse year in ISO week date {original:?}"
+                )
+            })?;
+        let extended = input.starts_with(b"-");
+
+        // Parse optional separator.
+        let Parsed { input, .. } = self
+            .parse_date_separator(input, extended)
+            .context("failed to parse separator after year")?;
+
+        // Parse week designator.
+        let input = match input.first() {
+            Some(&(b'W' | b'w')) => &input[1..],
+            Some(&byte) => {
+                return Err(parse_err!(
+                    "expected 'W' week designator after year in ISO week \
+                     date {original:?}, but found {found:?} instead",
+                    found = escape::Byte(byte),
+                ));
+            }
+            None => {
+                return Err(parse_err!(
+                    "expected 'W' week designator after year in ISO week \
+                     date {original:?}, but found end of input",
+                ));
+            }
+        };
+
+        // Parse week component.
+        let Parsed { value: week, input } =
+            self.parse_week(input).with_context(|| {
+                parse_err!(
+                    "failed to parse week in ISO week date {original:?}"
+                )
+            })?;
+
+        // Parse optional separator.
+        let Parsed { input, .. } = self
+            .parse_date_separator(input, extended)
+            .context("failed to parse separator after week")?;
+
+        // Parse weekday component.
+        let Parsed { value: weekday, input } =
+            self.parse_weekday(input).with_context(|| {
+                parse_err!(
+                    "failed to parse weekday in ISO week date {original:?}"
+                )
+            })?;
+
+        let weekdate = ISOWeekDate::new_ranged(year, week, weekday)
+            .with_context(|| {
+                parse_err!(
+                    "ISO week date parsed from {original:?} is not valid"
+                )
+            })?;
+        Ok(Parsed { value: weekdate, input })
+    }
+
     #[inline(always)]
     pub(super) fn parse_time_zone<'i>(
         &self,
         mut input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedTimeZone<'i>>, Error> {
         let Some(first) = input.first().copied() else {
-            return Err(err!("an empty string is not a valid time zone"));
+            return Err(parse_err!(
+                "an empty string is not a valid time zone"
+            ));
         };
         let original = escape::Bytes(input);
         if matches!(first, b'+' | b'-') {
@@ -485,7 +612,7 @@ impl DateTimeParser {
         // different cases below, hence the helper function.
         let mknamed = |consumed, remaining| {
             let Ok(tzid) = core::str::from_utf8(consumed) else {
-                return Err(err!(
+                return Err(parse_err!(
                     "found plausible IANA time zone identifier \
                      {input:?}, but it is not valid UTF-8",
                     input = escape::Bytes(consumed),
@@ -523,7 +650,7 @@ impl DateTimeParser {
         }
         #[cfg(not(feature = "alloc"))]
         {
-            Err(err!(
+            Err(parse_err!(
                 "cannot parsed time zones other than fixed offsets \
                  without the `alloc` crate feature enabled",
             ))
@@ -562,7 +689,7 @@ impl DateTimeParser {
         // Parse year component.
         let Parsed { value: year, input } =
             self.parse_year(input).with_context(|| {
-                err!("failed to parse year in date {original:?}")
+                parse_err!("failed to parse year in date {original:?}")
             })?;
         let extended = input.starts_with(b"-");
 
@@ -574,7 +701,7 @@ impl DateTimeParser {
         // Parse month component.
         let Parsed { value: month, input } =
             self.parse_month(input).with_context(|| {
-                err!("failed to parse month in date {original:?}")
+                parse_err!("failed to parse month in date {original:?}")
             })?;
 
         // Parse optional separator.
@@ -585,11 +712,11 @@ impl DateTimeParser {
         // Parse day component.
         let Parsed { value: day, input } =
             self.parse_day(input).with_context(|| {
-                err!("failed to parse day in date {original:?}")
+                parse_err!("failed to parse day in date {original:?}")
             })?;
 
         let date = Date::new_ranged(year, month, day).with_context(|| {
-            err!("date parsed from {original:?} is not valid")
+            parse_err!("date parsed from {original:?} is not valid")
         })?;
         let value = ParsedDate { input: escape::Bytes(mkslice(input)), date };
         Ok(Parsed { value, input })
@@ -612,7 +739,7 @@ impl DateTimeParser {
         // Parse hour component.
         let Parsed { value: hour, input } =
             self.parse_hour(input).with_context(|| {
-                err!("failed to parse hour in time {original:?}")
+                parse_err!("failed to parse hour in time {original:?}")
             })?;
         let extended = input.starts_with(b":");
 
@@ -635,7 +762,7 @@ impl DateTimeParser {
         }
         let Parsed { value: minute, input } =
             self.parse_minute(input).with_context(|| {
-                err!("failed to parse minute in time {original:?}")
+                parse_err!("failed to parse minute in time {original:?}")
             })?;
 
         // Parse optional second component.
@@ -657,17 +784,27 @@ impl DateTimeParser {
         }
         let Parsed { value: second, input } =
             self.parse_second(input).with_context(|| {
-                err!("failed to parse second in time {original:?}")
+                parse_err!("failed to parse second in time {original:?}")
             })?;
 
         // Parse an optional fractional component.
         let Parsed { value: nanosecond, input } =
             parse_temporal_fraction(input).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse fractional nanoseconds \
                      in time {original:?}",
                 )
             })?;
+        let input = if nanosecond.is_some() {
+            self.parse_excess_fraction(input).with_context(|| {
+                parse_err!(
+                    "failed to parse fractional nanoseconds \
+                     in time {original:?}",
+                )
+            })?
+        } else {
+            input
+        };
 
         let time = Time::new_ranged(
             hour,
@@ -683,6 +820,31 @@ impl DateTimeParser {
         Ok(Parsed { value, input })
     }
 
+    /// Handles any fractional second digits remaining after parsing a
+    /// fraction with nanosecond precision.
+    ///
+    /// When truncation is enabled, the excess digits are consumed and
+    /// discarded. Otherwise, excess digits result in an error.
+    #[inline(always)]
+    fn parse_excess_fraction<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<&'i [u8], Error> {
+        let excess = input.iter().take_while(|b| b.is_ascii_digit()).count();
+        if excess == 0 {
+            return Ok(input);
+        }
+        match self.subsecond {
+            SubsecondPolicy::Truncate => Ok(&input[excess..]),
+            SubsecondPolicy::Reject => Err(parse_err!(
+                "found {digits} digits after decimal, but at most 9 are \
+                 allowed (fractional seconds beyond nanosecond precision \
+                 are rejected unless truncation is enabled)",
+                digits = 9 + excess,
+            )),
+        }
+    }
+
     // ValidMonthDay :::
     //   DateMonth -[opt] 0 NonZeroDigit
     //   DateMonth -[opt] 1 DecimalDigit
@@ -705,7 +867,7 @@ impl DateTimeParser {
         // Parse month component.
         let Parsed { value: month, mut input } =
             self.parse_month(input).with_context(|| {
-                err!("failed to parse month in month-day {original:?}")
+                parse_err!("failed to parse month in month-day {original:?}")
             })?;
 
         // Skip over optional separator.
@@ -716,7 +878,7 @@ impl DateTimeParser {
         // Parse day component.
         let Parsed { value: day, input } =
             self.parse_day(input).with_context(|| {
-                err!("failed to parse day in month-day {original:?}")
+                parse_err!("failed to parse day in month-day {original:?}")
             })?;
 
         // Check that the month-day is valid. Since Temporal's month-day
@@ -725,7 +887,7 @@ impl DateTimeParser {
         // user.
         let year = t::Year::N::<2024>();
         let _ = Date::new_ranged(year, month, day).with_context(|| {
-            err!("month-day parsed from {original:?} is not valid")
+            parse_err!("month-day parsed from {original:?} is not valid")
         })?;
 
         // We have a valid year-month. But we don't return it because we just
@@ -748,7 +910,7 @@ impl DateTimeParser {
         // Parse year component.
         let Parsed { value: year, mut input } =
             self.parse_year(input).with_context(|| {
-                err!("failed to parse year in date {original:?}")
+                parse_err!("failed to parse year in date {original:?}")
             })?;
 
         // Skip over optional separator.
@@ -759,14 +921,14 @@ impl DateTimeParser {
         // Parse month component.
         let Parsed { value: month, input } =
             self.parse_month(input).with_context(|| {
-                err!("failed to parse month in month-day {original:?}")
+                parse_err!("failed to parse month in month-day {original:?}")
             })?;
 
         // Check that the year-month is valid. We just use a day of 1, since
         // every month in every year must have a day 1.
         let day = t::Day::N::<1>();
         let _ = Date::new_ranged(year, month, day).with_context(|| {
-            err!("year-month parsed from {original:?} is not valid")
+            parse_err!("year-month parsed from {original:?} is not valid")
         })?;
 
         // We have a valid year-month. But we don't return it because we just
@@ -792,13 +954,13 @@ impl DateTimeParser {
         let Parsed { value: sign, input } = self.parse_year_sign(input);
         if let Some(sign) = sign {
             let (year, input) = parse::split(input, 6).ok_or_else(|| {
-                err!(
+                parse_err!(
                     "expected six digit year (because of a leading sign), \
                      but found end of input",
                 )
             })?;
             let year = parse::i64(year).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse {year:?} as year (a six digit integer)",
                     year = escape::Bytes(year),
                 )
@@ -806,7 +968,7 @@ impl DateTimeParser {
             let year =
                 t::Year::try_new("year", year).context("year is not valid")?;
             if year == 0 && sign < 0 {
-                return Err(err!(
+                return Err(parse_err!(
                     "year zero must be written without a sign or a \
                      positive sign, but not a negative sign",
                 ));
@@ -814,13 +976,13 @@ impl DateTimeParser {
             Ok(Parsed { value: year * sign, input })
         } else {
             let (year, input) = parse::split(input, 4).ok_or_else(|| {
-                err!(
+                parse_err!(
                     "expected four digit year (or leading sign for \
                      six digit year), but found end of input",
                 )
             })?;
             let year = parse::i64(year).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to parse {year:?} as year (a four digit integer)",
                     year = escape::Bytes(year),
                 )
@@ -842,10 +1004,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Month>, Error> {
         let (month, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit month, but found end of input")
+            parse_err!("expected two digit month, but found end of input")
         })?;
         let month = parse::i64(month).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {month:?} as month (a two digit integer)",
                 month = escape::Bytes(month),
             )
@@ -867,10 +1029,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Day>, Error> {
         let (day, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit day, but found end of input")
+            parse_err!("expected two digit day, but found end of input")
         })?;
         let day = parse::i64(day).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {day:?} as day (a two digit integer)",
                 day = escape::Bytes(day),
             )
@@ -879,6 +1041,59 @@ impl DateTimeParser {
         Ok(Parsed { value: day, input })
     }
 
+    // DateWeek :::
+    //   0 NonZeroDigit
+    //   1 DecimalDigit
+    //   2 DecimalDigit
+    //   3 DecimalDigit
+    //   4 DecimalDigit
+    //   50
+    //   51
+    //   52
+    //   53
+    #[inline(always)]
+    fn parse_week<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, t::ISOWeek>, Error> {
+        let (week, input) = parse::split(input, 2).ok_or_else(|| {
+            parse_err!("expected two digit week, but found end of input")
+        })?;
+        let week = parse::i64(week).with_context(|| {
+            parse_err!(
+                "failed to parse {week:?} as week (a two digit integer)",
+                week = escape::Bytes(week),
+            )
+        })?;
+        let week =
+            t::ISOWeek::try_new("week", week).context("week is not valid")?;
+        Ok(Parsed { value: week, input })
+    }
+
+    // DateWeekday ::: one of
+    //   1 2 3 4 5 6 7
+    #[inline(always)]
+    fn parse_weekday<'i>(
+        &self,
+        input: &'i [u8],
+    ) -> Result<Parsed<'i, Weekday>, Error> {
+        let (weekday, input) = parse::split(input, 1).ok_or_else(|| {
+            parse_err!("expected one digit weekday, but found end of input")
+        })?;
+        let weekday = parse::i64(weekday).with_context(|| {
+            parse_err!(
+                "failed to parse {weekday:?} as weekday (a one digit integer)",
+                weekday = escape::Bytes(weekday),
+            )
+        })?;
+        let weekday = t::WeekdayOne::try_new("weekday", weekday)
+            .context("weekday is not valid")?;
+        Ok(Parsed {
+            value: Weekday::from_monday_one_offset_ranged(weekday),
+            input,
+        })
+    }
+
     // TimeHour :::
     //   Hour
     //
@@ -895,10 +1110,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Hour>, Error> {
         let (hour, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit hour, but found end of input")
+            parse_err!("expected two digit hour, but found end of input")
         })?;
         let hour = parse::i64(hour).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {hour:?} as hour (a two digit integer)",
                 hour = escape::Bytes(hour),
             )
@@ -924,10 +1139,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Minute>, Error> {
         let (minute, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit minute, but found end of input")
+            parse_err!("expected two digit minute, but found end of input")
         })?;
         let minute = parse::i64(minute).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {minute:?} as minute (a two digit integer)",
                 minute = escape::Bytes(minute),
             )
@@ -954,10 +1169,10 @@ impl DateTimeParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, t::Second>, Error> {
         let (second, input) = parse::split(input, 2).ok_or_else(|| {
-            err!("expected two digit second, but found end of input",)
+            parse_err!("expected two digit second, but found end of input",)
         })?;
         let mut second = parse::i64(second).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {second:?} as second (a two digit integer)",
                 second = escape::Bytes(second),
             )
@@ -987,12 +1202,13 @@ impl DateTimeParser {
         &self,
         input: &'i [u8],
     ) -> Result<Parsed<'i, ParsedAnnotations<'i>>, Error> {
-        const P: rfc9557::Parser = rfc9557::Parser::new();
         if input.is_empty() || input[0] != b'[' {
             let value = ParsedAnnotations::none();
             return Ok(Parsed { input, value });
         }
-        P.parse(input)
+        rfc9557::Parser::new()
+            .allow_unsupported_critical(self.allow_unsupported_critical)
+            .parse(input)
     }
 
     /// Parses the separator that is expected to appear between
@@ -1010,7 +1226,7 @@ impl DateTimeParser {
             // If we see a '-' when not in extended mode, then we can report
             // a better error message than, e.g., "-3 isn't a valid day."
             if input.starts_with(b"-") {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected no separator after month since none was \
                      found after the year, but found a '-' separator",
                 ));
@@ -1018,12 +1234,12 @@ impl DateTimeParser {
             return Ok(Parsed { value: (), input });
         }
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected '-' separator, but found end of input"
             ));
         }
         if input[0] != b'-' {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected '-' separator, but found {found:?} instead",
                 found = escape::Byte(input[0]),
             ));
@@ -1147,7 +1363,7 @@ impl SpanParser {
 
             let (time_span, parsed_any_time) = parsed.value;
             if !parsed_any_time {
-                return Err(err!(
+                return Err(parse_err!(
                     "found a time designator (T or t) in an ISO 8601 \
                      duration string in {original:?}, but did not find \
                      any time units",
@@ -1155,7 +1371,7 @@ impl SpanParser {
             }
             span = time_span;
         } else if !parsed_any_date {
-            return Err(err!(
+            return Err(parse_err!(
                 "found the start of a ISO 8601 duration string \
                  in {original:?}, but did not find any units",
             ));
@@ -1176,7 +1392,7 @@ impl SpanParser {
         let Parsed { value: has_time, input } =
             self.parse_time_designator(input);
         if !has_time {
-            return Err(err!(
+            return Err(parse_err!(
                 "parsing ISO 8601 duration into SignedDuration requires \
                  that the duration contain a time component and no \
                  components of days or greater",
@@ -1212,7 +1428,7 @@ impl SpanParser {
 
             if let Some(prev_unit) = prev_unit {
                 if prev_unit <= unit {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found value {value:?} with unit {unit} \
                          after unit {prev_unit}, but units must be \
                          written from largest to smallest \
@@ -1224,7 +1440,7 @@ impl SpanParser {
             }
             prev_unit = Some(unit);
             span = span.try_units_ranged(unit, value).with_context(|| {
-                err!(
+                parse_err!(
                     "failed to set value {value:?} as {unit} unit on span",
                     unit = Unit::from(unit).singular(),
                 )
@@ -1263,7 +1479,7 @@ impl SpanParser {
 
             if let Some(prev_unit) = prev_unit {
                 if prev_unit <= unit {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found value {value:?} with unit {unit} \
                          after unit {prev_unit}, but units must be \
                          written from largest to smallest \
@@ -1285,7 +1501,7 @@ impl SpanParser {
             } else {
                 let result =
                     span.try_units_ranged(unit, value).with_context(|| {
-                        err!(
+                        parse_err!(
                             "failed to set value {value:?} \
                              as {unit} unit on span",
                             unit = Unit::from(unit).singular(),
@@ -1343,7 +1559,7 @@ impl SpanParser {
 
             if let Some(prev_unit) = prev_unit {
                 if prev_unit <= unit {
-                    return Err(err!(
+                    return Err(parse_err!(
                         "found value {value:?} with unit {unit} \
                          after unit {prev_unit}, but units must be \
                          written from largest to smallest \
@@ -1362,7 +1578,7 @@ impl SpanParser {
                 Unit::Minute => {
                     let mins = value.get();
                     mins.checked_mul(60).ok_or_else(|| {
-                        err!(
+                        parse_err!(
                             "minute units {mins} overflowed i64 when \
                              converted to seconds"
                         )
@@ -1371,7 +1587,7 @@ impl SpanParser {
                 Unit::Hour => {
                     let hours = value.get();
                     hours.checked_mul(3_600).ok_or_else(|| {
-                        err!(
+                        parse_err!(
                             "hour units {hours} overflowed i64 when \
                              converted to seconds"
                         )
@@ -1390,7 +1606,7 @@ impl SpanParser {
                 dur.checked_add(unit_dur)
             };
             dur = result.ok_or_else(|| {
-                err!(
+                parse_err!(
                     "adding value {value} from unit {unit} overflowed \
                      signed duration {dur:?}",
                     unit = unit.singular(),
@@ -1406,7 +1622,7 @@ impl SpanParser {
                     dur.checked_add(fraction_dur)
                 };
                 dur = result.ok_or_else(|| {
-                    err!(
+                    parse_err!(
                         "adding fractional duration {fraction_dur:?} \
                          from unit {unit} to {dur:?} overflowed \
                          signed duration limits",
@@ -1420,7 +1636,7 @@ impl SpanParser {
             }
         }
         if !parsed_any {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected at least one unit of time (hours, minutes or \
                  seconds) in ISO 8601 duration when parsing into a \
                  `SignedDuration`",
@@ -1448,7 +1664,7 @@ impl SpanParser {
             return Ok(Parsed { value: None, input });
         }
         let value = parse::i64(digits).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to parse {digits:?} as 64-bit signed integer",
                 digits = escape::Bytes(digits),
             )
@@ -1464,7 +1680,7 @@ impl SpanParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, Unit>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find date unit designator suffix \
                  (Y, M, W or D), but found end of input",
             ));
@@ -1475,7 +1691,7 @@ impl SpanParser {
             b'W' | b'w' => Unit::Week,
             b'D' | b'd' => Unit::Day,
             unknown => {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected to find date unit designator suffix \
                      (Y, M, W or D), but found {found:?} instead",
                     found = escape::Byte(unknown),
@@ -1491,7 +1707,7 @@ impl SpanParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, Unit>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find time unit designator suffix \
                  (H, M or S), but found end of input",
             ));
@@ -1501,7 +1717,7 @@ impl SpanParser {
             b'M' | b'm' => Unit::Minute,
             b'S' | b's' => Unit::Second,
             unknown => {
-                return Err(err!(
+                return Err(parse_err!(
                     "expected to find time unit designator suffix \
                      (H, M or S), but found {found:?} instead",
                     found = escape::Byte(unknown),
@@ -1519,13 +1735,13 @@ impl SpanParser {
         input: &'i [u8],
     ) -> Result<Parsed<'i, ()>, Error> {
         if input.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected to find duration beginning with 'P' or 'p', \
                  but found end of input",
             ));
         }
         if !matches!(input[0], b'P' | b'p') {
-            return Err(err!(
+            return Err(parse_err!(
                 "expected 'P' or 'p' prefix to begin duration, \
                  but found {found:?} instead",
                 found = escape::Byte(input[0]),
@@ -1726,3 +1726,130 @@ impl<'n> From<&'n str> for TimeZoneAnnotationName<'n> {
         TimeZoneAnnotationName { name: StringCow::from(string) }
     }
 }
+
+/// All of the [RFC 9557] annotations parsed from a zoned datetime string,
+/// along with its UTC offset.
+///
+/// This is returned by [`Zoned::parse_full`]. It is meant for tooling that
+/// needs to inspect (and perhaps re-emit) the annotations in a datetime
+/// string exactly as they were written. Parsing a [`Zoned`] via its
+/// `FromStr` trait implementation instead discards everything except for
+/// what is needed to construct the `Zoned` value.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::temporal::TimeZoneAnnotation, tz::offset, Zoned};
+///
+/// let (zdt, ann) = Zoned::parse_full(
+///     "2025-01-02T16:47-05[America/New_York][u-ca=iso8601]",
+/// )?;
+/// assert_eq!(zdt.to_string(), "2025-01-02T16:47:00-05:00[America/New_York]");
+/// assert_eq!(
+///     ann.time_zone_annotation(),
+///     Some(&TimeZoneAnnotation::from("America/New_York")),
+/// );
+/// assert_eq!(ann.offset().map(|o| o.to_numeric_offset()), Some(offset(-5)));
+/// assert_eq!(ann.others().len(), 1);
+/// assert_eq!(ann.others()[0].key(), "u-ca");
+/// assert_eq!(ann.others()[0].value(), "iso8601");
+/// assert!(!ann.others()[0].is_critical());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557.html
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Annotations {
+    pub(crate) time_zone: Option<TimeZoneAnnotation<'static>>,
+    pub(crate) offset: Option<PiecesOffset>,
+    pub(crate) others: alloc::vec::Vec<Annotation>,
+}
+
+#[cfg(feature = "alloc")]
+impl Annotations {
+    /// Returns the time zone annotation, if one was present.
+    ///
+    /// When parsing a [`Zoned`], this is always present. Whether it was
+    /// marked as critical is available via
+    /// [`TimeZoneAnnotation::is_critical`].
+    #[inline]
+    pub fn time_zone_annotation(
+        &self,
+    ) -> Option<&TimeZoneAnnotation<'static>> {
+        self.time_zone.as_ref()
+    }
+
+    /// Returns the UTC offset that preceded the annotations, if one was
+    /// present.
+    ///
+    /// Note that this is not itself an annotation, but it is included here
+    /// since it can't otherwise be recovered from the parsed [`Zoned`]. For
+    /// example, it may be `Z` (see [`PiecesOffset::Zulu`]) or a `-00:00`
+    /// offset.
+    #[inline]
+    pub fn offset(&self) -> Option<PiecesOffset> {
+        self.offset
+    }
+
+    /// Returns all of the key/value annotations other than the time zone
+    /// annotation, in the order in which they appeared.
+    ///
+    /// Jiff doesn't make use of any of these annotations. In particular,
+    /// unlike when parsing via `FromStr`, [`Zoned::parse_full`] does not
+    /// reject annotations marked as critical. It is up to the caller to
+    /// inspect [`Annotation::is_critical`] and act accordingly.
+    #[inline]
+    pub fn others(&self) -> &[Annotation] {
+        &self.others
+    }
+}
+
+/// A single key/value [RFC 9557] annotation, e.g., `[u-ca=iso8601]`.
+///
+/// Values of this type are returned by [`Annotations::others`].
+///
+/// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557.html
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Annotation {
+    critical: bool,
+    key: alloc::string::String,
+    value: alloc::string::String,
+}
+
+#[cfg(feature = "alloc")]
+impl Annotation {
+    pub(crate) fn new(
+        critical: bool,
+        key: alloc::string::String,
+        value: alloc::string::String,
+    ) -> Annotation {
+        Annotation { critical, key, value }
+    }
+
+    /// Returns the key of this annotation. For example, `u-ca` in
+    /// `[u-ca=iso8601]`.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of this annotation. For example, `iso8601` in
+    /// `[u-ca=iso8601]`.
+    ///
+    /// When a value consists of multiple components, they are returned
+    /// joined by `-` exactly as they appeared in the input.
+    #[inline]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns true when this annotation is marked as "critical," i.e., with
+    /// a `!` prefix like `[!u-ca=iso8601]`.
+    #[inline]
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    civil::{Date, DateTime, Time},
+    civil::{Date, DateTime, ISOWeekDate, Time},
     error::{err, Error},
     fmt::{
         temporal::{Pieces, PiecesOffset, TimeZoneAnnotationKind},
@@ -123,6 +123,31 @@ impl DateTimePrinter {
         Ok(())
     }
 
+    /// Formats the given ISO 8601 week date into the writer given.
+    pub(super) fn print_iso_week_date<W: Write>(
+        &self,
+        weekdate: &ISOWeekDate,
+        mut wtr: W,
+    ) -> Result<(), Error> {
+        static FMT_YEAR_POSITIVE: DecimalFormatter =
+            DecimalFormatter::new().padding(4);
+        static FMT_YEAR_NEGATIVE: DecimalFormatter =
+            DecimalFormatter::new().padding(6);
+        static FMT_TWO: DecimalFormatter = DecimalFormatter::new().padding(2);
+        static FMT_ONE: DecimalFormatter = DecimalFormatter::new();
+
+        if weekdate.year() >= 0 {
+            wtr.write_int(&FMT_YEAR_POSITIVE, weekdate.year())?;
+        } else {
+            wtr.write_int(&FMT_YEAR_NEGATIVE, weekdate.year())?;
+        }
+        wtr.write_str("-W")?;
+        wtr.write_int(&FMT_TWO, weekdate.week())?;
+        wtr.write_str("-")?;
+        wtr.write_int(&FMT_ONE, weekdate.weekday().to_monday_one_offset())?;
+        Ok(())
+    }
+
     /// Formats the given time into the writer given.
     pub(super) fn print_time<W: Write>(
         &self,
@@ -379,19 +404,29 @@ impl Default for DateTimePrinter {
 pub(super) struct SpanPrinter {
     /// Whether to use lowercase unit designators.
     lowercase: bool,
+    /// The number of fractional second digits to write, if fixed.
+    precision: Option<u8>,
 }
 
 impl SpanPrinter {
     /// Create a new Temporal span printer with the default configuration.
     pub(super) const fn new() -> SpanPrinter {
-        SpanPrinter { lowercase: false }
+        SpanPrinter { lowercase: false, precision: None }
     }
 
     /// Use lowercase for unit designator labels.
     ///
     /// By default, unit designator labels are written in uppercase.
     pub(super) const fn lowercase(self, yes: bool) -> SpanPrinter {
-        SpanPrinter { lowercase: yes }
+        SpanPrinter { lowercase: yes, ..self }
+    }
+
+    /// Set the precision to use for the fractional seconds component.
+    ///
+    /// By default, the fractional component is written with the minimal
+    /// number of digits required, and omitted when it is zero.
+    pub(super) const fn precision(self, precision: Option<u8>) -> SpanPrinter {
+        SpanPrinter { precision, ..self }
     }
 
     /// Print the given span to the writer given.
@@ -403,7 +438,6 @@ impl SpanPrinter {
         mut wtr: W,
     ) -> Result<(), Error> {
         static FMT_INT: DecimalFormatter = DecimalFormatter::new();
-        static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
 
         if span.is_negative() {
             wtr.write_str("-")?;
@@ -462,17 +496,12 @@ impl SpanPrinter {
             span.get_microseconds_ranged().abs(),
             span.get_nanoseconds_ranged().abs(),
         );
-        if (seconds != 0 || !non_zero_greater_than_second)
-            && millis == 0
-            && micros == 0
-            && nanos == 0
+        if seconds != 0
+            || millis != 0
+            || micros != 0
+            || nanos != 0
+            || !non_zero_greater_than_second
         {
-            if !printed_time_prefix {
-                wtr.write_str("T")?;
-            }
-            wtr.write_int(&FMT_INT, seconds.get())?;
-            wtr.write_char(self.label('S'))?;
-        } else if millis != 0 || micros != 0 || nanos != 0 {
             if !printed_time_prefix {
                 wtr.write_str("T")?;
             }
@@ -496,10 +525,7 @@ impl SpanPrinter {
                 combined_as_nanos % t::NANOS_PER_SECOND,
             );
             wtr.write_int(&FMT_INT, fraction_second.get())?;
-            if fraction_nano != 0 {
-                wtr.write_str(".")?;
-                wtr.write_fraction(&FMT_FRACTION, fraction_nano.get())?;
-            }
+            self.print_fraction(fraction_nano.get(), &mut wtr)?;
             wtr.write_char(self.label('S'))?;
         }
         Ok(())
@@ -514,7 +540,6 @@ impl SpanPrinter {
         mut wtr: W,
     ) -> Result<(), Error> {
         static FMT_INT: DecimalFormatter = DecimalFormatter::new();
-        static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
 
         let mut non_zero_greater_than_second = false;
         if dur.is_negative() {
@@ -542,14 +567,32 @@ impl SpanPrinter {
             wtr.write_char(self.label('M'))?;
             non_zero_greater_than_second = true;
         }
-        if (secs != 0 || !non_zero_greater_than_second) && nanos == 0 {
+        if secs != 0 || nanos != 0 || !non_zero_greater_than_second {
             wtr.write_int(&FMT_INT, secs)?;
+            self.print_fraction(nanos, &mut wtr)?;
             wtr.write_char(self.label('S'))?;
-        } else if nanos != 0 {
-            wtr.write_int(&FMT_INT, secs)?;
+        }
+        Ok(())
+    }
+
+    /// Print the fractional seconds component (including the leading `.`),
+    /// given as a number of nanoseconds, according to this printer's
+    /// precision setting.
+    ///
+    /// When no precision is set, nothing is written if the fraction is zero.
+    fn print_fraction<W: Write>(
+        &self,
+        nanos: i32,
+        wtr: &mut W,
+    ) -> Result<(), Error> {
+        static FMT_FRACTION: FractionalFormatter = FractionalFormatter::new();
+
+        if self.precision.map_or(nanos != 0, |p| p > 0) {
             wtr.write_str(".")?;
-            wtr.write_fraction(&FMT_FRACTION, nanos)?;
-            wtr.write_char(self.label('S'))?;
+            wtr.write_fraction(
+                &FMT_FRACTION.precision(self.precision),
+                nanos,
+            )?;
         }
         Ok(())
     }
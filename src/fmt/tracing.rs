@@ -0,0 +1,199 @@
+/*!
+This module provides helpers for recording Jiff values as [`tracing`] fields.
+
+This module is only available when the `tracing` crate feature is enabled.
+
+The helpers in this module wrap a datetime in a value that `tracing` records
+as an [RFC 3339] string. For example, `2024-06-19T15:22:45-04:00`. This is
+usually what structured logging consumers expect, and avoids calling
+`to_string()` at every log site or recording the `Debug` representation of a
+value by mistake.
+
+Note that Jiff can't implement `tracing`'s `Value` trait on its types
+directly, since that trait is sealed. Instead, the helpers in this module
+return a [`DisplayValue`], which implements `Value`.
+
+[`tracing`]: https://docs.rs/tracing
+[RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+
+# Example
+
+```
+use jiff::{fmt::tracing::{timestamp, zoned}, Timestamp};
+
+let zdt = Timestamp::from_second(1_718_824_965)?.in_tz("America/New_York")?;
+tracing::info!(
+    at = zoned(&zdt),
+    at_utc = timestamp(zdt.timestamp()),
+    "something happened",
+);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use tracing_core::field::{display, DisplayValue};
+
+use crate::{Timestamp, TimestampDisplayWithOffset, Zoned};
+
+/// Returns a `tracing` value that records the given timestamp as an RFC 3339
+/// string in UTC.
+///
+/// The string recorded is the same as the one produced by the
+/// [`std::fmt::Display`] trait implementation on [`Timestamp`]. For example,
+/// `2024-06-19T19:22:45Z`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::tracing::timestamp, Timestamp};
+///
+/// let ts = Timestamp::from_second(1_718_824_965)?;
+/// tracing::info!(at = timestamp(ts), "something happened");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn timestamp(timestamp: Timestamp) -> DisplayValue<Timestamp> {
+    display(timestamp)
+}
+
+/// Returns a `tracing` value that records the given zoned datetime as an
+/// RFC 3339 string with its offset.
+///
+/// The string recorded is the same as the one produced by
+/// [`Timestamp::display_with_offset`] with the zoned datetime's offset. For
+/// example, `2024-06-19T15:22:45-04:00`. Notably, the time zone annotation
+/// that is included by the [`std::fmt::Display`] trait implementation on
+/// [`Zoned`] is omitted, since it isn't part of RFC 3339. Similarly, an
+/// offset with a non-zero seconds component is rounded to the nearest minute.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{fmt::tracing::zoned, Timestamp};
+///
+/// let ts = Timestamp::from_second(1_718_824_965)?;
+/// let zdt = ts.in_tz("America/New_York")?;
+/// tracing::info!(at = zoned(&zdt), "something happened");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn zoned(zdt: &Zoned) -> DisplayValue<TimestampDisplayWithOffset> {
+    display(zdt.timestamp().display_with_offset(zdt.offset()))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use std::sync::{Arc, Mutex};
+
+    use tracing_core::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::civil::date;
+
+    use super::*;
+
+    /// A subscriber that captures every field recorded on events.
+    #[derive(Clone, Default)]
+    struct Capture {
+        fields: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl Capture {
+        fn record(f: impl FnOnce()) -> Vec<(String, String)> {
+            let capture = Capture::default();
+            tracing::subscriber::with_default(capture.clone(), f);
+            let fields = capture.fields.lock().unwrap();
+            fields.clone()
+        }
+    }
+
+    impl Visit for Capture {
+        fn record_debug(
+            &mut self,
+            field: &Field,
+            value: &dyn core::fmt::Debug,
+        ) {
+            let (name, value) =
+                (field.name().to_string(), alloc::format!("{value:?}"));
+            self.fields.lock().unwrap().push((name, value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn records_rfc3339() {
+        let ts = Timestamp::new(1_718_824_965, 123_000_000).unwrap();
+        let zdt =
+            ts.to_zoned(crate::tz::TimeZone::fixed(crate::tz::offset(-4)));
+        let fields = Capture::record(|| {
+            tracing::info!(ts = timestamp(ts), zdt = zoned(&zdt), "hello");
+        });
+        let get = |name: &str| {
+            fields.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("ts"), Some("2024-06-19T19:22:45.123Z"));
+        assert_eq!(get("zdt"), Some("2024-06-19T15:22:45.123-04:00"));
+    }
+
+    #[test]
+    fn records_rfc3339_in_named_time_zone() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let zdt = date(2024, 3, 10).at(3, 0, 0, 0).in_tz("America/New_York");
+        let zdt = zdt.unwrap();
+        let fields = Capture::record(|| {
+            tracing::info!(zdt = zoned(&zdt));
+        });
+        assert_eq!(
+            fields[0],
+            ("zdt".to_string(), "2024-03-10T03:00:00-04:00".to_string())
+        );
+        // The `Display` impl on `Zoned` includes the time zone annotation.
+        assert_eq!(
+            zdt.to_string(),
+            "2024-03-10T03:00:00-04:00[America/New_York]"
+        );
+
+        // Offsets with non-zero seconds are rounded to the nearest minute,
+        // since RFC 3339 doesn't support them. (This is New York's LMT
+        // offset of -04:56:02.)
+        let zdt = date(1880, 1, 1).at(0, 0, 0, 0).in_tz("America/New_York");
+        let zdt = zdt.unwrap();
+        let fields = Capture::record(|| {
+            tracing::info!(zdt = zoned(&zdt));
+        });
+        assert_eq!(fields[0].1, "1880-01-01T00:00:00-04:56");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    error::{err, ErrorContext},
+    error::{parse_err, ErrorContext},
     fmt::Parsed,
     util::{escape, parse, rangeint::RFrom, t},
     Error, SignedDuration, Span, Unit,
@@ -359,7 +359,7 @@ pub(crate) fn parse_temporal_fraction<'i>(
         }
         let digits = mkdigits(input);
         if digits.is_empty() {
-            return Err(err!(
+            return Err(parse_err!(
                 "found decimal after seconds component, \
                  but did not find any decimal digits after decimal",
             ));
@@ -368,7 +368,7 @@ pub(crate) fn parse_temporal_fraction<'i>(
         // than 9 ASCII digits. Any sequence of 9 ASCII digits can be parsed
         // into an `i64`.
         let nanoseconds = parse::fraction(digits, 9).map_err(|err| {
-            err!(
+            parse_err!(
                 "failed to parse {digits:?} as fractional component \
                  (up to 9 digits, nanosecond precision): {err}",
                 digits = escape::Bytes(digits),
@@ -380,7 +380,9 @@ pub(crate) fn parse_temporal_fraction<'i>(
         // possible expressible value here is in range.
         let nanoseconds =
             t::SubsecNanosecond::try_new("nanoseconds", nanoseconds).map_err(
-                |err| err!("fractional nanoseconds are not valid: {err}"),
+                |err| {
+                    parse_err!("fractional nanoseconds are not valid: {err}")
+                },
             )?;
         Ok(Parsed { value: Some(nanoseconds), input })
     }
@@ -425,7 +427,7 @@ pub(crate) fn fractional_time_to_span(
             | Unit::Microsecond
     );
     if !allowed {
-        return Err(err!(
+        return Err(parse_err!(
             "fractional {unit} units are not allowed",
             unit = unit.singular(),
         ));
@@ -522,7 +524,7 @@ pub(crate) fn fractional_time_to_span(
     }
     if nanos > 0 {
         span = span.try_nanoseconds_ranged(nanos).with_context(|| {
-            err!(
+            parse_err!(
                 "failed to set nanosecond value {nanos} on span \
                  determined from {value}.{fraction}",
             )
@@ -561,7 +563,7 @@ pub(crate) fn fractional_time_to_duration(
         Unit::Millisecond => fraction / t::NANOS_PER_MICRO,
         Unit::Microsecond => fraction / t::NANOS_PER_MILLI,
         unit => {
-            return Err(err!(
+            return Err(parse_err!(
                 "fractional {unit} units are not allowed",
                 unit = unit.singular(),
             ))
@@ -617,6 +617,23 @@ For more, see the [`fmt::serde`] sub-module. (This requires enabling Jiff's
   Temporal, but it's a mix of the "best" parts of RFC 3339, RFC 9557 and
   ISO 8601. See the [`fmt::temporal`] module for more details on the format
   used.
+* **arbitrary** -
+  When enabled, [`Timestamp`], [`Zoned`], [`Span`], [`civil::Date`],
+  [`civil::Time`] and [`civil::DateTime`] implement the `arbitrary` crate's
+  `Arbitrary` trait. This is useful for fuzzing code that consumes Jiff
+  types, e.g., with `cargo fuzz`. Only valid values are ever generated. For
+  `Zoned`, the time zone is either one of a small set of well known IANA time
+  zones (falling back to UTC if the time zone database is unavailable) or an
+  arbitrary fixed offset.
+* **proptest** -
+  When enabled, the `jiff::proptest` module is available. It provides
+  `proptest` strategies for generating Jiff values that shrink toward
+  readable values (like the Unix epoch or an empty span). This feature
+  implies `std`.
+* **tracing** -
+  When enabled, the `jiff::fmt::tracing` module is available. It provides
+  helpers for recording [`Timestamp`] and [`Zoned`] values as RFC 3339
+  strings in fields of the `tracing` crate.
 * **js** -
   On _only_ the `wasm32-unknown-unknown` and `wasm64-unknown-unknown` targets,
   the `js` feature will add dependencies on `js-sys` and `wasm-bindgen`.
@@ -726,15 +743,17 @@ extern crate std;
 extern crate alloc;
 
 pub use crate::{
-    error::Error,
+    error::{Error, ErrorCategory},
+    recurrence::{Frequency, Recurrence, RecurrenceIter},
     signed_duration::{SignedDuration, SignedDurationRound},
     span::{
         Span, SpanArithmetic, SpanCompare, SpanFieldwise, SpanRelativeTo,
         SpanRound, SpanTotal, ToSpan, Unit,
     },
     timestamp::{
-        Timestamp, TimestampArithmetic, TimestampDifference,
-        TimestampDisplayWithOffset, TimestampRound, TimestampSeries,
+        Inclusivity, Timestamp, TimestampArithmetic, TimestampChunks,
+        TimestampDifference, TimestampDisplayWithOffset, TimestampRound,
+        TimestampSeries,
     },
     util::round::mode::RoundMode,
     zoned::{Zoned, ZonedArithmetic, ZonedDifference, ZonedRound, ZonedWith},
@@ -749,6 +768,9 @@ mod error;
 pub mod fmt;
 #[cfg(feature = "std")]
 mod now;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod recurrence;
 #[doc(hidden)]
 pub mod shared;
 mod signed_duration;
@@ -0,0 +1,286 @@
+/*!
+Strategies for generating Jiff values with [`proptest`](::proptest).
+
+This module is only available when the `proptest` crate feature is enabled.
+It provides [`Strategy`] implementations for Jiff's core types so that
+property tests can be written over code that consumes them.
+
+Every strategy only generates valid values. Moreover, every strategy shrinks
+toward a "zero" value so that failing cases minimize to something readable:
+
+* [`timestamp`] shrinks toward [`Timestamp::UNIX_EPOCH`].
+* [`date`] shrinks toward `1970-01-01`.
+* [`span`] shrinks toward an empty span, one unit at a time.
+* [`zoned`] shrinks its timestamp toward the Unix epoch and its time zone
+toward the first time zone given.
+
+# Example
+
+This shows how to write a property test that checks a (trivial) property for
+timestamps in a handful of time zones:
+
+```
+use jiff::{proptest::zoned, tz::TimeZone, Zoned};
+use proptest::prelude::*;
+
+let zones = [
+    TimeZone::UTC,
+    TimeZone::get("America/New_York")?,
+    TimeZone::get("Australia/Lord_Howe")?,
+];
+proptest!(|(zdt in zoned(zones))| {
+    let got: Zoned = zdt.to_string().parse().unwrap();
+    prop_assert_eq!(zdt, got);
+});
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use alloc::vec::Vec;
+
+use ::proptest::{
+    sample,
+    strategy::{Just, Strategy},
+};
+
+use crate::{
+    civil::Date,
+    tz::TimeZone,
+    util::t::{self, UnixEpochDay, UnixSeconds},
+    Span, Timestamp, Zoned,
+};
+
+/// Returns a strategy that generates any valid [`Timestamp`].
+///
+/// Generated values shrink toward [`Timestamp::UNIX_EPOCH`].
+///
+/// # Example
+///
+/// ```
+/// use jiff::{proptest::timestamp, Timestamp};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(ts in timestamp())| {
+///     prop_assert!(Timestamp::MIN <= ts && ts <= Timestamp::MAX);
+/// });
+/// ```
+pub fn timestamp() -> impl Strategy<Value = Timestamp> {
+    (UnixSeconds::MIN_REPR..=UnixSeconds::MAX_REPR, 0..=999_999_999i32)
+        .prop_filter_map("timestamp is out of range", |(second, nanos)| {
+            Timestamp::new(second, nanos).ok()
+        })
+}
+
+/// Returns a strategy that generates any valid [`Date`].
+///
+/// Generated values shrink toward `1970-01-01`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{civil::Date, proptest::date};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(d in date())| {
+///     prop_assert!(Date::MIN <= d && d <= Date::MAX);
+/// });
+/// ```
+pub fn date() -> impl Strategy<Value = Date> {
+    (UnixEpochDay::MIN_REPR..=UnixEpochDay::MAX_REPR)
+        .prop_filter_map("date is out of range", |day| {
+            UnixEpochDay::new(day).map(Date::from_unix_epoch_day)
+        })
+}
+
+/// Returns a strategy that generates any valid [`Span`].
+///
+/// Every unit is generated independently within its own limits, and all
+/// units share the same sign. This means generated spans are usually quite
+/// big, and so adding them to a datetime will often fail.
+///
+/// Generated values shrink toward an empty span. Each unit is shrunk toward
+/// zero independently of the others, so a failing case will usually minimize
+/// to a span with only a small number of non-zero units.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{proptest::span, Span};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(span in span())| {
+///     let got: Span = span.to_string().parse().unwrap();
+///     prop_assert_eq!(span.signum(), got.signum());
+/// });
+/// ```
+pub fn span() -> impl Strategy<Value = Span> {
+    fn unit(max: impl Into<i64>) -> impl Strategy<Value = i64> {
+        0..=max.into()
+    }
+
+    let calendar = (
+        unit(t::SpanYears::MAX_REPR),
+        unit(t::SpanMonths::MAX_REPR),
+        unit(t::SpanWeeks::MAX_REPR),
+        unit(t::SpanDays::MAX_REPR),
+    );
+    let time = (
+        unit(t::SpanHours::MAX_REPR),
+        unit(t::SpanMinutes::MAX_REPR),
+        unit(t::SpanSeconds::MAX_REPR),
+        unit(t::SpanMilliseconds::MAX_REPR),
+        unit(t::SpanMicroseconds::MAX_REPR),
+        unit(t::SpanNanoseconds::MAX_REPR),
+    );
+    (::proptest::bool::ANY, calendar, time).prop_filter_map(
+        "span is out of range",
+        |(negative, (years, months, weeks, days), time)| {
+            let (hours, minutes, seconds, millis, micros, nanos) = time;
+            let span = Span::new()
+                .try_years(years)
+                .and_then(|span| span.try_months(months))
+                .and_then(|span| span.try_weeks(weeks))
+                .and_then(|span| span.try_days(days))
+                .and_then(|span| span.try_hours(hours))
+                .and_then(|span| span.try_minutes(minutes))
+                .and_then(|span| span.try_seconds(seconds))
+                .and_then(|span| span.try_milliseconds(millis))
+                .and_then(|span| span.try_microseconds(micros))
+                .and_then(|span| span.try_nanoseconds(nanos))
+                .ok()?;
+            Some(if negative { span.negate() } else { span })
+        },
+    )
+}
+
+/// Returns a strategy that generates a [`Zoned`] from any valid
+/// [`Timestamp`] in one of the time zones given.
+///
+/// Generated values shrink toward the Unix epoch and toward the first time
+/// zone given.
+///
+/// If no time zones are given, then every generated value is in UTC.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{proptest::zoned, tz::{self, TimeZone}};
+/// use proptest::prelude::*;
+///
+/// let zones = [TimeZone::UTC, TimeZone::fixed(tz::offset(-5))];
+/// proptest!(|(zdt in zoned(zones))| {
+///     let offset = zdt.offset();
+///     prop_assert!(offset == tz::offset(0) || offset == tz::offset(-5));
+/// });
+/// ```
+pub fn zoned(
+    zones: impl IntoIterator<Item = TimeZone>,
+) -> impl Strategy<Value = Zoned> {
+    let zones: Vec<TimeZone> = zones.into_iter().collect();
+    let zones = if zones.is_empty() {
+        Just(TimeZone::UTC).boxed()
+    } else {
+        sample::select(zones).boxed()
+    };
+    (timestamp(), zones).prop_map(|(ts, tz)| Zoned::new(ts, tz))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use ::proptest::{
+        prop_assert_eq, proptest,
+        test_runner::{TestError, TestRunner},
+    };
+
+    use crate::{civil, SignedDuration};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn timestamp_roundtrip(ts in timestamp()) {
+            let got: Timestamp = ts.to_string().parse().unwrap();
+            prop_assert_eq!(ts, got);
+        }
+
+        #[test]
+        fn date_roundtrip(d in date()) {
+            let got: Date = d.to_string().parse().unwrap();
+            prop_assert_eq!(d, got);
+        }
+
+        #[test]
+        fn span_roundtrip(span in span()) {
+            // Sub-second units are printed as fractional seconds, so we
+            // compare the printed forms instead of the spans themselves.
+            let printed = span.to_string();
+            let got: Span = printed.parse().unwrap();
+            prop_assert_eq!(printed, got.to_string());
+        }
+
+        #[test]
+        fn zoned_roundtrip(zdt in zoned([
+            TimeZone::UTC,
+            TimeZone::fixed(crate::tz::offset(-5)),
+        ])) {
+            // Parsing the `UTC` time zone annotation requires a time zone
+            // database.
+            if crate::tz::db().is_definitively_empty() {
+                return Ok(());
+            }
+            let got: Zoned = zdt.to_string().parse().unwrap();
+            prop_assert_eq!(zdt, got);
+        }
+    }
+
+    /// Runs the given failing property and returns the minimal failing
+    /// input found after shrinking.
+    fn minimize<S: Strategy>(
+        strategy: S,
+        test: impl Fn(S::Value) -> bool,
+    ) -> S::Value {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&strategy, |value| {
+            ::proptest::prop_assert!(test(value));
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("expected property to fail, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn shrink_timestamp_toward_epoch() {
+        let limit = Timestamp::new(1_000, 0).unwrap();
+        let got = minimize(timestamp(), |ts| ts < limit);
+        assert_eq!(got, limit);
+    }
+
+    #[test]
+    fn shrink_date_toward_epoch() {
+        let epoch = civil::date(1970, 1, 1);
+        let got = minimize(date(), |d| d < epoch || d.year() < 2000);
+        assert_eq!(got, civil::date(2000, 1, 1));
+    }
+
+    #[test]
+    fn shrink_span_toward_zero() {
+        let got = minimize(span(), |span| span.get_hours() == 0);
+        assert_eq!(got.fieldwise(), Span::new().hours(1));
+    }
+
+    #[test]
+    fn shrink_zoned_toward_first_zone() {
+        let zones = [TimeZone::UTC, TimeZone::fixed(crate::tz::offset(-5))];
+        let got = minimize(zoned(zones), |zdt| {
+            zdt.timestamp().duration_since(Timestamp::UNIX_EPOCH)
+                < SignedDuration::from_hours(1)
+        });
+        assert_eq!(got.time_zone(), &TimeZone::UTC);
+        assert_eq!(got.timestamp(), Timestamp::new(3_600, 0).unwrap());
+    }
+}
@@ -0,0 +1,334 @@
+use core::num::NonZeroU32;
+
+use crate::{Span, Timestamp, Zoned};
+
+/// How often a [`Recurrence`] repeats.
+///
+/// The frequency is combined with an interval (which defaults to `1`) to
+/// determine the span of time between each occurrence. For example, a
+/// frequency of [`Frequency::Weekly`] with an interval of `2` repeats every
+/// other week.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Frequency {
+    /// Repeats every day.
+    Daily,
+    /// Repeats every week.
+    Weekly,
+    /// Repeats every month.
+    Monthly,
+    /// Repeats every year.
+    Yearly,
+}
+
+impl Frequency {
+    /// Returns a span corresponding to `count` repetitions of this frequency.
+    fn span(self, count: i64) -> Option<Span> {
+        let span = match self {
+            Frequency::Daily => Span::new().try_days(count),
+            Frequency::Weekly => Span::new().try_weeks(count),
+            Frequency::Monthly => Span::new().try_months(count),
+            Frequency::Yearly => Span::new().try_years(count),
+        };
+        span.ok()
+    }
+}
+
+/// A simple rule for generating recurring zoned datetimes.
+///
+/// A recurrence starts at a [`Zoned`] value and repeats at a fixed
+/// [`Frequency`] and interval. It may optionally be limited to a number
+/// of occurrences with [`Recurrence::count`], or to occurrences at or
+/// before a particular instant with [`Recurrence::until`]. Without either
+/// limit, the recurrence only stops once the next occurrence would overflow
+/// Jiff's supported range of datetimes.
+///
+/// This is intentionally much simpler than an iCalendar `RRULE`. It only
+/// supports repeating a single starting datetime at a fixed frequency.
+///
+/// # Time zone transitions
+///
+/// Each occurrence is computed by adding a multiple of the frequency to the
+/// starting zoned datetime, using the same rules as [`Zoned::checked_add`].
+/// This means occurrences keep the same civil (wall clock) time as the start,
+/// even across time zone transitions like daylight saving time. That is, a
+/// daily recurrence at 09:00 stays at 09:00 local time, and does not drift
+/// by an hour when the offset changes.
+///
+/// When the civil time of an occurrence falls into a gap (i.e., it doesn't
+/// exist on the clocks in that time zone), then the time is shifted forward
+/// by the length of the gap. When it is ambiguous because it falls into a
+/// fold, then the earlier of the two possible instants is used.
+///
+/// Similarly, because each occurrence is computed from the start and not
+/// from the previous occurrence, month-end clamping does not accumulate. A
+/// monthly recurrence starting on January 31 occurs on February 29 (or 28)
+/// and then again on March 31.
+///
+/// # Example
+///
+/// This shows a daily recurrence at 09:00 in New York across the start of
+/// daylight saving time in 2024:
+///
+/// ```
+/// use jiff::{civil::date, Frequency, Recurrence};
+///
+/// let start = date(2024, 3, 9).at(9, 0, 0, 0).in_tz("America/New_York")?;
+/// let got: Vec<String> = Recurrence::new(Frequency::Daily, start)
+///     .count(3)
+///     .iter()
+///     .map(|zdt| zdt.to_string())
+///     .collect();
+/// assert_eq!(got, vec![
+///     "2024-03-09T09:00:00-05:00[America/New_York]",
+///     "2024-03-10T09:00:00-04:00[America/New_York]",
+///     "2024-03-11T09:00:00-04:00[America/New_York]",
+/// ]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Example: every other week until a deadline
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use jiff::{civil::date, Frequency, Recurrence};
+///
+/// let start = date(2024, 1, 5).at(17, 30, 0, 0).in_tz("Europe/London")?;
+/// let until = date(2024, 3, 1).at(17, 30, 0, 0).in_tz("Europe/London")?;
+/// let got: Vec<String> = Recurrence::new(Frequency::Weekly, start)
+///     .interval(NonZeroU32::new(2).unwrap())
+///     .until(until.timestamp())
+///     .iter()
+///     .map(|zdt| zdt.date().to_string())
+///     .collect();
+/// assert_eq!(got, vec![
+///     "2024-01-05", "2024-01-19", "2024-02-02", "2024-02-16", "2024-03-01",
+/// ]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Recurrence {
+    start: Zoned,
+    frequency: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<Timestamp>,
+}
+
+impl Recurrence {
+    /// Create a new recurrence starting at the given zoned datetime and
+    /// repeating at the given frequency.
+    ///
+    /// The starting datetime is always the first occurrence. The interval
+    /// defaults to `1`, and there is no limit on the number of occurrences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, Frequency, Recurrence};
+    ///
+    /// let start = date(2024, 1, 31).at(12, 0, 0, 0).in_tz("Asia/Tokyo")?;
+    /// let got: Vec<String> = Recurrence::new(Frequency::Monthly, start)
+    ///     .iter()
+    ///     .take(3)
+    ///     .map(|zdt| zdt.date().to_string())
+    ///     .collect();
+    /// assert_eq!(got, vec!["2024-01-31", "2024-02-29", "2024-03-31"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn new(frequency: Frequency, start: Zoned) -> Recurrence {
+        Recurrence { start, frequency, interval: 1, count: None, until: None }
+    }
+
+    /// Set the number of frequency units between each occurrence.
+    ///
+    /// For example, a [`Frequency::Daily`] recurrence with an interval of
+    /// `3` occurs every third day.
+    ///
+    /// This defaults to `1`. An interval of zero would never advance past
+    /// the starting datetime, so the interval given must be non-zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    ///
+    /// use jiff::{civil::date, Frequency, Recurrence};
+    ///
+    /// let start = date(2024, 2, 29).at(0, 0, 0, 0).in_tz("UTC")?;
+    /// let got: Vec<String> = Recurrence::new(Frequency::Yearly, start)
+    ///     .interval(NonZeroU32::new(4).unwrap())
+    ///     .count(3)
+    ///     .iter()
+    ///     .map(|zdt| zdt.date().to_string())
+    ///     .collect();
+    /// assert_eq!(got, vec!["2024-02-29", "2028-02-29", "2032-02-29"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn interval(self, interval: NonZeroU32) -> Recurrence {
+        Recurrence { interval: i64::from(interval.get()), ..self }
+    }
+
+    /// Limit this recurrence to at most the given number of occurrences.
+    ///
+    /// The starting datetime counts as an occurrence. So a count of `1`
+    /// yields only the starting datetime, and a count of `0` yields
+    /// nothing.
+    ///
+    /// This may be combined with [`Recurrence::until`], in which case
+    /// iteration stops as soon as either limit is reached.
+    ///
+    /// By default, there is no limit.
+    #[inline]
+    pub fn count(self, count: usize) -> Recurrence {
+        Recurrence { count: Some(count), ..self }
+    }
+
+    /// Limit this recurrence to occurrences at or before the given instant.
+    ///
+    /// The limit is inclusive. That is, an occurrence equal to `until` is
+    /// yielded.
+    ///
+    /// This may be combined with [`Recurrence::count`], in which case
+    /// iteration stops as soon as either limit is reached.
+    ///
+    /// By default, there is no limit.
+    #[inline]
+    pub fn until(self, until: Timestamp) -> Recurrence {
+        Recurrence { until: Some(until), ..self }
+    }
+
+    /// Returns an iterator over the occurrences of this recurrence.
+    ///
+    /// The iterator is exhausted when the configured count or until limit
+    /// is reached, or when the next occurrence would overflow Jiff's
+    /// supported range of datetimes.
+    #[inline]
+    pub fn iter(&self) -> RecurrenceIter {
+        RecurrenceIter { recurrence: self.clone(), step: 0 }
+    }
+}
+
+impl IntoIterator for &Recurrence {
+    type Item = Zoned;
+    type IntoIter = RecurrenceIter;
+
+    #[inline]
+    fn into_iter(self) -> RecurrenceIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the occurrences of a [`Recurrence`].
+///
+/// This iterator is created by [`Recurrence::iter`].
+#[derive(Clone, Debug)]
+pub struct RecurrenceIter {
+    recurrence: Recurrence,
+    step: i64,
+}
+
+impl Iterator for RecurrenceIter {
+    type Item = Zoned;
+
+    #[inline]
+    fn next(&mut self) -> Option<Zoned> {
+        let r = &self.recurrence;
+        if let Some(count) = r.count {
+            if usize::try_from(self.step).map_or(true, |step| step >= count) {
+                return None;
+            }
+        }
+        let span = r.frequency.span(self.step.checked_mul(r.interval)?)?;
+        let zdt = r.start.checked_add(span).ok()?;
+        if r.until.is_some_and(|until| zdt.timestamp() > until) {
+            return None;
+        }
+        self.step = self.step.checked_add(1)?;
+        Some(zdt)
+    }
+}
+
+impl core::iter::FusedIterator for RecurrenceIter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{civil::date, tz::TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn daily_across_dst() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let start =
+            date(2024, 3, 5).at(9, 0, 0, 0).in_tz("America/New_York").unwrap();
+        let got: alloc::vec::Vec<Zoned> =
+            Recurrence::new(Frequency::Daily, start)
+                .count(10)
+                .iter()
+                .collect();
+        assert_eq!(got.len(), 10);
+        for (i, zdt) in got.iter().enumerate() {
+            let day = i8::try_from(5 + i).unwrap();
+            assert_eq!(zdt.datetime(), date(2024, 3, day).at(9, 0, 0, 0));
+            assert_eq!(zdt.time_zone().iana_name(), Some("America/New_York"));
+        }
+        // The offset changes on the day daylight saving time begins.
+        assert_eq!(got[4].offset(), crate::tz::offset(-5));
+        assert_eq!(got[5].offset(), crate::tz::offset(-4));
+
+        // And the same thing across the end of DST, where the interval
+        // between occurrences is 25 hours.
+        let start = date(2024, 11, 1)
+            .at(9, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+        let got: alloc::vec::Vec<Zoned> =
+            Recurrence::new(Frequency::Daily, start)
+                .count(10)
+                .iter()
+                .collect();
+        for (i, zdt) in got.iter().enumerate() {
+            let day = i8::try_from(1 + i).unwrap();
+            assert_eq!(zdt.datetime(), date(2024, 11, day).at(9, 0, 0, 0));
+        }
+        assert_eq!(
+            got[2].timestamp().duration_since(got[1].timestamp()),
+            crate::SignedDuration::from_hours(25),
+        );
+    }
+
+    #[test]
+    fn count_and_until() {
+        let start =
+            date(2024, 1, 1).at(0, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+
+        let r = Recurrence::new(Frequency::Daily, start.clone());
+        assert_eq!(r.clone().count(0).iter().count(), 0);
+        assert_eq!(r.clone().count(1).iter().count(), 1);
+        assert_eq!(r.clone().count(7).iter().count(), 7);
+
+        let until =
+            date(2024, 1, 10).at(0, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+        assert_eq!(r.clone().until(until.timestamp()).iter().count(), 10);
+        assert_eq!(
+            r.clone().until(until.timestamp()).count(3).iter().count(),
+            3,
+        );
+        let before = start.timestamp() - crate::SignedDuration::from_secs(1);
+        assert_eq!(r.clone().until(before).iter().count(), 0);
+
+        let last = Recurrence::new(Frequency::Yearly, start).iter().last();
+        assert_eq!(last.unwrap().date(), date(9999, 1, 1));
+    }
+}
@@ -111,6 +111,122 @@ impl TzifOwned {
         Ok(tzif)
     }
 
+    // only-jiff-start
+    /// Builds TZif data from an explicit sequence of transitions.
+    ///
+    /// Each transition is a Unix timestamp in seconds, along with the offset
+    /// (in seconds), abbreviation and DST status that apply at and after
+    /// that timestamp. Transitions must be in strictly ascending order.
+    ///
+    /// Before the first transition, the offset is zero with the abbreviation
+    /// `-00`, unless the first transition is at the minimum timestamp
+    /// supported by Jiff, in which case it applies to all prior times.
+    pub(crate) fn from_transitions<'a>(
+        name: Option<String>,
+        transitions: impl IntoIterator<Item = (i64, i32, &'a str, bool)>,
+    ) -> Result<TzifOwned, Error> {
+        let mut tzif = TzifOwned {
+            fixed: TzifFixed {
+                name,
+                // There is no TZif data, so there's no version either.
+                version: 0,
+                // filled in later
+                checksum: 0,
+                designations: String::new(),
+                posix_tz: None,
+            },
+            types: vec![],
+            transitions: TzifTransitions {
+                timestamps: vec![],
+                civil_starts: vec![],
+                civil_ends: vec![],
+                infos: vec![],
+            },
+        };
+        // In lieu of the raw TZif data, the checksum is computed over a
+        // simple encoding of the transitions given. This ensures two time
+        // zones built from the same transitions compare equal, and otherwise
+        // compare unequal.
+        let mut raw: alloc::vec::Vec<u8> = vec![];
+        let mut prev: Option<i64> = None;
+        for (timestamp, offset, abbrev, is_dst) in transitions {
+            if let Some(prev) = prev {
+                if timestamp <= prev {
+                    return Err(err!(
+                        "found transition at Unix timestamp {timestamp} \
+                         that does not come after the previous transition \
+                         at Unix timestamp {prev}, but transitions must be \
+                         in strictly ascending order",
+                    ));
+                }
+            }
+            if !(TIMESTAMP_MIN..=TIMESTAMP_MAX).contains(&timestamp) {
+                return Err(err!(
+                    "found transition at out-of-bounds Unix timestamp \
+                     {timestamp}",
+                ));
+            }
+            if !(OFFSET_MIN..=OFFSET_MAX).contains(&offset) {
+                return Err(err!(
+                    "found transition with out-of-bounds offset: {offset}"
+                ));
+            }
+            if abbrev.is_empty() || abbrev.contains('\x00') {
+                return Err(err!(
+                    "found transition with invalid abbreviation {abbrev:?}: \
+                     abbreviations must be non-empty and not contain NUL",
+                ));
+            }
+            let ioff = IOffset { second: offset };
+            let type_index = tzif
+                .find_or_create_local_time_type(ioff, abbrev, is_dst)
+                .ok_or_else(|| {
+                    err!(
+                        "too many distinct offsets and abbreviations \
+                         in transitions",
+                    )
+                })?;
+            if prev.is_none() && timestamp != TIMESTAMP_MIN {
+                // Like the TZif parser, we add a dummy transition at the
+                // minimum timestamp so that there is always an offset to
+                // use before the first transition.
+                let type_index = tzif
+                    .find_or_create_local_time_type(IOffset::UTC, "-00", false)
+                    .ok_or_else(|| {
+                        err!(
+                            "too many distinct offsets and abbreviations \
+                             in transitions",
+                        )
+                    })?;
+                tzif.transitions
+                    .add_with_type_index(TIMESTAMP_MIN, type_index);
+            }
+            tzif.transitions.add_with_type_index(timestamp, type_index);
+            prev = Some(timestamp);
+
+            raw.extend_from_slice(&timestamp.to_be_bytes());
+            raw.extend_from_slice(&offset.to_be_bytes());
+            raw.push(u8::from(is_dst));
+            raw.extend_from_slice(abbrev.as_bytes());
+            raw.push(0);
+        }
+        if prev.is_none() {
+            return Err(err!("at least one transition is required"));
+        }
+        tzif.add_civil_datetimes_to_transitions();
+        tzif.fixed.checksum = super::crc32::sum(&raw);
+
+        tzif.fixed.designations.shrink_to_fit();
+        tzif.types.shrink_to_fit();
+        tzif.transitions.timestamps.shrink_to_fit();
+        tzif.transitions.civil_starts.shrink_to_fit();
+        tzif.transitions.civil_ends.shrink_to_fit();
+        tzif.transitions.infos.shrink_to_fit();
+
+        Ok(tzif)
+    }
+    // only-jiff-end
+
     fn parse32<'b>(
         name: Option<String>,
         header32: Header,
@@ -209,12 +325,27 @@ impl TzifOwned {
         // trigger for any times before the first transition found in the TZif
         // data.
         self.transitions.add_with_type_index(TIMESTAMP_MIN, 0);
+        let mut prev: Option<i64> = None;
         while let Some(chunk) = it.next() {
             let mut timestamp = if header.is_32bit() {
                 i64::from(from_be_bytes_i32(chunk))
             } else {
                 from_be_bytes_i64(chunk)
             };
+            // RFC 8536 requires transition times to be sorted in strictly
+            // ascending order. Lookups rely on this (via binary search), so
+            // if it isn't true, we'd silently return wrong offsets.
+            if let Some(prev) = prev {
+                if timestamp <= prev {
+                    return Err(err!(
+                        "found transition at Unix timestamp {timestamp} \
+                         that does not come after the previous transition \
+                         at Unix timestamp {prev}, but transitions must be \
+                         in strictly ascending order",
+                    ));
+                }
+            }
+            prev = Some(timestamp);
             if !(TIMESTAMP_MIN <= timestamp && timestamp <= TIMESTAMP_MAX) {
                 // We really shouldn't error here just because the Unix
                 // timestamp is outside what Jiff supports. Since what Jiff
@@ -257,7 +388,7 @@ impl TzifOwned {
         for (transition_index, &type_index) in (1..).zip(bytes) {
             if usize::from(type_index) >= header.tzh_typecnt {
                 return Err(err!(
-                    "found transition type index {type_index},
+                    "found transition type index {type_index}, \
                      but there are only {} local time types",
                     header.tzh_typecnt,
                 ));
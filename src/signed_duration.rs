@@ -2,7 +2,7 @@ use core::time::Duration;
 
 use crate::{
     civil::{Date, DateTime, Time},
-    error::{err, ErrorContext},
+    error::{err, parse_err, ErrorContext},
     fmt::{friendly, temporal},
     tz::Offset,
     util::{escape, rangeint::TryRFrom, t},
@@ -2034,6 +2034,156 @@ impl SignedDuration {
         let options: SignedDurationRound = options.into();
         options.round(self)
     }
+
+    /// Returns this duration formatted as a "clock" style string, i.e.,
+    /// `HH:MM:SS.fff`.
+    ///
+    /// This is the format commonly used by stopwatches, timers and media
+    /// players. It is distinct from the ISO 8601 format used by this type's
+    /// `Display` implementation.
+    ///
+    /// The hours are always written with at least two digits, but are never
+    /// clamped or wrapped. So a duration of 100 hours is written as `100:...`.
+    /// The fractional seconds are always written with exactly three digits
+    /// (milliseconds). Any precision beyond milliseconds is truncated. A
+    /// leading `-` is written for negative durations, unless the duration is
+    /// zero after truncation.
+    ///
+    /// The string returned can be parsed back into a `SignedDuration` via
+    /// [`SignedDuration::parse_hms`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let dur = SignedDuration::new(5_025, 678_900_000);
+    /// assert_eq!(dur.to_hms_string(), "01:23:45.678");
+    /// assert_eq!((-dur).to_hms_string(), "-01:23:45.678");
+    ///
+    /// let dur = SignedDuration::from_hours(123);
+    /// assert_eq!(dur.to_hms_string(), "123:00:00.000");
+    ///
+    /// let dur = SignedDuration::from_micros(-999);
+    /// assert_eq!(dur.to_hms_string(), "00:00:00.000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hms_string(&self) -> alloc::string::String {
+        let secs = self.as_secs().unsigned_abs();
+        let millis = self.subsec_millis().unsigned_abs();
+        let (hours, minutes, seconds) =
+            (secs / 3600, secs / 60 % 60, secs % 60);
+        let sign = if self.is_negative() && (secs != 0 || millis != 0) {
+            "-"
+        } else {
+            ""
+        };
+        alloc::format!(
+            "{sign}{hours:02}:{minutes:02}:{seconds:02}.{millis:03}"
+        )
+    }
+
+    /// Parses a "clock" style duration string, i.e., `HH:MM:SS.fff`.
+    ///
+    /// This is the format commonly used by stopwatches, timers and media
+    /// players, and is what [`SignedDuration::to_hms_string`] produces.
+    ///
+    /// The format accepted is an optional sign (`+` or `-`) followed by one
+    /// or more digits for hours, then a `:` and exactly two digits for
+    /// minutes, then a `:` and exactly two digits for seconds. The seconds
+    /// may be followed by a `.` and between one and nine digits of fractional
+    /// seconds. Minutes and seconds must each be less than `60`, but there is
+    /// no limit on hours other than the range of a `SignedDuration`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input doesn't match the format described
+    /// above, or if the duration it describes overflows a `SignedDuration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::SignedDuration;
+    ///
+    /// let dur = SignedDuration::parse_hms("01:23:45.678")?;
+    /// assert_eq!(dur, SignedDuration::new(5_025, 678_000_000));
+    ///
+    /// let dur = SignedDuration::parse_hms("-123:00:00")?;
+    /// assert_eq!(dur, SignedDuration::from_hours(-123));
+    ///
+    /// assert!(SignedDuration::parse_hms("01:60:00").is_err());
+    /// assert!(SignedDuration::parse_hms("1:2:3").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_hms<I: AsRef<[u8]>>(
+        input: I,
+    ) -> Result<SignedDuration, Error> {
+        let input = input.as_ref();
+        parse_hms(input).with_context(|| {
+            err!(
+                "failed to parse {input:?} as an `HH:MM:SS` duration",
+                input = escape::Bytes(input),
+            )
+        })
+    }
+}
+
+/// Parses a duration in the `[+-]HH:MM:SS[.fffffffff]` format.
+///
+/// This is used to implement `SignedDuration::parse_hms`, which adds context
+/// to any error returned.
+fn parse_hms(input: &[u8]) -> Result<SignedDuration, Error> {
+    /// Parses a two digit minute or second component that must be less
+    /// than 60.
+    fn sexagesimal(what: &'static str, digits: &[u8]) -> Result<i64, Error> {
+        if digits.len() != 2 {
+            return Err(parse_err!(
+                "expected exactly two digits for {what}, but found {:?}",
+                escape::Bytes(digits),
+            ));
+        }
+        let n = crate::util::parse::i64(digits)?;
+        if n >= 60 {
+            return Err(Error::range(what, n, 0, 59));
+        }
+        Ok(n)
+    }
+
+    let (negative, input) = match input.split_first() {
+        Some((&b'-', rest)) => (true, rest),
+        Some((&b'+', rest)) => (false, rest),
+        _ => (false, input),
+    };
+    let mut parts = input.splitn(3, |&b| b == b':');
+    let (Some(hours), Some(minutes), Some(seconds)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(parse_err!("expected two `:` separators"));
+    };
+    let (seconds, fraction) = match seconds.iter().position(|&b| b == b'.') {
+        None => (seconds, None),
+        Some(i) => (&seconds[..i], Some(&seconds[i + 1..])),
+    };
+
+    let hours = crate::util::parse::i64(hours)?;
+    let minutes = sexagesimal("minutes", minutes)?;
+    let seconds = sexagesimal("seconds", seconds)?;
+    let nanos = match fraction {
+        None => 0,
+        Some(fraction) => crate::util::parse::fraction(fraction, 9)?,
+    };
+    // We accumulate toward the sign of the duration instead of negating
+    // at the end. Otherwise, the magnitude of `SignedDuration::MIN` would
+    // overflow an `i64`.
+    let sign = if negative { -1 } else { 1 };
+    let secs = hours
+        .checked_mul(sign * t::SECONDS_PER_HOUR.value())
+        .and_then(|secs| secs.checked_add(sign * (minutes * 60 + seconds)))
+        .ok_or_else(|| err!("hours {hours} overflowed `SignedDuration`"))?;
+    // OK because `parse::fraction` guarantees at most 9 digits.
+    let nanos = i32::try_from(nanos).unwrap();
+    Ok(SignedDuration::new(secs, (sign as i32) * nanos))
 }
 
 impl core::fmt::Display for SignedDuration {
@@ -2643,6 +2793,30 @@ mod tests {
         assert_eq!(None, add((i64::MIN, -1), (0, -999_999_999)));
     }
 
+    #[test]
+    fn unsigned_duration_conversions() {
+        let ud = Duration::new(86_400 + 5, 123_456_789);
+        let sd = SignedDuration::try_from(ud).unwrap();
+        assert_eq!(sd, SignedDuration::new(86_400 + 5, 123_456_789));
+        assert_eq!(Duration::try_from(sd).unwrap(), ud);
+
+        let sd = SignedDuration::try_from(Duration::ZERO).unwrap();
+        assert_eq!(sd, SignedDuration::ZERO);
+        assert_eq!(Duration::try_from(sd).unwrap(), Duration::ZERO);
+
+        let sd = SignedDuration::try_from(Duration::new(i64::MAX as u64, 0));
+        assert_eq!(sd.unwrap(), SignedDuration::new(i64::MAX, 0));
+        assert!(SignedDuration::try_from(Duration::MAX).is_err());
+
+        let err = Duration::try_from(SignedDuration::new(-5, 0)).unwrap_err();
+        insta::assert_snapshot!(
+            err,
+            @"cannot convert negative duration `5s ago` to unsigned `std::time::Duration`",
+        );
+        let sd = SignedDuration::new(0, -1);
+        assert!(Duration::try_from(sd).is_err());
+    }
+
     /// # `serde` deserializer compatibility test
     ///
     /// Serde YAML used to be unable to deserialize `jiff` types,
@@ -2815,4 +2989,67 @@ mod tests {
         .iter()
         .sum::<SignedDuration>();
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn hms_print() {
+        let sub_second = SignedDuration::from_millis(250);
+        assert_eq!(sub_second.to_hms_string(), "00:00:00.250");
+        let multi_hour = SignedDuration::new(5_025, 678_999_999);
+        assert_eq!(multi_hour.to_hms_string(), "01:23:45.678");
+        let long = SignedDuration::from_hours(1_000);
+        assert_eq!(long.to_hms_string(), "1000:00:00.000");
+        let negative = -SignedDuration::new(3_723, 4_000_000);
+        assert_eq!(negative.to_hms_string(), "-01:02:03.004");
+
+        assert_eq!(SignedDuration::ZERO.to_hms_string(), "00:00:00.000");
+        let tiny = SignedDuration::from_nanos(-1);
+        assert_eq!(tiny.to_hms_string(), "00:00:00.000");
+        assert_eq!(
+            SignedDuration::MIN.to_hms_string(),
+            "-2562047788015215:30:08.999",
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn hms_parse() {
+        let p = |s: &str| SignedDuration::parse_hms(s).unwrap();
+
+        assert_eq!(p("00:00:00.25"), SignedDuration::from_millis(250));
+        assert_eq!(p("+01:23:45"), SignedDuration::from_secs(5_025));
+        assert_eq!(p("1000:00:00"), SignedDuration::from_hours(1_000));
+        assert_eq!(p("-1:02:03.000000004"), -SignedDuration::new(3_723, 4));
+        assert_eq!(p("-00:00:00"), SignedDuration::ZERO);
+
+        for dur in [
+            SignedDuration::ZERO,
+            SignedDuration::from_millis(250),
+            SignedDuration::new(5_025, 678_000_000),
+            -SignedDuration::new(3_723, 4_000_000),
+            SignedDuration::from_hours(-1_000),
+        ] {
+            assert_eq!(p(&dur.to_hms_string()), dur);
+        }
+        // Precision beyond milliseconds is lost when printing.
+        for dur in [SignedDuration::MIN, SignedDuration::MAX] {
+            let truncated = SignedDuration::new(
+                dur.as_secs(),
+                dur.subsec_millis() * 1_000_000,
+            );
+            assert_eq!(p(&dur.to_hms_string()), truncated);
+        }
+
+        let p = |s: &str| SignedDuration::parse_hms(s).unwrap_err();
+        insta::assert_snapshot!(p("01:60:00"), @"failed to parse \"01:60:00\" as an `HH:MM:SS` duration: parameter 'minutes' with value 60 is not in the required range of 0..=59");
+        insta::assert_snapshot!(p("01:2:03"), @"failed to parse \"01:2:03\" as an `HH:MM:SS` duration: expected exactly two digits for minutes, but found \"2\"");
+        insta::assert_snapshot!(p("01:02"), @"failed to parse \"01:02\" as an `HH:MM:SS` duration: expected two `:` separators");
+        insta::assert_snapshot!(p("01:02:03."), @"failed to parse \"01:02:03.\" as an `HH:MM:SS` duration: invalid fraction, no digits found");
+        insta::assert_snapshot!(p("x1:02:03"), @"failed to parse \"x1:02:03\" as an `HH:MM:SS` duration: invalid digit, expected 0-9 but got x");
+        insta::assert_snapshot!(p("9999999999999999:00:00"), @"failed to parse \"9999999999999999:00:00\" as an `HH:MM:SS` duration: hours 9999999999999999 overflowed `SignedDuration`");
+
+        for input in ["01:2:03", "01:02", "01:02:03.", "x1:02:03"] {
+            assert_eq!(p(input).category(), crate::ErrorCategory::Parse);
+        }
+    }
 }
@@ -87,6 +87,21 @@ pub(crate) use span_eq;
 /// assert_eq!(span.to_string(), "P5DT8H1M");
 /// ```
 ///
+/// These mutator methods panic when a value is out of range for its unit.
+/// When building a span from values that may be out of range, such as user
+/// input, use the fallible `try_` variants instead. They return an error
+/// naming the unit whose value is out of range:
+///
+/// ```
+/// use jiff::Span;
+///
+/// let span = Span::new().try_years(1)?.try_months(2)?.try_days(3)?;
+/// assert_eq!(span.to_string(), "P1Y2M3D");
+/// assert!(Span::new().try_years(1)?.try_months(1_000_000).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// But Jiff provides a [`ToSpan`] trait that defines extension methods on
 /// primitive signed integers to make span creation terser:
 ///
@@ -285,6 +300,31 @@ pub(crate) use span_eq;
 /// For more details, see the [`fmt::temporal`](temporal) and
 /// [`fmt::friendly`](friendly) modules.
 ///
+/// When the `serde` crate feature is enabled, `Span` also implements Serde's
+/// `Serialize` and `Deserialize` traits. Serialization uses the same ISO 8601
+/// format as the `Display` implementation, and deserialization accepts the
+/// same formats as parsing:
+///
+/// ```
+/// use jiff::{Span, ToSpan};
+///
+/// #[derive(Debug, serde::Deserialize, serde::Serialize)]
+/// struct Record {
+///     retention: Span,
+/// }
+///
+/// let record = Record { retention: 1.day().hours(2) };
+/// let json = serde_json::to_string(&record)?;
+/// assert_eq!(json, r#"{"retention":"P1DT2H"}"#);
+/// let got: Record = serde_json::from_str(&json)?;
+/// assert_eq!(got.retention, 1.day().hours(2).fieldwise());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// To serialize a `Span` in the "friendly" format instead, see the
+/// [`fmt::serde`](crate::fmt::serde) module.
+///
 /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
 ///
 /// # Comparisons
@@ -1075,6 +1115,37 @@ impl Span {
 }
 
 /// Routines for accessing the individual units in a `Span`.
+///
+/// Each unit has a corresponding `get_` accessor, since the unprefixed names
+/// (like [`Span::years`]) are used for setting units. Together with
+/// [`Span::signum`], [`Span::is_zero`], [`Span::is_positive`] and
+/// [`Span::is_negative`], these accessors permit fully destructuring a `Span`
+/// without any arithmetic. This is useful for implementing custom formatting
+/// of spans.
+///
+/// Note that every unit in a span has the same sign. So when a span is
+/// negative, every non-zero unit returned by these accessors is negative.
+///
+/// # Example
+///
+/// ```
+/// use jiff::Span;
+///
+/// let span: Span = "-P1Y2M3W4DT5H6M7.008009010S".parse()?;
+/// assert!(span.is_negative());
+/// assert_eq!(span.get_years(), -1);
+/// assert_eq!(span.get_months(), -2);
+/// assert_eq!(span.get_weeks(), -3);
+/// assert_eq!(span.get_days(), -4);
+/// assert_eq!(span.get_hours(), -5);
+/// assert_eq!(span.get_minutes(), -6);
+/// assert_eq!(span.get_seconds(), -7);
+/// assert_eq!(span.get_milliseconds(), -8);
+/// assert_eq!(span.get_microseconds(), -9);
+/// assert_eq!(span.get_nanoseconds(), -10);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 impl Span {
     /// Returns the number of year units in this span.
     ///
@@ -1297,6 +1368,135 @@ impl Span {
     pub fn get_nanoseconds(&self) -> i64 {
         self.get_nanoseconds_ranged().get()
     }
+
+    /// Returns every non-zero unit in this span along with its value, in
+    /// descending order of unit.
+    ///
+    /// This is a convenient way to iterate over the units of a span without
+    /// calling each of the `get_*` accessors individually. For example, when
+    /// rendering a span in a custom format. Each value is signed in the same
+    /// way as the corresponding accessor, e.g., [`Span::get_hours`].
+    ///
+    /// Units that are zero are omitted. This means the components of a zero
+    /// span (see [`Span::is_zero`]) are empty. Callers rendering a span will
+    /// usually want to handle that case specially, e.g., by printing `0s`
+    /// or `now`.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{ToSpan, Unit};
+    ///
+    /// let span = 5.years().days(3).minutes(30).nanoseconds(1);
+    /// assert_eq!(span.components(), vec![
+    ///     (Unit::Year, 5),
+    ///     (Unit::Day, 3),
+    ///     (Unit::Minute, 30),
+    ///     (Unit::Nanosecond, 1),
+    /// ]);
+    ///
+    /// let span = -2.weeks().hours(12);
+    /// assert_eq!(span.components(), vec![
+    ///     (Unit::Week, -2),
+    ///     (Unit::Hour, -12),
+    /// ]);
+    /// ```
+    ///
+    /// # Example: rendering a span
+    ///
+    /// ```
+    /// use jiff::{Span, ToSpan};
+    ///
+    /// fn render(span: Span) -> String {
+    ///     if span.is_zero() {
+    ///         return "nothing".to_string();
+    ///     }
+    ///     let parts: Vec<String> = span
+    ///         .components()
+    ///         .into_iter()
+    ///         .map(|(unit, value)| format!("{unit:?}: {value}"))
+    ///         .collect();
+    ///     parts.join(", ")
+    /// }
+    ///
+    /// assert_eq!(render(1.day().hours(2)), "Day: 1, Hour: 2");
+    /// assert_eq!(render(Span::new()), "nothing");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn components(&self) -> alloc::vec::Vec<(Unit, i64)> {
+        let mut units = self.units();
+        let mut components = alloc::vec::Vec::new();
+        while let Some(unit) = units.largest_unit() {
+            components.push((unit, self.get_units_ranged(unit).get()));
+            units = units.set(unit, true);
+        }
+        components
+    }
+}
+
+/// Routines for parsing `Span` values.
+impl Span {
+    /// Parses a span from Jiff's "friendly" duration format.
+    ///
+    /// This is the format that people tend to write on the command line,
+    /// e.g., `2h30m`, `1d12h` or `90s`. Unlike [`Span`]'s `FromStr`
+    /// implementation, this does not accept ISO 8601 durations. It is
+    /// equivalent to calling
+    /// [`SpanParser::parse_span`](friendly::SpanParser::parse_span) with a
+    /// default parser.
+    ///
+    /// Whitespace is permitted (but not required) between a unit value and
+    /// its label and between successive units. Leading and trailing
+    /// whitespace is not permitted. Units must be written from
+    /// largest to smallest and may not be repeated. The following unit
+    /// labels are supported, among others (see the
+    /// [`fmt::friendly`](friendly) module for the full grammar):
+    ///
+    /// * `y` for years.
+    /// * `mo` for months.
+    /// * `w` for weeks.
+    /// * `d` for days.
+    /// * `h` for hours.
+    /// * `m` for minutes.
+    /// * `s` for seconds.
+    /// * `ms` for milliseconds.
+    /// * `us` or `µs` for microseconds.
+    /// * `ns` for nanoseconds.
+    ///
+    /// Note that `m` always means minutes and never months.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input isn't a valid friendly duration or
+    /// if any of its units are out of range. When the input contains an
+    /// unrecognized unit label or trailing garbage, the error message
+    /// includes the byte offset at which parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Span, ToSpan};
+    ///
+    /// let span = Span::parse_friendly("2h30m")?;
+    /// assert_eq!(span, 2.hours().minutes(30).fieldwise());
+    ///
+    /// let span = Span::parse_friendly("1d 12h")?;
+    /// assert_eq!(span, 1.day().hours(12).fieldwise());
+    ///
+    /// let span = Span::parse_friendly("1mo 5m")?;
+    /// assert_eq!(span, 1.month().minutes(5).fieldwise());
+    ///
+    /// let err = Span::parse_friendly("2h30x").unwrap_err();
+    /// assert!(err.to_string().contains("at byte offset 4"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn parse_friendly<I: AsRef<[u8]>>(input: I) -> Result<Span, Error> {
+        friendly::DEFAULT_SPAN_PARSER.parse_span(input)
+    }
 }
 
 /// Routines for manipulating, comparing and inspecting `Span` values.
@@ -1976,6 +2176,34 @@ impl Span {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     ///
+    /// # Example: equality by duration
+    ///
+    /// Since spans are compared by the duration they represent, this routine
+    /// can be used to check whether two spans with different fields are
+    /// equivalent. Whether they are can depend on the relative datetime
+    /// given. For example, `P1D` and `PT24H` are equivalent on most days, but
+    /// not on days with a DST transition:
+    ///
+    /// ```
+    /// use jiff::{civil::date, ToSpan};
+    ///
+    /// let span1 = 1.day();
+    /// let span2 = 24.hours();
+    ///
+    /// let relative = date(2024, 3, 10).in_tz("America/New_York")?;
+    /// // A day is 23 hours long on this date in New York.
+    /// assert!(span1.compare((span2, &relative))?.is_lt());
+    ///
+    /// let relative = date(2024, 3, 11).in_tz("America/New_York")?;
+    /// assert!(span1.compare((span2, &relative))?.is_eq());
+    ///
+    /// // Civil dates don't have time zone transitions, and so, a day is
+    /// // always 24 hours long.
+    /// assert!(span1.compare((span2, date(2024, 3, 10)))?.is_eq());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// See the examples for [`Span::total`] if you want to sort spans without
     /// an `unwrap()` call.
     #[inline]
@@ -3694,6 +3922,52 @@ impl<'de> serde::Deserialize<'de> for Span {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Span {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Span> {
+        // Every unit is generated independently within its own limits, but
+        // all units must share the same sign. So we generate a magnitude for
+        // each unit and then apply a single sign to all of them.
+        fn unit(
+            u: &mut arbitrary::Unstructured<'_>,
+            max: impl Into<i64>,
+        ) -> arbitrary::Result<i64> {
+            u.int_in_range(0..=max.into())
+        }
+
+        let negative = bool::arbitrary(u)?;
+        let years = unit(u, t::SpanYears::MAX_REPR)?;
+        let months = unit(u, t::SpanMonths::MAX_REPR)?;
+        let weeks = unit(u, t::SpanWeeks::MAX_REPR)?;
+        let days = unit(u, t::SpanDays::MAX_REPR)?;
+        let hours = unit(u, t::SpanHours::MAX_REPR)?;
+        let minutes = unit(u, t::SpanMinutes::MAX_REPR)?;
+        let seconds = unit(u, t::SpanSeconds::MAX_REPR)?;
+        let milliseconds = unit(u, t::SpanMilliseconds::MAX_REPR)?;
+        let microseconds = unit(u, t::SpanMicroseconds::MAX_REPR)?;
+        let nanoseconds = unit(u, t::SpanNanoseconds::MAX_REPR)?;
+        let span = Span::new()
+            .try_years(years)
+            .and_then(|span| span.try_months(months))
+            .and_then(|span| span.try_weeks(weeks))
+            .and_then(|span| span.try_days(days))
+            .and_then(|span| span.try_hours(hours))
+            .and_then(|span| span.try_minutes(minutes))
+            .and_then(|span| span.try_seconds(seconds))
+            .and_then(|span| span.try_milliseconds(milliseconds))
+            .and_then(|span| span.try_microseconds(microseconds))
+            .and_then(|span| span.try_nanoseconds(nanoseconds))
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(if negative { span.negate() } else { span })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(bool, [i64; 10]) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Span {
     fn arbitrary(g: &mut quickcheck::Gen) -> Span {
@@ -4168,6 +4442,50 @@ impl_to_span!(i64);
 /// assert!(Unit::Hour > Unit::Minute);
 /// assert_eq!(Unit::Hour, Unit::Hour);
 /// ```
+///
+/// # Calendar units versus time units
+///
+/// The units [`Unit::Year`], [`Unit::Month`], [`Unit::Week`] and
+/// [`Unit::Day`] are _calendar_ units. Their length in absolute time varies.
+/// For example, a month can have anywhere from 28 to 31 days, and in a time
+/// zone with daylight saving time, a day can be 23 or 25 hours long. So
+/// operations that need to know the length of a calendar unit (like rounding,
+/// computing totals or comparing spans) require a relative datetime to
+/// resolve them against. (Days may alternatively be treated as always being
+/// 24 hours via [`SpanRelativeTo::days_are_24_hours`].)
+///
+/// The remaining units, from [`Unit::Hour`] down to [`Unit::Nanosecond`],
+/// are _time_ units. Each of them always has the same length, and so they
+/// never require a relative datetime.
+///
+/// ```
+/// use jiff::{civil::date, ToSpan, Unit};
+///
+/// // Every unit, from biggest to smallest.
+/// let units = [
+///     Unit::Year,
+///     Unit::Month,
+///     Unit::Week,
+///     Unit::Day,
+///     Unit::Hour,
+///     Unit::Minute,
+///     Unit::Second,
+///     Unit::Millisecond,
+///     Unit::Microsecond,
+///     Unit::Nanosecond,
+/// ];
+/// assert!(units.windows(2).all(|pair| pair[0] > pair[1]));
+///
+/// // Calendar units require a relative datetime.
+/// assert!(1.year().total(Unit::Day).is_err());
+/// assert_eq!(1.year().total((Unit::Day, date(2024, 1, 1)))?, 366.0);
+/// assert_eq!(1.year().total((Unit::Day, date(2025, 1, 1)))?, 365.0);
+///
+/// // Time units do not.
+/// assert_eq!(90.minutes().total(Unit::Hour)?, 1.5);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum Unit {
     /// A Gregorian calendar year. It usually has 365 days for non-leap years,
@@ -6319,9 +6637,7 @@ impl<'a> RelativeZoned<'a> {
         &self,
         span: Span,
     ) -> Result<RelativeZoned<'static>, Error> {
-        let zoned = self.zoned.checked_add(span).with_context(|| {
-            err!("failed to add {span} to {zoned}", zoned = self.zoned)
-        })?;
+        let zoned = self.zoned.checked_add(span)?;
         Ok(RelativeZoned { zoned: DumbCow::Owned(zoned) })
     }
 
@@ -6335,9 +6651,7 @@ impl<'a> RelativeZoned<'a> {
         &self,
         duration: SignedDuration,
     ) -> Result<RelativeZoned<'static>, Error> {
-        let zoned = self.zoned.checked_add(duration).with_context(|| {
-            err!("failed to add {duration:?} to {zoned}", zoned = self.zoned)
-        })?;
+        let zoned = self.zoned.checked_add(duration)?;
         Ok(RelativeZoned { zoned: DumbCow::Owned(zoned) })
     }
 
@@ -6793,6 +7107,50 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn components() {
+        let span = 1
+            .year()
+            .months(2)
+            .days(4)
+            .hours(5)
+            .seconds(7)
+            .milliseconds(8)
+            .nanoseconds(10);
+        assert_eq!(
+            span.components(),
+            [
+                (Unit::Year, 1),
+                (Unit::Month, 2),
+                (Unit::Day, 4),
+                (Unit::Hour, 5),
+                (Unit::Second, 7),
+                (Unit::Millisecond, 8),
+                (Unit::Nanosecond, 10),
+            ],
+        );
+        assert_eq!(
+            (-span.weeks(3).minutes(6).microseconds(9)).components(),
+            [
+                (Unit::Year, -1),
+                (Unit::Month, -2),
+                (Unit::Week, -3),
+                (Unit::Day, -4),
+                (Unit::Hour, -5),
+                (Unit::Minute, -6),
+                (Unit::Second, -7),
+                (Unit::Millisecond, -8),
+                (Unit::Microsecond, -9),
+                (Unit::Nanosecond, -10),
+            ],
+        );
+        // Setting a unit back to zero removes it.
+        assert_eq!(span.hours(0).seconds(0).components().len(), 5);
+        assert_eq!(Span::new().components(), []);
+        assert_eq!(Span::new().hours(0).components(), []);
+    }
+
     #[test]
     fn test_total() {
         if crate::tz::db().is_definitively_empty() {
@@ -6890,6 +7248,100 @@ mod tests {
         span_eq!(sum, 2.months().days(30));
     }
 
+    #[test]
+    fn test_compare_equal_duration() {
+        let (day, hours) = (1.day(), 24.hours());
+        // `Eq` is always field-wise.
+        assert_ne!(day.fieldwise(), hours.fieldwise());
+
+        let relative = date(2024, 3, 10);
+        assert_eq!(day.compare((hours, relative)).unwrap(), Ordering::Equal);
+        let options = SpanCompare::from(hours).days_are_24_hours();
+        assert_eq!(day.compare(options).unwrap(), Ordering::Equal);
+        // Days require a relative datetime.
+        assert!(day.compare(hours).is_err());
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let zdt = date(2024, 3, 10).in_tz("America/New_York").unwrap();
+        assert_eq!(day.compare((hours, &zdt)).unwrap(), Ordering::Less);
+        assert_eq!(day.compare((23.hours(), &zdt)).unwrap(), Ordering::Equal);
+        let zdt = date(2024, 11, 3).in_tz("America/New_York").unwrap();
+        assert_eq!(day.compare((hours, &zdt)).unwrap(), Ordering::Greater);
+        assert_eq!(day.compare((25.hours(), &zdt)).unwrap(), Ordering::Equal);
+        let zdt = date(2024, 11, 4).in_tz("America/New_York").unwrap();
+        assert_eq!(day.compare((hours, &zdt)).unwrap(), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        // Multiplication never balances units, so `PT15M * 4` is `PT60M`.
+        let span = 15.minutes().checked_mul(4).unwrap();
+        span_eq!(span, 60.minutes());
+        assert_eq!(span.to_string(), "PT60M");
+
+        let span = 1.year().days(2).hours(3).checked_mul(3).unwrap();
+        span_eq!(span, 3.years().days(6).hours(9));
+
+        // A negative factor flips the sign of every field.
+        let span = 15.minutes().seconds(30).checked_mul(-4).unwrap();
+        span_eq!(span, -60.minutes().seconds(120));
+        let span = span.checked_mul(-1).unwrap();
+        span_eq!(span, 60.minutes().seconds(120));
+
+        span_eq!(15.minutes().checked_mul(0).unwrap(), Span::new());
+
+        // Overflow in any field is an error that names the field.
+        let err = 19_998.years().checked_mul(2).unwrap_err();
+        assert!(err.to_string().contains("years"), "{err}");
+        let err =
+            1.hour().minutes(6_000_000_000i64).checked_mul(2).unwrap_err();
+        assert!(err.to_string().contains("minutes"), "{err}");
+        let err = 2.nanoseconds().checked_mul(i64::MAX).unwrap_err();
+        assert!(err.to_string().contains("nanoseconds"), "{err}");
+    }
+
+    #[test]
+    fn test_to_duration_relative() {
+        // `P1M` is a different amount of elapsed time depending on the
+        // month it's anchored to.
+        let span = 1.month();
+        let dur = span.to_duration(date(2023, 1, 31)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(28 * 24));
+        let dur = span.to_duration(date(2023, 1, 15)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(31 * 24));
+        let dur = span.to_duration(date(2023, 3, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(31 * 24));
+
+        // Crossing from a 31 day month into a 30 day month. The day is
+        // clamped to the end of April.
+        let dur = span.to_duration(date(2023, 3, 31)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(30 * 24));
+        let dur = span.to_duration(date(2023, 4, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(30 * 24));
+
+        // February in leap and non-leap years.
+        let dur = span.to_duration(date(2024, 2, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(29 * 24));
+        let dur = span.to_duration(date(2023, 2, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(28 * 24));
+        let dur = 1.year().to_duration(date(2024, 1, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(366 * 24));
+        let dur = 1.year().to_duration(date(2023, 1, 1)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(365 * 24));
+
+        // Negative calendar spans are anchored in the same way.
+        let dur = (-1).month().to_duration(date(2024, 3, 31)).unwrap();
+        assert_eq!(dur, SignedDuration::from_hours(-31 * 24));
+
+        // Time-only spans don't depend on the anchor.
+        let span = 5.hours().minutes(30);
+        let expected = SignedDuration::new(5 * 60 * 60 + 30 * 60, 0);
+        assert_eq!(span.to_duration(date(2023, 1, 31)).unwrap(), expected);
+        assert_eq!(span.to_duration(date(2024, 2, 29)).unwrap(), expected);
+    }
+
     #[test]
     fn test_round_day_time() {
         let span = 29.seconds();
@@ -6945,6 +7397,73 @@ mod tests {
         span_eq!(rounded, 10.minutes());
     }
 
+    #[test]
+    fn test_round_half_even_and_calendar() {
+        let half_even =
+            |unit| SpanRound::new().smallest(unit).mode(RoundMode::HalfEven);
+
+        let span = 1.hour().minutes(40);
+        span_eq!(span.round(half_even(Unit::Hour)).unwrap(), 2.hours());
+        // Ties round to the nearest even increment.
+        let span = 1.hour().minutes(30);
+        span_eq!(span.round(half_even(Unit::Hour)).unwrap(), 2.hours());
+        let span = 2.hours().minutes(30);
+        span_eq!(span.round(half_even(Unit::Hour)).unwrap(), 2.hours());
+        let span = -2.hours().minutes(30);
+        span_eq!(span.round(half_even(Unit::Hour)).unwrap(), -2.hours());
+
+        // Rounding calendar units requires a relative date, since the length
+        // of a month varies. Here, 15 days is more than half of February
+        // 2024, but less than half of March 2024.
+        let span = 1.month().days(15);
+        assert!(span.round(half_even(Unit::Month)).is_err());
+        let rounded = span
+            .round(half_even(Unit::Month).relative(date(2024, 1, 1)))
+            .unwrap();
+        span_eq!(rounded, 2.months());
+        let rounded = span
+            .round(half_even(Unit::Month).relative(date(2024, 2, 1)))
+            .unwrap();
+        span_eq!(rounded, 1.month());
+    }
+
+    #[test]
+    fn test_fluent_setters() {
+        let span = Span::new()
+            .years(1)
+            .months(2)
+            .days(3)
+            .hours(4)
+            .minutes(5)
+            .milliseconds(6);
+        span_eq!(
+            span,
+            1.year().months(2).days(3).hours(4).minutes(5).milliseconds(6)
+        );
+        assert_eq!(span.to_string(), "P1Y2M3DT4H5M0.006S");
+
+        let span = Span::new()
+            .try_years(-19_998)
+            .and_then(|s| s.try_months(-1))
+            .and_then(|s| s.try_hours(-4))
+            .unwrap();
+        assert_eq!(span.get_years(), -19_998);
+        assert_eq!(span.get_months(), -1);
+        assert_eq!(span.get_hours(), -4);
+
+        insta::assert_snapshot!(
+            Span::new().try_years(1).unwrap().try_years(19_999).unwrap_err(),
+            @"parameter 'years' with value 19999 is not in the required range of -19998..=19998",
+        );
+        insta::assert_snapshot!(
+            Span::new().try_days(3).unwrap().try_hours(i64::MAX).unwrap_err(),
+            @"parameter 'hours' with value 9223372036854775807 is not in the required range of -175307616..=175307616",
+        );
+        // Mixing signs is not allowed, and the sign of the most recently
+        // set non-zero unit wins.
+        assert!(Span::new().years(1).days(-3).is_negative());
+    }
+
     #[test]
     fn test_round_relative_zoned_calendar() {
         if crate::tz::db().is_definitively_empty() {
@@ -7321,6 +7840,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn accessors() {
+        let span = 1
+            .year()
+            .months(2)
+            .weeks(3)
+            .days(4)
+            .hours(5)
+            .minutes(6)
+            .seconds(7)
+            .milliseconds(8)
+            .microseconds(9)
+            .nanoseconds(10);
+        let components = |span: Span| {
+            (
+                span.get_years(),
+                span.get_months(),
+                span.get_weeks(),
+                span.get_days(),
+                span.get_hours(),
+                span.get_minutes(),
+                span.get_seconds(),
+                span.get_milliseconds(),
+                span.get_microseconds(),
+                span.get_nanoseconds(),
+            )
+        };
+
+        assert_eq!(components(span), (1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
+        assert!(!span.is_zero());
+        assert!(span.is_positive());
+        assert!(!span.is_negative());
+
+        let span = span.negate();
+        assert_eq!(
+            components(span),
+            (-1, -2, -3, -4, -5, -6, -7, -8, -9, -10),
+        );
+        assert!(!span.is_zero());
+        assert!(!span.is_positive());
+        assert!(span.is_negative());
+
+        let span = Span::new();
+        assert_eq!(components(span), (0, 0, 0, 0, 0, 0, 0, 0, 0, 0));
+        assert!(span.is_zero());
+        assert!(!span.is_positive());
+        assert!(!span.is_negative());
+    }
+
+    #[test]
+    fn parse_friendly() {
+        let p = |s: &str| Span::parse_friendly(s);
+
+        insta::assert_snapshot!(p("2h30m").unwrap(), @"PT2H30M");
+        insta::assert_snapshot!(p("1w2d").unwrap(), @"P1W2D");
+        insta::assert_snapshot!(p("1d12h").unwrap(), @"P1DT12H");
+        insta::assert_snapshot!(p("90s").unwrap(), @"PT90S");
+        // Surrounding whitespace isn't allowed.
+        insta::assert_snapshot!(p(" 1mo  5m ").unwrap_err(), @"failed to parse \" 1mo  5m \" in the \"friendly\" format: parsing a friendly duration requires it to start with a unit value (a decimal integer) after an optional sign, but no integer was found");
+        insta::assert_snapshot!(p("1mo  5m").unwrap(), @"P1MT5M");
+        insta::assert_snapshot!(p("1s 2ms 3us 4ns").unwrap(), @"PT1.002003004S");
+        insta::assert_snapshot!(p("1s 2ms 3µs 4ns").unwrap(), @"PT1.002003004S");
+
+        insta::assert_snapshot!(p("2h30x").unwrap_err(), @"failed to parse \"2h30x\" in the \"friendly\" format: expected to find unit designator suffix (e.g., 'years' or 'secs'), but found input beginning with \"x\" at byte offset 4 instead");
+        insta::assert_snapshot!(p("2h 30m!").unwrap_err(), @"failed to parse \"2h 30m!\" in the \"friendly\" format: parsed value 'PT2H30M', but unparsed input \"!\" remains at byte offset 6 (expected no unparsed input)");
+        // The ISO 8601 format isn't accepted.
+        insta::assert_snapshot!(p("PT2H30M").unwrap_err(), @"failed to parse \"PT2H30M\" in the \"friendly\" format: parsing a friendly duration requires it to start with a unit value (a decimal integer) after an optional sign, but no integer was found");
+    }
+
     #[test]
     fn serde_deserialize() {
         let p = |s: &str| -> Result<Span, serde_json::Error> {
@@ -7365,4 +7953,32 @@ mod tests {
             @"found nothing after sign `-`, which is not a valid `Span`, expected either a ISO 8601 or Jiff's 'friendly' format at line 1 column 3",
         );
     }
+
+    #[test]
+    fn serde_roundtrip() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Record {
+            span: Span,
+            maybe: Option<Span>,
+        }
+
+        let calendar = 1.year().months(2).weeks(3).days(4).hours(5);
+        let record = Record { span: calendar, maybe: Some(-calendar) };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"span":"P1Y2M3W4DT5H","maybe":"-P1Y2M3W4DT5H"}"#);
+        let got: Record = serde_json::from_str(&json).unwrap();
+        span_eq!(got.span, calendar);
+        span_eq!(got.maybe.unwrap(), -calendar);
+
+        let time = 2.hours().minutes(30).seconds(1).milliseconds(500);
+        let record = Record { span: time, maybe: None };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"span":"PT2H30M1.5S","maybe":null}"#);
+        let got: Record = serde_json::from_str(&json).unwrap();
+        span_eq!(got.span, time);
+        assert!(got.maybe.is_none());
+
+        let err = serde_json::from_str::<Span>(r#""P1Q""#).unwrap_err();
+        insta::assert_snapshot!(err, @"failed to parse ISO 8601 duration string into `Span`: expected to find date unit designator suffix (Y, M, W or D), but found \"Q\" instead at line 1 column 5");
+    }
 }
@@ -1,6 +1,7 @@
 use core::time::Duration as UnsignedDuration;
 
 use crate::{
+    civil,
     duration::{Duration, SDuration},
     error::{err, Error, ErrorContext},
     fmt::{
@@ -10,14 +11,15 @@ use crate::{
     shared::util::itime::ITimestamp,
     tz::{Offset, TimeZone},
     util::{
-        rangeint::{self, Composite, RFrom, RInto},
+        parse,
+        rangeint::{self, Composite, RFrom, RInto, TryRFrom},
         round::increment,
         t::{
             self, FractionalNanosecond, NoUnits, NoUnits128, UnixMicroseconds,
             UnixMilliseconds, UnixNanoseconds, UnixSeconds, C,
         },
     },
-    zoned::Zoned,
+    zoned::{Zoned, ZonedArithmetic},
     RoundMode, SignedDuration, Span, SpanRound, Unit,
 };
 
@@ -61,9 +63,47 @@ use crate::{
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 ///
+/// Parsing is not restricted to strict RFC 3339. In particular, the date
+/// and time may be separated by `T`, `t` or a single ASCII space (as
+/// permitted by the note in [RFC 3339 section 5.6]). This means formats
+/// emitted by, e.g., PostgreSQL, can be parsed directly:
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let ts1: Timestamp = "2021-07-30T21:20:04Z".parse()?;
+/// let ts2: Timestamp = "2021-07-30t21:20:04z".parse()?;
+/// let ts3: Timestamp = "2021-07-30 21:20:04Z".parse()?;
+/// assert_eq!(ts1, ts2);
+/// assert_eq!(ts1, ts3);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Printing a `Timestamp` never allocates. To avoid allocating a new `String`
+/// for every timestamp printed, write it into an existing buffer (or any
+/// other [`core::fmt::Write`] implementation) with
+/// [`Timestamp::format_into`] or `write!`:
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let mut buf = String::new();
+/// for second in [0, 1_718_810_565] {
+///     buf.clear();
+///     Timestamp::from_second(second)?.format_into(&mut buf)?;
+///     assert!(buf.ends_with('Z'));
+/// }
+/// assert_eq!(buf, "2024-06-19T15:22:45Z");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// For more information on the specific format supported, see the
 /// [`fmt::temporal`](crate::fmt::temporal) module documentation.
 ///
+/// [RFC 3339 section 5.6]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+///
 /// # Default value
 ///
 /// For convenience, this type implements the `Default` trait. Its default
@@ -102,6 +142,28 @@ use crate::{
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 ///
+/// The ordering is guaranteed to be a total order on the instants
+/// represented by each timestamp. That is, two timestamps compare equal if
+/// and only if they refer to precisely the same instant in time (down to the
+/// nanosecond), and `ts1 < ts2` if and only if `ts1` occurs before `ts2`.
+///
+/// For reducing a collection of timestamps, [`Timestamp::min`] and
+/// [`Timestamp::max`] are also provided:
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let timestamps = [
+///     Timestamp::from_second(123_456_790)?,
+///     Timestamp::from_second(123_456_789)?,
+///     Timestamp::from_second(123_456_791)?,
+/// ];
+/// let latest = timestamps.iter().copied().reduce(Timestamp::max);
+/// assert_eq!(latest, Some(Timestamp::from_second(123_456_791)?));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// # Arithmetic
 ///
 /// This type provides routines for adding and subtracting spans of time, as
@@ -405,6 +467,106 @@ impl Timestamp {
             .expect("system time is valid")
     }
 
+    /// Returns the current system time as a timestamp, truncated to the
+    /// given unit.
+    ///
+    /// This is useful when the current time will be stored somewhere that
+    /// can't represent Jiff's full nanosecond precision. For example, a
+    /// database column with millisecond resolution. Truncating up front
+    /// ensures that the timestamp used by the program is the same as the one
+    /// that will be read back out of storage.
+    ///
+    /// This is equivalent to rounding the result of [`Timestamp::now`] with
+    /// [`RoundMode::Floor`]. That is, the timestamp returned is never after
+    /// the current time, even when the system clock is set to a time before
+    /// the Unix epoch. (Using [`RoundMode::Trunc`] instead would round
+    /// timestamps before the Unix epoch towards it, and thus into the
+    /// future.)
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given unit is bigger than
+    /// [`Unit::Second`].
+    ///
+    /// # Panics
+    ///
+    /// This panics in the same circumstances as [`Timestamp::now`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Unit};
+    ///
+    /// let ts = Timestamp::now_truncated(Unit::Millisecond)?;
+    /// assert_eq!(ts.subsec_nanosecond() % 1_000_000, 0);
+    ///
+    /// assert!(Timestamp::now_truncated(Unit::Minute).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn now_truncated(unit: Unit) -> Result<Timestamp, Error> {
+        if unit > Unit::Second {
+            return Err(err!(
+                "truncating the current time to {unit} is not supported, \
+                 expected seconds or a smaller unit",
+                unit = unit.plural(),
+            ));
+        }
+        let options =
+            TimestampRound::new().smallest(unit).mode(RoundMode::Floor);
+        Timestamp::now().round(options)
+    }
+
+    /// Returns the current system time as a timestamp, but never returns a
+    /// timestamp before one previously returned by this routine.
+    ///
+    /// The system clock can jump backwards, for example, when it is adjusted
+    /// by NTP or by an administrator. When that happens, [`Timestamp::now`]
+    /// will return a timestamp before one it returned previously. This
+    /// routine instead returns the last timestamp it returned, until the
+    /// system clock catches up again. This makes it suitable for things like
+    /// ordering events or generating ordered identifiers within a process.
+    ///
+    /// The last timestamp returned is tracked by a single atomic shared by
+    /// the entire process. So this is safe to call from multiple threads, and
+    /// all calls to this routine observe a single non-decreasing sequence of
+    /// timestamps. Note that consecutive calls may return equal timestamps.
+    ///
+    /// Beware that while the system clock is behind a previously returned
+    /// value, the timestamps returned by this routine diverge from true wall
+    /// clock time. They are only as accurate as the largest system time
+    /// observed so far. Moreover, this tracking is only done for system
+    /// times between the years 1677 and 2262 (the range of a 64-bit count of
+    /// nanoseconds). Outside of that range, this returns the same value as
+    /// `Timestamp::now`.
+    ///
+    /// This is only available when the `std` feature is enabled and the
+    /// target supports 64-bit atomics.
+    ///
+    /// # Panics
+    ///
+    /// This panics in the same circumstances as [`Timestamp::now`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let mut last = Timestamp::now_monotonic();
+    /// for _ in 0..1_000 {
+    ///     let now = Timestamp::now_monotonic();
+    ///     assert!(now >= last);
+    ///     last = now;
+    /// }
+    /// ```
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    pub fn now_monotonic() -> Timestamp {
+        static LAST: std::sync::atomic::AtomicI64 =
+            std::sync::atomic::AtomicI64::new(i64::MIN);
+        Timestamp::now().clamp_to_last(&LAST)
+    }
+
     /// Creates a new instant in time represented as a timestamp.
     ///
     /// While a timestamp is logically a count of nanoseconds since the Unix
@@ -843,6 +1005,106 @@ impl Timestamp {
         Ok(Timestamp::from_nanosecond_ranged(nanosecond))
     }
 
+    /// Parses a string containing an integer number of units elapsed since
+    /// the Unix epoch.
+    ///
+    /// The string must consist of one or more ASCII decimal digits, optionally
+    /// preceded by a `+` or `-` sign. No other characters, including
+    /// whitespace, are allowed. The unit must be one of [`Unit::Second`],
+    /// [`Unit::Millisecond`], [`Unit::Microsecond`] or [`Unit::Nanosecond`].
+    ///
+    /// This is useful when consuming data like logs, where a Unix timestamp
+    /// is often represented as a raw string. If the unit isn't known, then
+    /// [`Timestamp::from_epoch_str_auto`] can guess it.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the string is not a valid integer, if the
+    /// unit is not one of the units listed above or if the integer
+    /// corresponds to an instant outside the [`Timestamp::MIN`] and
+    /// [`Timestamp::MAX`] boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{Timestamp, Unit};
+    ///
+    /// let ts = Timestamp::from_epoch_str("1627680004", Unit::Second)?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04Z");
+    ///
+    /// let ts = Timestamp::from_epoch_str("1627680004123", Unit::Millisecond)?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04.123Z");
+    ///
+    /// let ts = Timestamp::from_epoch_str("-1", Unit::Nanosecond)?;
+    /// assert_eq!(ts.to_string(), "1969-12-31T23:59:59.999999999Z");
+    ///
+    /// assert!(Timestamp::from_epoch_str("1627680004", Unit::Day).is_err());
+    /// assert!(Timestamp::from_epoch_str("1.5", Unit::Second).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_epoch_str(s: &str, unit: Unit) -> Result<Timestamp, Error> {
+        let (sign, digits) = split_epoch_sign(s);
+        from_epoch_digits(sign, digits, unit).with_context(|| {
+            err!(
+                "failed to parse {s:?} as Unix timestamp in {unit}",
+                unit = unit.plural(),
+            )
+        })
+    }
+
+    /// Parses a string containing an integer number of units elapsed since
+    /// the Unix epoch, where the unit is guessed from the number of digits.
+    ///
+    /// This is a best effort heuristic. It works by assuming the instant
+    /// is relatively close to the present, such that the magnitude of the
+    /// integer reveals its unit. Specifically, ignoring any leading `+` or `-`
+    /// sign:
+    ///
+    /// * `1` to `11` digits are interpreted as seconds.
+    /// * `12` to `14` digits are interpreted as milliseconds.
+    /// * `15` to `17` digits are interpreted as microseconds.
+    /// * `18` or more digits are interpreted as nanoseconds.
+    ///
+    /// This means that, for example, millisecond timestamps before
+    /// 1973-03-03 or second timestamps after the year 5138 will be
+    /// misinterpreted. When the unit is known, prefer
+    /// [`Timestamp::from_epoch_str`].
+    ///
+    /// Note that leading zeros count as digits.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same cases as
+    /// [`Timestamp::from_epoch_str`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::from_epoch_str_auto("1627680004")?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04Z");
+    ///
+    /// let ts = Timestamp::from_epoch_str_auto("1627680004123")?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04.123Z");
+    ///
+    /// let ts = Timestamp::from_epoch_str_auto("1627680004123456789")?;
+    /// assert_eq!(ts.to_string(), "2021-07-30T21:20:04.123456789Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_epoch_str_auto(s: &str) -> Result<Timestamp, Error> {
+        let (_, digits) = split_epoch_sign(s);
+        let unit = match digits.len() {
+            0..=11 => Unit::Second,
+            12..=14 => Unit::Millisecond,
+            15..=17 => Unit::Microsecond,
+            _ => Unit::Nanosecond,
+        };
+        Timestamp::from_epoch_str(s, unit)
+    }
+
     /// Creates a new timestamp from a `Duration` with the given sign since the
     /// Unix epoch.
     ///
@@ -1197,6 +1459,55 @@ impl Timestamp {
         SignedDuration::from_timestamp(self)
     }
 
+    /// Returns the number of seconds since the Unix epoch, with a possible
+    /// fractional nanosecond component, as a 64-bit float.
+    ///
+    /// Note that a 64-bit float cannot represent every timestamp exactly.
+    /// Timestamps far from the Unix epoch will lose sub-second precision.
+    /// For example, a timestamp in the present day can only be represented
+    /// to within roughly a few hundred nanoseconds. Use
+    /// [`Timestamp::as_nanosecond`] when a lossless value is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(5, 123_456_789)?;
+    /// assert_eq!(ts.as_second_f64(), 5.123456789);
+    /// let ts = Timestamp::new(-5, -123_456_789)?;
+    /// assert_eq!(ts.as_second_f64(), -5.123456789);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn as_second_f64(self) -> f64 {
+        self.as_duration().as_secs_f64()
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch, with a
+    /// possible fractional nanosecond component, as a 64-bit float.
+    ///
+    /// Like [`Timestamp::as_second_f64`], this may lose precision for
+    /// timestamps far from the Unix epoch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts = Timestamp::new(5, 123_456_789)?;
+    /// assert_eq!(ts.as_millisecond_f64(), 5123.456789);
+    /// let ts = Timestamp::new(-5, -123_456_789)?;
+    /// assert_eq!(ts.as_millisecond_f64(), -5123.456789);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn as_millisecond_f64(self) -> f64 {
+        self.as_duration().as_millis_f64()
+    }
+
     /// Returns the sign of this timestamp.
     ///
     /// This can return one of three possible values:
@@ -1257,6 +1568,133 @@ impl Timestamp {
         self.as_second() == 0 && self.subsec_nanosecond() == 0
     }
 
+    /// Returns true if and only if this timestamp occurs strictly before the
+    /// Unix epoch.
+    ///
+    /// This is equivalent to `ts.signum() < 0` and to
+    /// `ts < Timestamp::UNIX_EPOCH`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// assert!(Timestamp::new(0, -1)?.is_before_epoch());
+    /// assert!(!Timestamp::UNIX_EPOCH.is_before_epoch());
+    /// assert!(!Timestamp::new(0, 1)?.is_before_epoch());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_before_epoch(self) -> bool {
+        self.signum() < 0
+    }
+
+    /// Returns true if and only if this timestamp occurs strictly after the
+    /// Unix epoch.
+    ///
+    /// This is equivalent to `ts.signum() > 0` and to
+    /// `ts > Timestamp::UNIX_EPOCH`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// assert!(!Timestamp::new(0, -1)?.is_after_epoch());
+    /// assert!(!Timestamp::UNIX_EPOCH.is_after_epoch());
+    /// assert!(Timestamp::new(0, 1)?.is_after_epoch());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_after_epoch(self) -> bool {
+        self.signum() > 0
+    }
+
+    /// Returns the earlier of this timestamp and the one given.
+    ///
+    /// This is equivalent to [`Ord::min`], but is provided as an inherent
+    /// method for convenience. When both timestamps are equal, `self` is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts1 = Timestamp::from_second(123_456_789)?;
+    /// let ts2 = Timestamp::from_second(123_456_790)?;
+    /// assert_eq!(ts1.min(ts2), ts1);
+    /// assert_eq!(ts2.min(ts1), ts1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn min(self, other: Timestamp) -> Timestamp {
+        Ord::min(self, other)
+    }
+
+    /// Returns the later of this timestamp and the one given.
+    ///
+    /// This is equivalent to [`Ord::max`], but is provided as an inherent
+    /// method for convenience. When both timestamps are equal, `other` is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let ts1 = Timestamp::from_second(123_456_789)?;
+    /// let ts2 = Timestamp::from_second(123_456_790)?;
+    /// assert_eq!(ts1.max(ts2), ts2);
+    /// assert_eq!(ts2.max(ts1), ts2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn max(self, other: Timestamp) -> Timestamp {
+        Ord::max(self, other)
+    }
+
+    /// Returns true if and only if this timestamp is between `start` and
+    /// `end`.
+    ///
+    /// Whether `start` and `end` themselves are considered to be in the range
+    /// is determined by the given [`Inclusivity`].
+    ///
+    /// When `start` is after `end`, the range is empty and this always
+    /// returns false.
+    ///
+    /// # Example
+    ///
+    /// This shows how to check whether a token is still valid, where the
+    /// expiration time itself is excluded from the validity window:
+    ///
+    /// ```
+    /// use jiff::{Inclusivity, Timestamp, ToSpan};
+    ///
+    /// let issued: Timestamp = "2024-06-19T15:00:00Z".parse()?;
+    /// let expires = issued.checked_add(1.hour())?;
+    ///
+    /// let now: Timestamp = "2024-06-19T15:59:59Z".parse()?;
+    /// assert!(now.is_between(issued, expires, Inclusivity::ClosedOpen));
+    /// assert!(!expires.is_between(issued, expires, Inclusivity::ClosedOpen));
+    /// assert!(expires.is_between(issued, expires, Inclusivity::Closed));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_between(
+        self,
+        start: Timestamp,
+        end: Timestamp,
+        inclusivity: Inclusivity,
+    ) -> bool {
+        inclusivity.contains(start, end, self)
+    }
+
     /// Creates a [`Zoned`] value by attaching a time zone for the given name
     /// to this instant in time.
     ///
@@ -1339,6 +1777,15 @@ impl Timestamp {
     /// like `Australia/Tasmania`, consider using [`Timestamp::in_tz`]
     /// instead.
     ///
+    /// Since a `Zoned` owns its time zone, this takes the time zone by value.
+    /// To convert many timestamps to the same time zone, clone it for each
+    /// one. This is [cheap](TimeZone#a-timezone-is-cheap-to-clone), since
+    /// it only increments a reference count. A borrowing API would need to
+    /// perform the same clone internally. If only the civil datetime or
+    /// offset is needed, then [`TimeZone::to_datetime`] and
+    /// [`TimeZone::to_offset`] borrow the time zone and avoid the clone
+    /// entirely.
+    ///
     /// # Example
     ///
     /// This example shows how to create a zoned value with a fixed time zone
@@ -1355,6 +1802,25 @@ impl Timestamp {
     /// assert_eq!(zdt.to_string(), "1973-11-29T17:33:09-04:00[-04:00]");
     /// ```
     ///
+    /// # Example: converting many timestamps to one time zone
+    ///
+    /// ```
+    /// use jiff::{tz::TimeZone, Timestamp};
+    ///
+    /// let tz = TimeZone::get("Europe/Paris")?;
+    /// let zdts: Vec<String> = (0..3)
+    ///     .map(|i| Timestamp::from_second(i * 86_400))
+    ///     .map(|ts| Ok(ts?.to_zoned(tz.clone()).to_string()))
+    ///     .collect::<Result<_, jiff::Error>>()?;
+    /// assert_eq!(zdts, [
+    ///     "1970-01-01T01:00:00+01:00[Europe/Paris]",
+    ///     "1970-01-02T01:00:00+01:00[Europe/Paris]",
+    ///     "1970-01-03T01:00:00+01:00[Europe/Paris]",
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// # Example: POSIX time zone strings
     ///
     /// This example shows how to create a time zone from a POSIX time zone
@@ -1382,6 +1848,34 @@ impl Timestamp {
         Zoned::new(self, tz)
     }
 
+    /// Converts this timestamp to a civil datetime in the given time zone.
+    ///
+    /// This is like [`Timestamp::to_zoned`], except it returns only the
+    /// [`civil::DateTime`] component instead of a [`Zoned`]. Since a civil
+    /// datetime doesn't carry a time zone, this routine only needs to borrow
+    /// the `TimeZone` given. It is equivalent to calling
+    /// [`TimeZone::to_datetime`].
+    ///
+    /// This conversion is infallible since every timestamp maps to exactly
+    /// one civil datetime in any time zone. The inverse of this operation,
+    /// [`civil::DateTime::to_timestamp`], can be ambiguous and fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone, Timestamp};
+    ///
+    /// let tz = TimeZone::get("Asia/Kolkata")?;
+    /// let ts: Timestamp = "2024-07-04T12:00:00Z".parse()?;
+    /// assert_eq!(ts.to_civil(&tz), date(2024, 7, 4).at(17, 30, 0, 0));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn to_civil(self, tz: &TimeZone) -> civil::DateTime {
+        tz.to_datetime(self)
+    }
+
     /// Add the given span of time to this timestamp.
     ///
     /// This operation accepts three different duration types: [`Span`],
@@ -1404,9 +1898,10 @@ impl Timestamp {
     ///
     /// This also returns an error if the given duration is a `Span` with any
     /// non-zero units greater than hours. If you want to use bigger units,
-    /// convert this timestamp to a `Zoned` and use [`Zoned::checked_add`].
-    /// This error occurs because a `Timestamp` has no time zone attached to
-    /// it, and thus cannot unambiguously resolve the length of a single day.
+    /// use [`Timestamp::checked_add_in`] or convert this timestamp to a
+    /// `Zoned` and use [`Zoned::checked_add`]. This error occurs because a
+    /// `Timestamp` has no time zone attached to it, and thus cannot
+    /// unambiguously resolve the length of a single day.
     ///
     /// # Example
     ///
@@ -1499,11 +1994,24 @@ impl Timestamp {
         duration: A,
     ) -> Result<Timestamp, Error> {
         let duration: TimestampArithmetic = duration.into();
-        duration.checked_add(self)
+        duration.checked_add(self).with_context(|| {
+            err!(
+                "failed to add {duration} to {self}",
+                duration = duration.duration
+            )
+        })
     }
 
+    /// Adds the given span to this timestamp.
+    ///
+    /// Unlike `Timestamp::checked_add`, this doesn't attach any context
+    /// describing the operation to errors. This is useful for callers that
+    /// attach their own context.
     #[inline]
-    fn checked_add_span(self, span: Span) -> Result<Timestamp, Error> {
+    pub(crate) fn checked_add_span(
+        self,
+        span: Span,
+    ) -> Result<Timestamp, Error> {
         if let Some(err) = span.smallest_non_time_non_zero_unit_error() {
             return Err(err);
         }
@@ -1518,58 +2026,109 @@ impl Timestamp {
         if self.subsec_nanosecond_ranged() == 0 {
             if let Some(span_seconds) = span.to_invariant_seconds() {
                 let time_seconds = self.as_second_ranged();
-                let sum = time_seconds
-                    .try_checked_add("span", span_seconds)
-                    .with_context(|| {
-                    err!("adding {span} to {self} overflowed")
-                })?;
+                let sum =
+                    time_seconds.try_checked_add("span", span_seconds)?;
                 return Ok(Timestamp::from_second_ranged(sum));
             }
         }
         let time_nanos = self.as_nanosecond_ranged();
         let span_nanos = span.to_invariant_nanoseconds();
-        let sum = time_nanos
-            .try_checked_add("span", span_nanos)
-            .with_context(|| err!("adding {span} to {self} overflowed"))?;
+        let sum = time_nanos.try_checked_add("span", span_nanos)?;
         Ok(Timestamp::from_nanosecond_ranged(sum))
     }
 
+    /// Adds the given signed duration to this timestamp.
+    ///
+    /// Like `Timestamp::checked_add_span`, this doesn't attach any context
+    /// describing the operation to errors.
     #[inline]
-    fn checked_add_duration(
+    pub(crate) fn checked_add_duration(
         self,
         duration: SignedDuration,
     ) -> Result<Timestamp, Error> {
         let start = self.as_duration();
-        let end = start.checked_add(duration).ok_or_else(|| {
-            err!("overflow when adding {duration:?} to {self}")
-        })?;
+        let end = start
+            .checked_add(duration)
+            .ok_or_else(|| err!("timestamp arithmetic overflowed"))?;
         Timestamp::from_duration(end)
     }
 
-    /// This routine is identical to [`Timestamp::checked_add`] with the
-    /// duration negated.
+    /// Add the given span of time to this timestamp, where any calendar units
+    /// are resolved in the given time zone.
+    ///
+    /// Unlike [`Timestamp::checked_add`], this permits spans with non-zero
+    /// units of days or greater. Since the length of a day (and therefore
+    /// also of a week, month or year) depends on the time zone, the time zone
+    /// must be provided explicitly. This is equivalent to
+    /// `ts.to_zoned(tz.clone()).checked_add(duration)?.timestamp()`.
+    ///
+    /// Like [`Zoned::checked_add`], this operation accepts three different
+    /// duration types: [`Span`], [`SignedDuration`] or
+    /// [`std::time::Duration`]. This is achieved via `From` trait
+    /// implementations for the [`ZonedArithmetic`] type.
     ///
     /// # Errors
     ///
-    /// This has the same error conditions as [`Timestamp::checked_add`].
+    /// This has the same error conditions as [`Zoned::checked_add`]. In
+    /// particular, an error is returned if the sum would overflow the minimum
+    /// or maximum timestamp values.
     ///
     /// # Example
     ///
-    /// This routine can be used via the `-` operator. Note though that if it
-    /// fails, it will result in a panic.
+    /// This shows how adding 1 month to the same instant can produce
+    /// different instants depending on the time zone used. In New York, the
+    /// month between the two datetimes contains a transition into daylight
+    /// saving time, so it is one hour shorter than it is in UTC:
     ///
     /// ```
-    /// use jiff::{SignedDuration, Timestamp, ToSpan};
+    /// use jiff::{tz::TimeZone, Timestamp, ToSpan};
     ///
-    /// let ts1 = Timestamp::new(2_999_999_999, 0)?;
-    /// assert_eq!(ts1.to_string(), "2065-01-24T05:19:59Z");
+    /// let ts: Timestamp = "2024-02-15T12:00:00Z".parse()?;
     ///
-    /// let ts2 = ts1 - 1.hour().minutes(30).nanoseconds(123);
-    /// assert_eq!(ts2.to_string(), "2065-01-24T03:49:58.999999877Z");
+    /// let utc = ts.checked_add_in(1.month(), &TimeZone::UTC)?;
+    /// assert_eq!(utc.to_string(), "2024-03-15T12:00:00Z");
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// let nyc = ts.checked_add_in(1.month(), &tz)?;
+    /// assert_eq!(nyc.to_string(), "2024-03-15T11:00:00Z");
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    ///
+    #[inline]
+    pub fn checked_add_in<A: Into<ZonedArithmetic>>(
+        self,
+        duration: A,
+        tz: &TimeZone,
+    ) -> Result<Timestamp, Error> {
+        self.to_zoned(tz.clone())
+            .checked_add(duration)
+            .map(|zdt| zdt.timestamp())
+    }
+
+    /// This routine is identical to [`Timestamp::checked_add`] with the
+    /// duration negated.
+    ///
+    /// # Errors
+    ///
+    /// This has the same error conditions as [`Timestamp::checked_add`].
+    ///
+    /// # Example
+    ///
+    /// This routine can be used via the `-` operator. Note though that if it
+    /// fails, it will result in a panic.
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp, ToSpan};
+    ///
+    /// let ts1 = Timestamp::new(2_999_999_999, 0)?;
+    /// assert_eq!(ts1.to_string(), "2065-01-24T05:19:59Z");
+    ///
+    /// let ts2 = ts1 - 1.hour().minutes(30).nanoseconds(123);
+    /// assert_eq!(ts2.to_string(), "2065-01-24T03:49:58.999999877Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// # Example: use with [`SignedDuration`] and [`std::time::Duration`]
     ///
     /// ```
@@ -1600,7 +2159,15 @@ impl Timestamp {
         duration: A,
     ) -> Result<Timestamp, Error> {
         let duration: TimestampArithmetic = duration.into();
-        duration.checked_neg().and_then(|ta| ta.checked_add(self))
+        duration
+            .checked_neg()
+            .and_then(|ta| ta.checked_add(self))
+            .with_context(|| {
+                err!(
+                    "failed to subtract {duration} from {self}",
+                    duration = duration.duration,
+                )
+            })
     }
 
     /// This routine is identical to [`Timestamp::checked_add`], except the
@@ -2006,7 +2573,9 @@ impl Timestamp {
     /// # Errors
     ///
     /// This returns an error if the smallest unit configured on the given
-    /// [`TimestampRound`] is bigger than hours.
+    /// [`TimestampRound`] is bigger than hours. An error is also returned if
+    /// the rounded timestamp would fall outside of the range supported by
+    /// `Timestamp`.
     ///
     /// The rounding increment, when combined with the smallest unit (which
     /// defaults to [`Unit::Nanosecond`]), must divide evenly into `86,400`
@@ -2092,6 +2661,123 @@ impl Timestamp {
         options.round(self)
     }
 
+    /// Floors this timestamp to the given unit and returns the floored
+    /// timestamp along with the remaining duration that was truncated.
+    ///
+    /// This is useful for bucketing timestamps. The timestamp returned
+    /// corresponds to the start of the bucket that this timestamp falls into,
+    /// and the duration returned is how far into that bucket this timestamp
+    /// is. The duration returned is always non-negative, even for timestamps
+    /// before the Unix epoch, and adding it to the floored timestamp always
+    /// gives back the original timestamp.
+    ///
+    /// This is equivalent to rounding with [`RoundMode::Floor`] and then
+    /// computing the duration since the rounded timestamp via
+    /// [`Timestamp::duration_since`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given unit is bigger than hours, or if
+    /// flooring would result in a timestamp before [`Timestamp::MIN`]. See
+    /// [`Timestamp::round`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp, Unit};
+    ///
+    /// let ts: Timestamp = "2024-06-19T15:27:09.5Z".parse()?;
+    /// let (start, remainder) = ts.floor_with_remainder(Unit::Hour)?;
+    /// assert_eq!(start.to_string(), "2024-06-19T15:00:00Z");
+    /// assert_eq!(remainder, SignedDuration::new(27 * 60 + 9, 500_000_000));
+    /// assert_eq!(start + remainder, ts);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn floor_with_remainder(
+        self,
+        unit: Unit,
+    ) -> Result<(Timestamp, SignedDuration), Error> {
+        let floored = TimestampRound::new()
+            .smallest(unit)
+            .mode(RoundMode::Floor)
+            .round(self)?;
+        Ok((floored, self.duration_since(floored)))
+    }
+
+    /// Floors this timestamp to a grid of instants spaced `step` apart and
+    /// offset from the Unix epoch by `phase`.
+    ///
+    /// That is, this returns the latest timestamp of the form
+    /// `UNIX_EPOCH + phase + k * step` (for some integer `k`) that is less
+    /// than or equal to this timestamp. Unlike [`Timestamp::round`], the step
+    /// may be any positive duration and isn't limited to increments of a
+    /// single unit. This is useful for aligning samples to a fixed grid,
+    /// e.g., every 5 minutes starting 2 minutes past the hour.
+    ///
+    /// The phase may be negative or bigger than the step. Only its value
+    /// modulo the step matters.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if `step` is zero or negative, or if flooring
+    /// would result in a timestamp before [`Timestamp::MIN`].
+    ///
+    /// # Example
+    ///
+    /// This quantizes a timestamp to a 5 minute grid, where the grid points
+    /// are 2 minutes after a multiple of 5 minutes:
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp};
+    ///
+    /// let step = SignedDuration::from_mins(5);
+    /// let phase = SignedDuration::from_mins(2);
+    ///
+    /// let ts: Timestamp = "2024-06-19T15:26:59Z".parse()?;
+    /// assert_eq!(
+    ///     ts.quantize(step, phase)?.to_string(),
+    ///     "2024-06-19T15:22:00Z",
+    /// );
+    /// let ts: Timestamp = "2024-06-19T15:27:00Z".parse()?;
+    /// assert_eq!(
+    ///     ts.quantize(step, phase)?.to_string(),
+    ///     "2024-06-19T15:27:00Z",
+    /// );
+    ///
+    /// // A zero step is an error.
+    /// assert!(ts.quantize(SignedDuration::ZERO, phase).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn quantize(
+        self,
+        step: SignedDuration,
+        phase: SignedDuration,
+    ) -> Result<Timestamp, Error> {
+        let step_nanos = step.as_nanos();
+        if step_nanos <= 0 {
+            return Err(err!(
+                "quantizing timestamp {self} requires a positive step \
+                 duration, but got {step}",
+            ));
+        }
+        let phase_nanos = phase.as_nanos();
+        // None of this can overflow since timestamps and signed durations
+        // both fit into 96 bits of nanoseconds, and `grid * step_nanos` is
+        // always within one step of `self - phase`.
+        let grid = (self.as_nanosecond() - phase_nanos).div_euclid(step_nanos);
+        let nanos = grid * step_nanos + phase_nanos;
+        Timestamp::from_nanosecond(nanos).with_context(|| {
+            err!(
+                "quantizing timestamp {self} to a grid with step {step} \
+                 and phase {phase} resulted in a timestamp out of range",
+            )
+        })
+    }
+
     /// Return an iterator of periodic timestamps determined by the given span.
     ///
     /// The given span may be negative, in which case, the iterator will move
@@ -2134,6 +2820,59 @@ impl Timestamp {
     pub fn series(self, period: Span) -> TimestampSeries {
         TimestampSeries::new(self, period)
     }
+
+    /// Return an iterator that splits the half-open range `[self, end)` into
+    /// adjacent windows of the given duration.
+    ///
+    /// Each item yielded is a pair `(a, b)` corresponding to the half-open
+    /// window `[a, b)`. The first window starts at `self`, each subsequent
+    /// window starts where the previous one ended and the last window ends
+    /// at `end`. Every window is exactly `step` long, except for the last
+    /// window, which may be shorter.
+    ///
+    /// If `self` is greater than or equal to `end`, then the iterator
+    /// returned is empty.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given `step` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// This splits a 10 minute range into 3 minute windows:
+    ///
+    /// ```
+    /// use jiff::{SignedDuration, Timestamp};
+    ///
+    /// let start: Timestamp = "2024-07-01T00:00:00Z".parse()?;
+    /// let end: Timestamp = "2024-07-01T00:10:00Z".parse()?;
+    /// let windows: Vec<(String, String)> = start
+    ///     .chunks(end, SignedDuration::from_mins(3))?
+    ///     .map(|(a, b)| (a.to_string(), b.to_string()))
+    ///     .collect();
+    /// assert_eq!(windows, vec![
+    ///     ("2024-07-01T00:00:00Z".to_string(), "2024-07-01T00:03:00Z".to_string()),
+    ///     ("2024-07-01T00:03:00Z".to_string(), "2024-07-01T00:06:00Z".to_string()),
+    ///     ("2024-07-01T00:06:00Z".to_string(), "2024-07-01T00:09:00Z".to_string()),
+    ///     ("2024-07-01T00:09:00Z".to_string(), "2024-07-01T00:10:00Z".to_string()),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn chunks(
+        self,
+        end: Timestamp,
+        step: SignedDuration,
+    ) -> Result<TimestampChunks, Error> {
+        if !step.is_positive() {
+            return Err(err!(
+                "splitting a timestamp range into chunks requires a positive \
+                 step duration, but got {step:?}",
+            ));
+        }
+        Ok(TimestampChunks { start: self, end, step })
+    }
 }
 
 /// Parsing and formatting APIs.
@@ -2248,10 +2987,70 @@ impl Timestamp {
     ) -> TimestampDisplayWithOffset {
         TimestampDisplayWithOffset { timestamp: *self, offset }
     }
+
+    /// Writes this timestamp as an RFC 3339 string in Zulu time into the
+    /// given writer.
+    ///
+    /// This writes the same string as this type's `Display` implementation,
+    /// but without needing to go through `write!`. This never allocates, so
+    /// writing into a reused buffer avoids the allocation of a new `String`
+    /// that `to_string` incurs for every timestamp printed.
+    ///
+    /// # Errors
+    ///
+    /// This only returns an error when the given writer returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Timestamp;
+    ///
+    /// let mut buf = String::new();
+    /// for second in [0, 1_718_810_565] {
+    ///     buf.clear();
+    ///     Timestamp::from_second(second)?.format_into(&mut buf)?;
+    /// }
+    /// assert_eq!(buf, "2024-06-19T15:22:45Z");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format_into<W: core::fmt::Write>(
+        &self,
+        wtr: &mut W,
+    ) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        temporal::DateTimePrinter::new()
+            .print_timestamp(self, StdFmtWrite(wtr))
+            .map_err(|_| core::fmt::Error)
+    }
 }
 
 /// Internal APIs using Jiff ranged integers.
 impl Timestamp {
+    /// Returns the maximum of this timestamp and the one stored in `last`,
+    /// and records the result in `last`.
+    ///
+    /// `last` is a count of nanoseconds since the Unix epoch. If this
+    /// timestamp doesn't fit in it, then this timestamp is returned as-is
+    /// and `last` is not updated.
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    fn clamp_to_last(self, last: &std::sync::atomic::AtomicI64) -> Timestamp {
+        let Ok(nanos) = i64::try_from(self.as_nanosecond()) else {
+            return self;
+        };
+        // A single read-modify-write on one atomic is all we need: every
+        // call observes the values stored by prior calls in a single total
+        // order, so the results can never decrease.
+        let prev = last.fetch_max(nanos, std::sync::atomic::Ordering::Relaxed);
+        if prev <= nanos {
+            return self;
+        }
+        Timestamp::from_nanosecond(i128::from(prev))
+            .expect("previously returned timestamp is valid")
+    }
+
     #[inline]
     pub(crate) fn new_ranged(
         second: impl RInto<UnixSeconds>,
@@ -2819,6 +3618,112 @@ impl From<Timestamp> for std::time::SystemTime {
     }
 }
 
+/// Converts a number of seconds since the Unix epoch into a timestamp.
+///
+/// The integer is always interpreted as a number of _seconds_. If you have
+/// milliseconds, microseconds or nanoseconds, use
+/// [`Timestamp::from_millisecond`], [`Timestamp::from_microsecond`] or
+/// [`Timestamp::from_nanosecond`] instead.
+///
+/// This is equivalent to [`Timestamp::from_second`].
+///
+/// # Errors
+///
+/// This returns an error if the number of seconds is outside the range
+/// supported by `Timestamp`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let ts = Timestamp::try_from(1_700_000_000i64)?;
+/// assert_eq!(ts.to_string(), "2023-11-14T22:13:20Z");
+///
+/// assert!(Timestamp::try_from(i64::MAX).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl TryFrom<i64> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(second: i64) -> Result<Timestamp, Error> {
+        Timestamp::from_second(second)
+    }
+}
+
+/// Converts a number of seconds since the Unix epoch into a timestamp.
+///
+/// The integer is always interpreted as a number of _seconds_. See the
+/// `TryFrom<i64>` implementation for more details.
+///
+/// # Errors
+///
+/// This returns an error if the number of seconds is outside the range
+/// supported by `Timestamp`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let ts: Timestamp = 1_700_000_000u64.try_into()?;
+/// assert_eq!(ts.to_string(), "2023-11-14T22:13:20Z");
+///
+/// assert!(Timestamp::try_from(u64::MAX).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl TryFrom<u64> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(second: u64) -> Result<Timestamp, Error> {
+        Timestamp::try_from(i128::from(second))
+    }
+}
+
+/// Converts a number of seconds since the Unix epoch into a timestamp.
+///
+/// The integer is always interpreted as a number of _seconds_. See the
+/// `TryFrom<i64>` implementation for more details. To convert from a number
+/// of nanoseconds, use [`Timestamp::from_nanosecond`] instead.
+///
+/// # Errors
+///
+/// This returns an error if the number of seconds is outside the range
+/// supported by `Timestamp`.
+///
+/// # Example
+///
+/// ```
+/// use jiff::Timestamp;
+///
+/// let ts = Timestamp::try_from(-1i128)?;
+/// assert_eq!(ts.to_string(), "1969-12-31T23:59:59Z");
+///
+/// assert!(Timestamp::try_from(i128::MAX).is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl TryFrom<i128> for Timestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(second: i128) -> Result<Timestamp, Error> {
+        let second = i64::try_from(second).map_err(|_| {
+            Error::range(
+                "second",
+                second,
+                UnixSeconds::MIN_REPR,
+                UnixSeconds::MAX_REPR,
+            )
+        })?;
+        Timestamp::from_second(second)
+    }
+}
+
 #[cfg(feature = "std")]
 impl TryFrom<std::time::SystemTime> for Timestamp {
     type Error = Error;
@@ -2887,6 +3792,86 @@ impl<'de> serde::Deserialize<'de> for Timestamp {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Timestamp> {
+        let second =
+            u.int_in_range(UnixSeconds::MIN_REPR..=UnixSeconds::MAX_REPR)?;
+        let nanosecond = u.int_in_range(0..=999_999_999)?;
+        Timestamp::new(second, nanosecond)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(i64, i32) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Tests that values generated by the `Arbitrary` impls of Jiff's datetime
+/// types roundtrip through their `Display` and `FromStr` impls.
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{
+        civil::{Date, DateTime, Time},
+        Span, Timestamp, Zoned,
+    };
+
+    /// Returns deterministic bytes, derived from the given seed, for use
+    /// with `arbitrary::Unstructured`.
+    fn data(mut seed: u64) -> Vec<u8> {
+        // This is splitmix64.
+        let mut data = Vec::new();
+        for _ in 0..32 {
+            seed = seed.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            data.extend_from_slice(&(z ^ (z >> 31)).to_le_bytes());
+        }
+        data
+    }
+
+    /// Asserts that printing many arbitrary values of type `T`, parsing
+    /// them and printing them again gives back the same string.
+    ///
+    /// This compares what's printed instead of the values themselves. For
+    /// example, sub-second units of a `Span` are printed as a fractional
+    /// number of seconds, and offsets of a `Zoned` with a non-zero seconds
+    /// component are printed rounded to the nearest minute.
+    fn roundtrip<T>()
+    where
+        T: for<'a> Arbitrary<'a>
+            + core::fmt::Display
+            + core::str::FromStr<Err = crate::Error>,
+    {
+        for seed in 0..1_000 {
+            let data = data(seed);
+            let value = T::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let printed = value.to_string();
+            let got: T = printed.parse().unwrap();
+            assert_eq!(printed, got.to_string(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn arbitrary_roundtrip() {
+        roundtrip::<Date>();
+        roundtrip::<Time>();
+        roundtrip::<DateTime>();
+        roundtrip::<Timestamp>();
+        roundtrip::<Span>();
+        if !crate::tz::db().is_definitively_empty() {
+            roundtrip::<Zoned>();
+        }
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Timestamp {
     fn arbitrary(g: &mut quickcheck::Gen) -> Timestamp {
@@ -3010,6 +3995,98 @@ impl Iterator for TimestampSeries {
     }
 }
 
+/// An iterator over adjacent windows of a timestamp range, created by
+/// [`Timestamp::chunks`].
+///
+/// Each item is a pair of timestamps corresponding to a half-open window.
+/// It is exhausted when the end of the range is reached.
+#[derive(Clone, Debug)]
+pub struct TimestampChunks {
+    start: Timestamp,
+    end: Timestamp,
+    step: SignedDuration,
+}
+
+impl Iterator for TimestampChunks {
+    type Item = (Timestamp, Timestamp);
+
+    #[inline]
+    fn next(&mut self) -> Option<(Timestamp, Timestamp)> {
+        if self.start >= self.end {
+            return None;
+        }
+        let start = self.start;
+        // If adding the step overflows, then it necessarily goes past the
+        // end of the range. So we just clamp to the end.
+        let end = start
+            .checked_add_duration(self.step)
+            .map_or(self.end, |next| next.min(self.end));
+        self.start = end;
+        Some((start, end))
+    }
+}
+
+impl core::iter::FusedIterator for TimestampChunks {}
+
+/// Determines whether the boundaries of a range are included in it.
+///
+/// This is used by [`Timestamp::is_between`] and [`Zoned::is_between`]. Each
+/// variant documents its corresponding interval notation, where `[` and `]`
+/// indicate an inclusive boundary and `(` and `)` indicate an exclusive
+/// boundary.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{Inclusivity, Timestamp};
+///
+/// let start = Timestamp::from_second(10)?;
+/// let end = Timestamp::from_second(20)?;
+///
+/// assert!(start.is_between(start, end, Inclusivity::Closed));
+/// assert!(start.is_between(start, end, Inclusivity::ClosedOpen));
+/// assert!(!start.is_between(start, end, Inclusivity::OpenClosed));
+/// assert!(!start.is_between(start, end, Inclusivity::Open));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Inclusivity {
+    /// Both boundaries are included: `[start, end]`.
+    Closed,
+    /// The start is included but the end is excluded: `[start, end)`.
+    ///
+    /// This is usually the right choice for half-open windows of time, since
+    /// adjacent windows never overlap.
+    ClosedOpen,
+    /// The start is excluded but the end is included: `(start, end]`.
+    OpenClosed,
+    /// Both boundaries are excluded: `(start, end)`.
+    Open,
+}
+
+impl Inclusivity {
+    /// Returns true if `value` is in the range `start..end` according to
+    /// this inclusivity.
+    #[inline]
+    pub(crate) fn contains<T: PartialOrd>(
+        self,
+        start: T,
+        end: T,
+        value: T,
+    ) -> bool {
+        let after_start = match self {
+            Inclusivity::Closed | Inclusivity::ClosedOpen => start <= value,
+            Inclusivity::OpenClosed | Inclusivity::Open => start < value,
+        };
+        let before_end = match self {
+            Inclusivity::Closed | Inclusivity::OpenClosed => value <= end,
+            Inclusivity::ClosedOpen | Inclusivity::Open => value < end,
+        };
+        after_start && before_end
+    }
+}
+
 /// Options for [`Timestamp::checked_add`] and [`Timestamp::checked_sub`].
 ///
 /// This type provides a way to ergonomically add one of a few different
@@ -3654,7 +4731,7 @@ impl TimestampRound {
             self.smallest,
             increment,
         );
-        let nanosecond = UnixNanoseconds::rfrom(rounded);
+        let nanosecond = UnixNanoseconds::try_rfrom("nanosecond", rounded)?;
         Ok(Timestamp::from_nanosecond_ranged(nanosecond))
     }
 }
@@ -3680,6 +4757,56 @@ impl From<(Unit, i64)> for TimestampRound {
     }
 }
 
+/// Splits a leading `+` or `-` sign off of the given string.
+///
+/// The sign returned is `-1` when the string starts with `-`, and `1`
+/// otherwise.
+fn split_epoch_sign(s: &str) -> (i64, &[u8]) {
+    let bytes = s.as_bytes();
+    match bytes.first() {
+        Some(&b'-') => (-1, &bytes[1..]),
+        Some(&b'+') => (1, &bytes[1..]),
+        _ => (1, bytes),
+    }
+}
+
+/// Builds a timestamp from the digits of an integer number of the given unit
+/// since the Unix epoch.
+///
+/// The digits are split into whole seconds and a fraction of a second before
+/// parsing. This means every `Timestamp` can be represented, even in units
+/// of nanoseconds, without needing a 128-bit integer.
+fn from_epoch_digits(
+    sign: i64,
+    digits: &[u8],
+    unit: Unit,
+) -> Result<Timestamp, Error> {
+    let (scale, multiplier) = match unit {
+        Unit::Second => (0, 1_000_000_000),
+        Unit::Millisecond => (3, 1_000_000),
+        Unit::Microsecond => (6, 1_000),
+        Unit::Nanosecond => (9, 1),
+        _ => {
+            return Err(err!(
+                "unit {unit} is not supported, expected one of \
+                 seconds, milliseconds, microseconds or nanoseconds",
+                unit = unit.plural(),
+            ))
+        }
+    };
+    if digits.is_empty() {
+        return Err(err!("invalid number, no digits found"));
+    }
+    let (second, fraction) =
+        digits.split_at(digits.len().saturating_sub(scale));
+    let second = if second.is_empty() { 0 } else { parse::i64(second)? };
+    let fraction = if fraction.is_empty() { 0 } else { parse::i64(fraction)? };
+    // OK because `fraction` has at most `scale` digits, and thus, this is
+    // always less than one second.
+    let nanosecond = i32::try_from(fraction * multiplier).unwrap();
+    Timestamp::new(sign * second, i32::try_from(sign).unwrap() * nanosecond)
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -3688,6 +4815,7 @@ mod tests {
 
     use crate::{
         civil::{self, datetime},
+        span::span_eq,
         tz::Offset,
         ToSpan,
     };
@@ -3830,6 +4958,36 @@ mod tests {
         assert_eq!(inst, got);
     }
 
+    #[test]
+    fn from_nanosecond_boundaries() {
+        let ts = Timestamp::from_nanosecond(0).unwrap();
+        assert_eq!(ts, Timestamp::UNIX_EPOCH);
+        assert_eq!(ts.as_nanosecond(), 0);
+
+        let ts = Timestamp::from_nanosecond(-1_500_000_001).unwrap();
+        assert_eq!(ts, Timestamp::new(-2, 499_999_999).unwrap());
+        assert_eq!(ts.as_second(), -1);
+        assert_eq!(ts.subsec_nanosecond(), -500_000_001);
+        assert_eq!(ts.as_nanosecond(), -1_500_000_001);
+
+        let min = Timestamp::MIN.as_nanosecond();
+        assert_eq!(Timestamp::from_nanosecond(min).unwrap(), Timestamp::MIN);
+        insta::assert_snapshot!(
+            Timestamp::from_nanosecond(min - 1).unwrap_err(),
+            @"parameter 'nanosecond timestamp' with value -377705023201000000001 is not in the required range of -377705023201000000000..=253402207200999999999",
+        );
+
+        let max = Timestamp::MAX.as_nanosecond();
+        assert_eq!(Timestamp::from_nanosecond(max).unwrap(), Timestamp::MAX);
+        insta::assert_snapshot!(
+            Timestamp::from_nanosecond(max + 1).unwrap_err(),
+            @"parameter 'nanosecond timestamp' with value 253402207201000000000 is not in the required range of -377705023201000000000..=253402207200999999999",
+        );
+
+        assert!(Timestamp::from_nanosecond(i128::MIN).is_err());
+        assert!(Timestamp::from_nanosecond(i128::MAX).is_err());
+    }
+
     #[test]
     fn timestamp_saturating_add() {
         insta::assert_snapshot!(
@@ -3926,4 +5084,593 @@ mod tests {
         assert_eq!(ts2.to_string(), "2025-01-25T18:32:22.783444592Z");
         assert_eq!(ts1, ts2 - span, "should be reversible");
     }
+
+    #[test]
+    fn timestamp_parse_separators() {
+        let expected = mktime(1627680004, 0);
+        for input in [
+            "2021-07-30T21:20:04Z",
+            "2021-07-30t21:20:04Z",
+            "2021-07-30 21:20:04Z",
+            "2021-07-30 21:20:04z",
+            "2021-07-30 23:20:04+02:00",
+        ] {
+            let got: Timestamp = input.parse().unwrap();
+            assert_eq!(got, expected, "input: {input:?}");
+        }
+        for input in [
+            "2021-07-30  21:20:04Z",
+            "2021-07-30_21:20:04Z",
+            "2021-07-30\t21:20:04Z",
+        ] {
+            assert!(input.parse::<Timestamp>().is_err(), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn timestamp_min_max_reduce() {
+        let timestamps = alloc::vec![
+            mktime(5, 0),
+            mktime(-10, 0),
+            mktime(5, 1),
+            mktime(0, 999_999_999),
+        ];
+        let max = timestamps.iter().copied().reduce(Timestamp::max);
+        assert_eq!(max, Some(mktime(5, 1)));
+        let min = timestamps.iter().copied().reduce(Timestamp::min);
+        assert_eq!(min, Some(mktime(-10, 0)));
+
+        let empty: alloc::vec::Vec<Timestamp> = alloc::vec![];
+        assert_eq!(empty.iter().copied().reduce(Timestamp::max), None);
+    }
+
+    #[test]
+    fn timestamp_from_epoch_str() {
+        let parse = |s, unit| Timestamp::from_epoch_str(s, unit).unwrap();
+
+        let ts = parse("1627680004", Unit::Second);
+        assert_eq!(ts, Timestamp::from_second(1627680004).unwrap());
+        let ts = parse("+1627680004", Unit::Second);
+        assert_eq!(ts, Timestamp::from_second(1627680004).unwrap());
+        let ts = parse("-1627680004", Unit::Second);
+        assert_eq!(ts, Timestamp::from_second(-1627680004).unwrap());
+
+        let ts = parse("1627680004123", Unit::Millisecond);
+        assert_eq!(ts, Timestamp::from_millisecond(1627680004123).unwrap());
+        let ts = parse("-1", Unit::Millisecond);
+        assert_eq!(ts, Timestamp::from_millisecond(-1).unwrap());
+
+        let ts = parse("1627680004123456", Unit::Microsecond);
+        assert_eq!(ts, Timestamp::from_microsecond(1627680004123456).unwrap(),);
+        let ts = parse("000000001", Unit::Microsecond);
+        assert_eq!(ts, Timestamp::from_microsecond(1).unwrap());
+
+        let ts = parse("1627680004123456789", Unit::Nanosecond);
+        assert_eq!(
+            ts,
+            Timestamp::from_nanosecond(1627680004123456789).unwrap(),
+        );
+        // Bigger than `i64::MAX`.
+        let ts = parse("253402207200999999999", Unit::Nanosecond);
+        assert_eq!(ts, Timestamp::MAX);
+        let ts = parse("-377705023201000000000", Unit::Nanosecond);
+        assert_eq!(ts, Timestamp::MIN);
+
+        let parse = |s, unit| Timestamp::from_epoch_str(s, unit);
+        assert!(parse("", Unit::Second).is_err());
+        assert!(parse("-", Unit::Second).is_err());
+        assert!(parse("--1", Unit::Second).is_err());
+        assert!(parse(" 1", Unit::Second).is_err());
+        assert!(parse("1.5", Unit::Second).is_err());
+        assert!(parse("253402207201", Unit::Second).is_err());
+        assert!(parse("1", Unit::Minute).is_err());
+        assert!(parse("1", Unit::Day).is_err());
+
+        insta::assert_snapshot!(
+            parse("1x", Unit::Second).unwrap_err(),
+            @"failed to parse \"1x\" as Unix timestamp in seconds: invalid digit, expected 0-9 but got x",
+        );
+        insta::assert_snapshot!(
+            parse("1", Unit::Hour).unwrap_err(),
+            @"failed to parse \"1\" as Unix timestamp in hours: unit hours is not supported, expected one of seconds, milliseconds, microseconds or nanoseconds",
+        );
+    }
+
+    #[test]
+    fn timestamp_from_epoch_str_auto() {
+        let parse = |s| Timestamp::from_epoch_str_auto(s).unwrap();
+
+        assert_eq!(parse("0"), Timestamp::UNIX_EPOCH);
+        assert_eq!(
+            parse("1627680004"),
+            Timestamp::from_second(1627680004).unwrap(),
+        );
+        assert_eq!(
+            parse("-1627680004"),
+            Timestamp::from_second(-1627680004).unwrap(),
+        );
+        assert_eq!(
+            parse("1627680004123"),
+            Timestamp::from_millisecond(1627680004123).unwrap(),
+        );
+        assert_eq!(
+            parse("1627680004123456"),
+            Timestamp::from_microsecond(1627680004123456).unwrap(),
+        );
+        assert_eq!(
+            parse("1627680004123456789"),
+            Timestamp::from_nanosecond(1627680004123456789).unwrap(),
+        );
+        assert!(Timestamp::from_epoch_str_auto("").is_err());
+    }
+
+    #[test]
+    fn timestamp_civil_roundtrip_exact() {
+        let timestamps = [
+            Timestamp::MIN,
+            mktime(-1, -1),
+            mktime(0, 1),
+            mktime(3600, 500_000_000),
+            mktime(1_719_755_160, 123_456_789),
+            mktime(1_719_755_160, 999_999_999),
+            Timestamp::MAX,
+        ];
+        let offsets = [
+            Offset::MIN,
+            Offset::constant(-5),
+            Offset::UTC,
+            Offset::from_seconds(5 * 60 * 60 + 30 * 60 + 15).unwrap(),
+            Offset::MAX,
+        ];
+        for ts in timestamps {
+            for offset in offsets {
+                let dt = offset.to_datetime(ts);
+                assert_eq!(
+                    dt.subsec_nanosecond(),
+                    ts.subsec_nanosecond().rem_euclid(1_000_000_000),
+                    "{ts} with offset {offset}",
+                );
+                let got = offset.to_timestamp(dt).unwrap();
+                assert_eq!(ts, got, "{ts} with offset {offset}");
+            }
+        }
+    }
+
+    #[test]
+    fn timestamp_since_largest_unit() {
+        let ts1: Timestamp = "2024-06-19T15:00:00Z".parse().unwrap();
+        let ts2: Timestamp = "2024-06-21T17:30:15.5Z".parse().unwrap();
+
+        let span = ts2.since(ts1).unwrap();
+        span_eq!(span, 181_815.seconds().milliseconds(500));
+        let span = ts2.since((Unit::Minute, ts1)).unwrap();
+        span_eq!(span, 3030.minutes().seconds(15).milliseconds(500));
+        let span = ts2.since((Unit::Hour, ts1)).unwrap();
+        span_eq!(span, 50.hours().minutes(30).seconds(15).milliseconds(500));
+        let span = ts1.since((Unit::Hour, ts2)).unwrap();
+        span_eq!(span, -50.hours().minutes(30).seconds(15).milliseconds(500));
+
+        // Timestamps have no calendar, so units bigger than hours are
+        // rejected.
+        assert!(ts2.since((Unit::Day, ts1)).is_err());
+        assert!(ts2.since((Unit::Month, ts1)).is_err());
+        assert!(ts2.until((Unit::Year, ts1)).is_err());
+    }
+
+    #[test]
+    fn timestamp_floor_with_remainder() {
+        let ts: Timestamp = "2024-06-19T15:27:09.123Z".parse().unwrap();
+        let (start, rem) = ts.floor_with_remainder(Unit::Hour).unwrap();
+        assert_eq!(start, "2024-06-19T15:00:00Z".parse().unwrap());
+        assert_eq!(rem, SignedDuration::new(27 * 60 + 9, 123_000_000));
+        assert_eq!(start + rem, ts);
+
+        let (start, rem) = ts.floor_with_remainder(Unit::Minute).unwrap();
+        assert_eq!(start, "2024-06-19T15:27:00Z".parse().unwrap());
+        assert_eq!(rem, SignedDuration::new(9, 123_000_000));
+
+        let (start, rem) = ts.floor_with_remainder(Unit::Nanosecond).unwrap();
+        assert_eq!(start, ts);
+        assert_eq!(rem, SignedDuration::ZERO);
+
+        // Timestamps before the Unix epoch still floor toward the past and
+        // have a non-negative remainder.
+        let ts: Timestamp = "1969-12-31T23:59:30Z".parse().unwrap();
+        let (start, rem) = ts.floor_with_remainder(Unit::Hour).unwrap();
+        assert_eq!(start, "1969-12-31T23:00:00Z".parse().unwrap());
+        assert_eq!(rem, SignedDuration::from_secs(59 * 60 + 30));
+
+        let (start, rem) =
+            Timestamp::MIN.floor_with_remainder(Unit::Second).unwrap();
+        assert_eq!(start, Timestamp::MIN);
+        assert_eq!(rem, SignedDuration::ZERO);
+        assert!(Timestamp::MIN.floor_with_remainder(Unit::Hour).is_err());
+
+        assert!(ts.floor_with_remainder(Unit::Day).is_err());
+        assert!(ts.floor_with_remainder(Unit::Year).is_err());
+    }
+
+    #[test]
+    fn timestamp_as_float() {
+        let ts = Timestamp::UNIX_EPOCH;
+        assert_eq!(ts.as_second_f64(), 0.0);
+        assert_eq!(ts.as_millisecond_f64(), 0.0);
+
+        let ts = Timestamp::new(1_500, 250_000_000).unwrap();
+        assert_eq!(ts.as_second_f64(), 1_500.25);
+        assert_eq!(ts.as_millisecond_f64(), 1_500_250.0);
+
+        // Before the Unix epoch, the fractional part has the same sign as
+        // the whole seconds.
+        let ts = Timestamp::new(-1, -500_000_000).unwrap();
+        assert_eq!(ts.as_second_f64(), -1.5);
+        assert_eq!(ts.as_millisecond_f64(), -1_500.0);
+        let ts: Timestamp = "1969-12-31T23:59:59.75Z".parse().unwrap();
+        assert_eq!(ts.as_second_f64(), -0.25);
+        assert_eq!(ts.as_millisecond_f64(), -250.0);
+
+        let ts = Timestamp::new(1_700_000_000, 500_000).unwrap();
+        assert_eq!(ts.as_millisecond_f64(), 1_700_000_000_000.5);
+    }
+
+    #[test]
+    fn timestamp_checked_add_signed_duration() {
+        let ts: Timestamp = "2024-06-19T15:22:45Z".parse().unwrap();
+
+        let got = ts.checked_add(SignedDuration::from_secs(90)).unwrap();
+        assert_eq!(got, "2024-06-19T15:24:15Z".parse().unwrap());
+        let got = ts.checked_add(SignedDuration::new(-90, -500)).unwrap();
+        assert_eq!(got, "2024-06-19T15:21:14.9999995Z".parse().unwrap());
+        assert_eq!(
+            ts.checked_sub(SignedDuration::from_secs(90)).unwrap(),
+            ts.checked_add(SignedDuration::from_secs(-90)).unwrap(),
+        );
+
+        let one_ns = SignedDuration::from_nanos(1);
+        assert!(Timestamp::MAX.checked_add(one_ns).is_err());
+        assert!(Timestamp::MIN.checked_add(-one_ns).is_err());
+        assert!(ts.checked_add(SignedDuration::MAX).is_err());
+        assert!(ts.checked_add(SignedDuration::MIN).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn timestamp_now_truncated() {
+        let ts = Timestamp::now_truncated(Unit::Second).unwrap();
+        assert_eq!(ts.subsec_nanosecond(), 0);
+        let ts = Timestamp::now_truncated(Unit::Millisecond).unwrap();
+        assert_eq!(ts.subsec_microsecond() % 1_000, 0);
+        assert_eq!(ts.subsec_nanosecond() % 1_000_000, 0);
+        let ts = Timestamp::now_truncated(Unit::Microsecond).unwrap();
+        assert_eq!(ts.subsec_nanosecond() % 1_000, 0);
+        assert!(Timestamp::now_truncated(Unit::Nanosecond).is_ok());
+
+        insta::assert_snapshot!(
+            Timestamp::now_truncated(Unit::Minute).unwrap_err(),
+            @"truncating the current time to minutes is not supported, expected seconds or a smaller unit",
+        );
+        assert!(Timestamp::now_truncated(Unit::Year).is_err());
+    }
+
+    #[test]
+    fn timestamp_is_between() {
+        let start = Timestamp::new(10, 0).unwrap();
+        let end = Timestamp::new(20, 0).unwrap();
+        let just_after_start = Timestamp::new(10, 1).unwrap();
+        let just_before_end = Timestamp::new(19, 999_999_999).unwrap();
+
+        let tests = [
+            (Inclusivity::Closed, true, true),
+            (Inclusivity::ClosedOpen, true, false),
+            (Inclusivity::OpenClosed, false, true),
+            (Inclusivity::Open, false, false),
+        ];
+        for (inclusivity, has_start, has_end) in tests {
+            assert_eq!(
+                start.is_between(start, end, inclusivity),
+                has_start,
+                "{inclusivity:?}",
+            );
+            assert_eq!(
+                end.is_between(start, end, inclusivity),
+                has_end,
+                "{inclusivity:?}",
+            );
+            assert!(just_after_start.is_between(start, end, inclusivity));
+            assert!(just_before_end.is_between(start, end, inclusivity));
+            assert!(!Timestamp::MIN.is_between(start, end, inclusivity));
+            assert!(!Timestamp::MAX.is_between(start, end, inclusivity));
+            // An inverted range is always empty.
+            assert!(!just_after_start.is_between(end, start, inclusivity));
+        }
+
+        // A degenerate range containing a single instant.
+        assert!(start.is_between(start, start, Inclusivity::Closed));
+        assert!(!start.is_between(start, start, Inclusivity::ClosedOpen));
+        assert!(!start.is_between(start, start, Inclusivity::OpenClosed));
+        assert!(!start.is_between(start, start, Inclusivity::Open));
+    }
+
+    #[test]
+    fn timestamp_arithmetic_overflow_error() {
+        let max = Timestamp::MAX;
+        let min = Timestamp::MIN;
+
+        insta::assert_snapshot!(
+            max.checked_add(1.second()).unwrap_err(),
+            @"failed to add PT1S to 9999-12-30T22:00:00.999999999Z: parameter 'span' with value 1000000000 is not in the required range of -377705023201000000000..=253402207200999999999",
+        );
+        insta::assert_snapshot!(
+            max.checked_add(SignedDuration::from_secs(1)).unwrap_err(),
+            @"failed to add PT1S to 9999-12-30T22:00:00.999999999Z: parameter 'second' with value 253402207201 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            max.checked_add(UnsignedDuration::from_secs(1)).unwrap_err(),
+            @"failed to add PT1S to 9999-12-30T22:00:00.999999999Z: parameter 'second' with value 253402207201 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            min.checked_sub(1.second()).unwrap_err(),
+            @"failed to subtract PT1S from -009999-01-02T01:59:59Z: parameter 'span' with value -1 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            min.checked_sub(SignedDuration::from_secs(1)).unwrap_err(),
+            @"failed to subtract PT1S from -009999-01-02T01:59:59Z: parameter 'second' with value -377705023202 is not in the required range of -377705023201..=253402207200",
+        );
+    }
+
+    #[test]
+    fn timestamp_quantize() {
+        let step = SignedDuration::from_mins(5);
+        let phase = SignedDuration::from_mins(2);
+        let ts = |secs| Timestamp::from_second(secs).unwrap();
+
+        assert_eq!(ts(0).quantize(step, phase).unwrap(), ts(-180));
+        assert_eq!(ts(119).quantize(step, phase).unwrap(), ts(-180));
+        assert_eq!(ts(120).quantize(step, phase).unwrap(), ts(120));
+        assert_eq!(ts(419).quantize(step, phase).unwrap(), ts(120));
+        assert_eq!(ts(420).quantize(step, phase).unwrap(), ts(420));
+        assert_eq!(ts(-181).quantize(step, phase).unwrap(), ts(-480));
+        assert_eq!(
+            Timestamp::new(419, 999_999_999)
+                .unwrap()
+                .quantize(step, phase)
+                .unwrap(),
+            ts(120),
+        );
+
+        // Only the phase modulo the step matters.
+        for phase in [-3, 7, 302, -298] {
+            let phase = SignedDuration::from_mins(phase);
+            assert_eq!(ts(419).quantize(step, phase).unwrap(), ts(120));
+        }
+
+        insta::assert_snapshot!(
+            ts(0).quantize(SignedDuration::ZERO, phase).unwrap_err(),
+            @"quantizing timestamp 1970-01-01T00:00:00Z requires a positive step duration, but got PT0S",
+        );
+        insta::assert_snapshot!(
+            ts(0).quantize(-step, phase).unwrap_err(),
+            @"quantizing timestamp 1970-01-01T00:00:00Z requires a positive step duration, but got -PT5M",
+        );
+        insta::assert_snapshot!(
+            Timestamp::MIN.quantize(step, phase).unwrap_err(),
+            @"quantizing timestamp -009999-01-02T01:59:59Z to a grid with step PT5M and phase PT2M resulted in a timestamp out of range: parameter 'nanosecond timestamp' with value -377705023380000000000 is not in the required range of -377705023201000000000..=253402207200999999999",
+        );
+        assert_eq!(
+            Timestamp::MAX
+                .quantize(SignedDuration::from_nanos(1), phase)
+                .unwrap(),
+            Timestamp::MAX,
+        );
+    }
+
+    #[test]
+    fn timestamp_epoch_sign() {
+        let tests = [
+            (Timestamp::MIN, -1),
+            (Timestamp::new(-1, 0).unwrap(), -1),
+            (Timestamp::new(0, -1).unwrap(), -1),
+            (Timestamp::UNIX_EPOCH, 0),
+            (Timestamp::new(0, 1).unwrap(), 1),
+            (Timestamp::new(1, 0).unwrap(), 1),
+            (Timestamp::MAX, 1),
+        ];
+        for (ts, sign) in tests {
+            assert_eq!(ts.signum(), sign, "{ts:?}");
+            assert_eq!(ts.is_before_epoch(), sign < 0, "{ts:?}");
+            assert_eq!(ts.is_after_epoch(), sign > 0, "{ts:?}");
+            assert_eq!(ts.is_before_epoch(), ts < Timestamp::UNIX_EPOCH);
+            assert_eq!(ts.is_after_epoch(), ts > Timestamp::UNIX_EPOCH);
+        }
+    }
+
+    #[test]
+    fn timestamp_checked_add_in() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let ts: Timestamp = "2024-02-15T12:00:00Z".parse().unwrap();
+        // Calendar units are rejected without a time zone.
+        assert!(ts.checked_add(1.month()).is_err());
+
+        let utc = ts.checked_add_in(1.month(), &TimeZone::UTC).unwrap();
+        assert_eq!(utc.to_string(), "2024-03-15T12:00:00Z");
+
+        // The month from February to March in New York is one hour shorter
+        // because of the transition into DST.
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let nyc = ts.checked_add_in(1.month(), &tz).unwrap();
+        assert_eq!(nyc.to_string(), "2024-03-15T11:00:00Z");
+        assert_eq!(utc.duration_since(nyc), SignedDuration::from_hours(1));
+
+        // Outside of a month containing a transition, they agree.
+        let ts: Timestamp = "2024-06-15T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            ts.checked_add_in(1.month(), &TimeZone::UTC).unwrap(),
+            ts.checked_add_in(1.month(), &tz).unwrap(),
+        );
+
+        assert!(Timestamp::MAX
+            .checked_add_in(1.day(), &TimeZone::UTC)
+            .is_err());
+    }
+
+    #[test]
+    fn timestamp_chunks() {
+        let start = Timestamp::new(1_000, 0).unwrap();
+        let end = Timestamp::new(1_600, 0).unwrap();
+        let step = SignedDuration::from_mins(3);
+
+        let windows: alloc::vec::Vec<(Timestamp, Timestamp)> =
+            start.chunks(end, step).unwrap().collect();
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].0, start);
+        assert_eq!(windows[3].1, end);
+        // Windows are contiguous.
+        for pair in windows.windows(2) {
+            assert_eq!(pair[0].1, pair[1].0);
+        }
+        // Every window but the last is exactly one step long.
+        for &(a, b) in &windows[..3] {
+            assert_eq!(b.duration_since(a), step);
+        }
+        assert_eq!(
+            windows[3].1.duration_since(windows[3].0),
+            SignedDuration::from_mins(1),
+        );
+
+        // An exact multiple doesn't produce an empty trailing window.
+        let end = Timestamp::new(1_360, 0).unwrap();
+        assert_eq!(start.chunks(end, step).unwrap().count(), 2);
+        // The iterator is fused.
+        let mut it = start.chunks(end, step).unwrap();
+        assert_eq!(it.by_ref().count(), 2);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+        // Empty and inverted ranges produce nothing.
+        assert_eq!(start.chunks(start, step).unwrap().count(), 0);
+        assert_eq!(end.chunks(start, step).unwrap().count(), 0);
+        // Overflow clamps the last window to the end of the range.
+        let last = Timestamp::MIN
+            .chunks(Timestamp::MAX, SignedDuration::MAX)
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(last.1, Timestamp::MAX);
+
+        insta::assert_snapshot!(
+            start.chunks(end, SignedDuration::ZERO).unwrap_err(),
+            @"splitting a timestamp range into chunks requires a positive step duration, but got 0s",
+        );
+        insta::assert_snapshot!(
+            start.chunks(end, -step).unwrap_err(),
+            @"splitting a timestamp range into chunks requires a positive step duration, but got 3m ago",
+        );
+    }
+
+    #[test]
+    fn timestamp_try_from_integer() {
+        let expected = Timestamp::from_second(1_700_000_000).unwrap();
+        assert_eq!(Timestamp::try_from(1_700_000_000i64).unwrap(), expected);
+        assert_eq!(Timestamp::try_from(1_700_000_000u64).unwrap(), expected);
+        assert_eq!(Timestamp::try_from(1_700_000_000i128).unwrap(), expected);
+        let ts: Timestamp = (-1i64).try_into().unwrap();
+        assert_eq!(ts, Timestamp::from_second(-1).unwrap());
+
+        let max = UnixSeconds::MAX_REPR;
+        assert_eq!(Timestamp::try_from(max).unwrap().as_second(), max);
+        assert_eq!(Timestamp::try_from(max as u64).unwrap().as_second(), max,);
+        assert_eq!(
+            Timestamp::try_from(i128::from(max)).unwrap().as_second(),
+            max,
+        );
+        let min = UnixSeconds::MIN_REPR;
+        assert_eq!(
+            Timestamp::try_from(i128::from(min)).unwrap().as_second(),
+            min,
+        );
+
+        assert!(Timestamp::try_from(max + 1).is_err());
+        assert!(Timestamp::try_from(min - 1).is_err());
+        assert!(Timestamp::try_from(max as u64 + 1).is_err());
+        insta::assert_snapshot!(
+            Timestamp::try_from(u64::MAX).unwrap_err(),
+            @"parameter 'second' with value 18446744073709551615 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            Timestamp::try_from(i128::MIN).unwrap_err(),
+            @"parameter 'second' with value -170141183460469231731687303715884105728 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            Timestamp::try_from(i64::MAX).unwrap_err(),
+            @"parameter 'second' with value 9223372036854775807 is not in the required range of -377705023201..=253402207200",
+        );
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    #[test]
+    fn timestamp_clamp_to_last() {
+        use std::sync::atomic::AtomicI64;
+
+        let last = AtomicI64::new(i64::MIN);
+        let ts = |second| Timestamp::from_second(second).unwrap();
+
+        // The injected clock jumps backwards twice before catching up.
+        let clock = [100, 101, 95, 99, 101, 102, 50, 103];
+        let got: alloc::vec::Vec<i64> = clock
+            .into_iter()
+            .map(|second| ts(second).clamp_to_last(&last).as_second())
+            .collect();
+        assert_eq!(got, [100, 101, 101, 101, 101, 102, 102, 103]);
+        assert!(got.windows(2).all(|w| w[0] <= w[1]));
+
+        // Timestamps that don't fit in the atomic are passed through.
+        assert_eq!(Timestamp::MAX.clamp_to_last(&last), Timestamp::MAX);
+        assert_eq!(Timestamp::MIN.clamp_to_last(&last), Timestamp::MIN);
+        assert_eq!(ts(104).clamp_to_last(&last), ts(104));
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    #[test]
+    fn timestamp_now_monotonic_threads() {
+        let handles: alloc::vec::Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    let mut last = Timestamp::now_monotonic();
+                    for _ in 0..1_000 {
+                        let now = Timestamp::now_monotonic();
+                        assert!(now >= last);
+                        last = now;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn duration_until_is_duration_since_reversed() {
+        let ts =
+            |second, nanosecond| Timestamp::new(second, nanosecond).unwrap();
+        let pairs = [
+            (Timestamp::UNIX_EPOCH, Timestamp::UNIX_EPOCH),
+            (ts(1_000, 0), ts(1_600, 0)),
+            (ts(1_600, 0), ts(1_000, 0)),
+            (ts(-1, 999_999_999), ts(0, 1)),
+            (ts(1_718_824_965, 123), ts(-1_718_824_965, 456)),
+            (Timestamp::MIN, Timestamp::MAX),
+            (Timestamp::MAX, Timestamp::MIN),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.duration_until(b), b.duration_since(a), "{a} {b}");
+            assert_eq!(a.duration_until(b), -a.duration_since(b), "{a} {b}");
+        }
+        assert_eq!(
+            ts(1_000, 0).duration_until(ts(1_600, 0)),
+            SignedDuration::from_mins(10),
+        );
+    }
 }
@@ -3,7 +3,7 @@ use crate::{
     error::{err, Error, ErrorContext},
     shared::util::itime::IAmbiguousOffset,
     tz::{Offset, TimeZone},
-    Timestamp, Zoned,
+    SignedDuration, Timestamp, Zoned,
 };
 
 /// Configuration for resolving ambiguous datetimes in a particular time zone.
@@ -203,6 +203,49 @@ pub enum AmbiguousOffset {
 }
 
 impl AmbiguousOffset {
+    /// Returns the length of the gap or fold described by this offset.
+    ///
+    /// For a [`AmbiguousOffset::Gap`], this is the amount of civil time that
+    /// was skipped. For a [`AmbiguousOffset::Fold`], this is the amount of
+    /// civil time that was repeated. In both cases, the duration returned is
+    /// positive and is derived from the difference between the `before` and
+    /// `after` offsets. For [`AmbiguousOffset::Unambiguous`], a zero duration
+    /// is returned.
+    ///
+    /// While most transitions are 1 hour, this isn't always the case. For
+    /// example, `Australia/Lord_Howe` shifts by 30 minutes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone, SignedDuration};
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    ///
+    /// let gap = tz.to_ambiguous_timestamp(date(2024, 3, 10).at(2, 30, 0, 0));
+    /// assert_eq!(gap.offset().duration(), SignedDuration::from_hours(1));
+    ///
+    /// let fold = tz.to_ambiguous_timestamp(date(2024, 11, 3).at(1, 30, 0, 0));
+    /// assert_eq!(fold.offset().duration(), SignedDuration::from_hours(1));
+    ///
+    /// let ok = tz.to_ambiguous_timestamp(date(2024, 7, 1).at(12, 0, 0, 0));
+    /// assert_eq!(ok.offset().duration(), SignedDuration::ZERO);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn duration(self) -> SignedDuration {
+        match self {
+            AmbiguousOffset::Unambiguous { .. } => SignedDuration::ZERO,
+            AmbiguousOffset::Gap { before, after } => {
+                after.duration_since(before)
+            }
+            AmbiguousOffset::Fold { before, after } => {
+                before.duration_since(after)
+            }
+        }
+    }
+
     #[inline]
     pub(crate) const fn from_iambiguous_offset_const(
         iaoff: IAmbiguousOffset,
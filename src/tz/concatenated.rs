@@ -20,11 +20,10 @@ use crate::{
 /// this implementation prevents against is accidentally OOMing or panicking as
 /// a result of naively doing `Vec::with_capacity(rdr.decode_integer())`.
 ///
-/// This is also designed to work in alloc-only contexts mostly out of "good
-/// sense." Technically we don't (currently) use this outside of `std`, since
-/// it's only used for reading tzdb on Android from the file system. But we do
-/// things this way in case we end up wanting to use it for something else.
-/// If we needed this for no-alloc environments, then that's a much bigger
+/// This is also designed to work in alloc-only contexts. Besides reading tzdb
+/// on Android from the file system (which requires `std`), it's also used to
+/// parse concatenated TZif data that the caller already has in memory. If we
+/// needed this for no-alloc environments, then that's a much bigger
 /// change, if only because it would require making the TZif parser no-alloc
 /// compatible, and it's not quite clear what the best way to do that is. We
 /// achieve the alloc-only API be introducing a trait that abstracts over a
@@ -322,8 +321,8 @@ impl<'a> core::fmt::Debug for IndexEntry<'a> {
 ///
 /// Basically, this just provides a way to read a fixed amount of data at a
 /// particular offset. This is obviously trivial to implement on `&[u8]` (and
-/// indeed, we do so for testing), but we use it to abstract over platform
-/// differences when reading from a `File`.
+/// indeed, we do so for in-memory databases and for testing), but we use it
+/// to abstract over platform differences when reading from a `File`.
 ///
 /// The intent is that on Unix, this will use `pread`, which avoids a file
 /// seek followed by a `read` call.
@@ -346,7 +345,6 @@ fn read_be32(bytes: &[u8]) -> u32 {
     u32::from_be_bytes(bytes.try_into().expect("slice of length 4"))
 }
 
-#[cfg(test)]
 impl Read for [u8] {
     fn read_exact_at(&self, buf: &mut [u8], offset: u64) -> Result<(), Error> {
         let offset = usize::try_from(offset)
@@ -0,0 +1,104 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    error::{err, Error, ErrorContext},
+    tz::{concatenated::ConcatenatedTzif, TimeZone, TimeZoneNameIter},
+    util::utf8,
+};
+
+/// A time zone database built from concatenated `tzdata` held entirely in
+/// memory.
+///
+/// Unlike a `tzdata` file, the data can never change. So there is no need
+/// for any expiration or revalidation. Every time zone is parsed up front,
+/// which means a malformed TZif entry is reported when the database is
+/// created instead of turning into a failed lookup later.
+pub(crate) struct Database {
+    /// The size, in bytes, of the concatenated `tzdata` given.
+    len: usize,
+    /// All available names, sorted.
+    names: Vec<String>,
+    /// All time zones, sorted by name.
+    zones: Vec<TimeZone>,
+}
+
+impl Database {
+    /// Parses every time zone in the given concatenated `tzdata`.
+    ///
+    /// If no names of time zones could be found, or if any TZif entry could
+    /// not be parsed, then an error is returned. In the latter case, the
+    /// error includes the name of the offending time zone.
+    pub(crate) fn from_bytes(data: &[u8]) -> Result<Database, Error> {
+        let db = ConcatenatedTzif::open(data)?;
+        let mut names = db.available(&mut vec![])?;
+        if names.is_empty() {
+            return Err(err!(
+                "found no IANA time zone identifiers in \
+                 concatenated tzdata bytes",
+            ));
+        }
+        names.sort_by(|n1, n2| utf8::cmp_ignore_ascii_case(n1, n2));
+        names.dedup_by(|n1, n2| utf8::cmp_ignore_ascii_case(n1, n2).is_eq());
+
+        let (mut scratch1, mut scratch2) = (vec![], vec![]);
+        let mut zones = Vec::with_capacity(names.len());
+        for name in names.iter() {
+            let tz = db
+                .get(name, &mut scratch1, &mut scratch2)
+                .with_context(|| {
+                    err!(
+                        "failed to parse time zone `{name}` in \
+                         concatenated tzdata bytes",
+                    )
+                })?
+                // OK because `name` came from the index of this very data.
+                .unwrap();
+            zones.push(tz);
+        }
+        trace!(
+            "found in-memory concatenated tzdata with version {version} \
+             and {len} IANA time zone identifiers",
+            version = db.version(),
+            len = names.len(),
+        );
+        Ok(Database { len: data.len(), names, zones })
+    }
+
+    pub(crate) fn reset(&self) {}
+
+    pub(crate) fn get(&self, query: &str) -> Option<TimeZone> {
+        // We just always assume UTC exists and map it to our special const
+        // TimeZone::UTC value.
+        if query == "UTC" {
+            return Some(TimeZone::UTC);
+        }
+        // Similarly for the special `Etc/Unknown` value.
+        if query == "Etc/Unknown" {
+            return Some(TimeZone::unknown());
+        }
+        let i = self
+            .zones
+            .binary_search_by(|tz| {
+                // OK because `ConcatenatedTzif` guarantees all `TimeZone`
+                // values it returns have an IANA name.
+                utf8::cmp_ignore_ascii_case(tz.iana_name().unwrap(), query)
+            })
+            .ok()?;
+        Some(self.zones[i].clone())
+    }
+
+    pub(crate) fn available<'d>(&'d self) -> TimeZoneNameIter<'d> {
+        TimeZoneNameIter::from_iter(self.names.clone().into_iter())
+    }
+
+    pub(crate) fn is_definitively_empty(&self) -> bool {
+        // Construction fails when no time zones could be found.
+        false
+    }
+}
+
+impl core::fmt::Debug for Database {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Memory(<{} bytes>)", self.len)
+    }
+}
@@ -6,6 +6,8 @@ use crate::{
 
 mod bundled;
 mod concatenated;
+#[cfg(feature = "alloc")]
+mod memory;
 mod zoneinfo;
 
 /// Returns a copy of the global [`TimeZoneDatabase`].
@@ -196,6 +198,8 @@ enum Kind {
     ZoneInfo(zoneinfo::Database),
     Concatenated(concatenated::Database),
     Bundled(bundled::Database),
+    #[cfg(feature = "alloc")]
+    Memory(memory::Database),
 }
 
 impl TimeZoneDatabase {
@@ -303,11 +307,21 @@ impl TimeZoneDatabase {
     /// This returns an error if the given directory does not contain a valid
     /// copy of the Time Zone Database. Generally, this means a directory with
     /// at least one valid TZif file.
+    ///
+    /// This also returns an error, which includes the path given, if the
+    /// path does not exist or does not point to a directory. This check is
+    /// done upfront so that a misconfigured path is reported immediately
+    /// instead of on the first time zone lookup.
     #[cfg(feature = "std")]
     pub fn from_dir<P: AsRef<std::path::Path>>(
         path: P,
     ) -> Result<TimeZoneDatabase, Error> {
         let path = path.as_ref();
+        let md =
+            std::fs::metadata(path).map_err(|e| Error::io(e).path(path))?;
+        if !md.is_dir() {
+            return Err(err!("path is not a directory").path(path));
+        }
         let db = zoneinfo::Database::from_dir(path)?;
         if db.is_definitively_empty() {
             warn!(
@@ -356,6 +370,74 @@ impl TimeZoneDatabase {
         Ok(TimeZoneDatabase::new(Kind::Concatenated(db)))
     }
 
+    /// Returns a time zone database initialized from concatenated `tzdata`
+    /// held in memory.
+    ///
+    /// The data given must be in the same concatenated format accepted
+    /// by [`TimeZoneDatabase::from_concatenated_path`] (see below). This is
+    /// useful when the Time Zone Database is embedded into the binary (e.g.,
+    /// via `include_bytes!`) or fetched over the network, and there is no
+    /// file system to read it from.
+    ///
+    /// Every time zone in the data is parsed when the database is created.
+    /// Since the data can never change, no lookup ever needs to consult it
+    /// again.
+    ///
+    /// This is only available when the `alloc` crate feature is enabled. It
+    /// does not require the `tzdb-concatenated` crate feature.
+    ///
+    /// # Format
+    ///
+    /// The data is made up of a header, an index and the TZif data for
+    /// each time zone. All integers are unsigned 32-bit big endian.
+    ///
+    /// The header is 24 bytes:
+    ///
+    /// * 12 bytes containing `tzdata`, followed by a 5 byte version (e.g.,
+    ///   `2024a`), followed by a NUL byte.
+    /// * The offset of the index, from the start of the data.
+    /// * The offset of the TZif data, from the start of the data. This must
+    ///   not be less than the offset of the index.
+    /// * The offset of a `zone.tab` section. This is ignored.
+    ///
+    /// The index spans from its offset to the offset of the TZif data. It is
+    /// a sequence of 52 byte entries, one for each time zone:
+    ///
+    /// * 40 bytes containing the IANA time zone identifier, padded with NUL
+    ///   bytes.
+    /// * The offset of the time zone's TZif data, relative to the offset of
+    ///   the TZif data in the header.
+    /// * The length of the time zone's TZif data.
+    /// * The time zone's raw UTC offset. This is ignored.
+    ///
+    /// Multiple index entries may point at the same TZif data.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the header or index of the given data is
+    /// invalid, or if it doesn't contain any time zones. This also returns
+    /// an error if the TZif data for any time zone is invalid. In that case,
+    /// the error message includes the IANA time zone identifier of the
+    /// offending entry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jiff::tz::TimeZoneDatabase;
+    ///
+    /// let data = std::fs::read("/system/usr/share/zoneinfo/tzdata")?;
+    /// let db = TimeZoneDatabase::from_bytes(&data)?;
+    /// let tz = db.get("America/New_York")?;
+    /// assert_eq!(tz.iana_name(), Some("America/New_York"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_bytes(data: &[u8]) -> Result<TimeZoneDatabase, Error> {
+        let db = memory::Database::from_bytes(data)?;
+        Ok(TimeZoneDatabase::new(Kind::Memory(db)))
+    }
+
     /// Returns a time zone database initialized from the bundled copy of
     /// the [IANA Time Zone Database].
     ///
@@ -439,6 +521,10 @@ impl TimeZoneDatabase {
     ///
     /// The lookup is performed without regard to ASCII case.
     ///
+    /// Any type that implements `AsRef<str>` can be used as a time zone name,
+    /// just like with [`TimeZone::get`]. The name is only copied into the
+    /// error returned when the lookup fails.
+    ///
     /// To see a list of all available time zone identifiers for this database,
     /// use [`TimeZoneDatabase::available`].
     ///
@@ -452,44 +538,58 @@ impl TimeZoneDatabase {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn get(&self, name: &str) -> Result<TimeZone, Error> {
-        let inner = self.inner.as_deref().ok_or_else(|| {
-            if cfg!(feature = "std") {
-                err!(
-                    "failed to find time zone `{name}` since there is no \
-                     time zone database configured",
-                )
-            } else {
-                err!(
-                    "failed to find time zone `{name}`, there is no \
-                     global time zone database configured (and is currently \
-                     impossible to do so without Jiff's `std` feature \
-                     enabled, if you need this functionality, please file \
-                     an issue on Jiff's tracker with your use case)",
-                )
-            }
-        })?;
-        match *inner {
-            Kind::ZoneInfo(ref db) => {
-                if let Some(tz) = db.get(name) {
-                    trace!("found time zone `{name}` in {db:?}", db = self);
-                    return Ok(tz);
+    pub fn get<N: AsRef<str>>(&self, name: N) -> Result<TimeZone, Error> {
+        fn inner(
+            tzdb: &TimeZoneDatabase,
+            name: &str,
+        ) -> Result<TimeZone, Error> {
+            let inner = tzdb.inner.as_deref().ok_or_else(|| {
+                if cfg!(feature = "std") {
+                    err!(
+                        "failed to find time zone `{name}` since there is no \
+                         time zone database configured",
+                    )
+                } else {
+                    err!(
+                        "failed to find time zone `{name}`, there is no \
+                         global time zone database configured (and is \
+                         currently impossible to do so without Jiff's `std` \
+                         feature enabled, if you need this functionality, \
+                         please file an issue on Jiff's tracker with your \
+                         use case)",
+                    )
                 }
-            }
-            Kind::Concatenated(ref db) => {
-                if let Some(tz) = db.get(name) {
-                    trace!("found time zone `{name}` in {db:?}", db = self);
-                    return Ok(tz);
+            })?;
+            match *inner {
+                Kind::ZoneInfo(ref db) => {
+                    if let Some(tz) = db.get(name) {
+                        trace!("found time zone `{name}` in {tzdb:?}");
+                        return Ok(tz);
+                    }
                 }
-            }
-            Kind::Bundled(ref db) => {
-                if let Some(tz) = db.get(name) {
-                    trace!("found time zone `{name}` in {db:?}", db = self);
-                    return Ok(tz);
+                Kind::Concatenated(ref db) => {
+                    if let Some(tz) = db.get(name) {
+                        trace!("found time zone `{name}` in {tzdb:?}");
+                        return Ok(tz);
+                    }
+                }
+                Kind::Bundled(ref db) => {
+                    if let Some(tz) = db.get(name) {
+                        trace!("found time zone `{name}` in {tzdb:?}");
+                        return Ok(tz);
+                    }
+                }
+                #[cfg(feature = "alloc")]
+                Kind::Memory(ref db) => {
+                    if let Some(tz) = db.get(name) {
+                        trace!("found time zone `{name}` in {tzdb:?}");
+                        return Ok(tz);
+                    }
                 }
             }
+            Err(Error::time_zone_lookup(name))
         }
-        Err(err!("failed to find time zone `{name}` in time zone database"))
+        inner(self, name.as_ref())
     }
 
     /// Returns a list of all available time zone identifiers from this
@@ -501,6 +601,11 @@ impl TimeZoneDatabase {
     /// IANA time zone identifiers are probably comfortable interacting with
     /// the identifiers returned here.
     ///
+    /// The identifiers returned include aliases (i.e., links in the IANA Time
+    /// Zone Database, such as `US/Eastern`) in addition to canonical
+    /// identifiers (such as `America/New_York`). Every identifier returned
+    /// can be passed to [`TimeZoneDatabase::get`] to load its time zone.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -518,6 +623,8 @@ impl TimeZoneDatabase {
             Kind::ZoneInfo(ref db) => db.available(),
             Kind::Concatenated(ref db) => db.available(),
             Kind::Bundled(ref db) => db.available(),
+            #[cfg(feature = "alloc")]
+            Kind::Memory(ref db) => db.available(),
         }
     }
 
@@ -530,12 +637,19 @@ impl TimeZoneDatabase {
     /// has changed on disk and want to force Jiff to re-load it immediately
     /// without spawning a new process or waiting for Jiff's internal cache
     /// invalidation heuristics to kick in.
+    ///
+    /// For a zoneinfo database, this also discards the index of time zone
+    /// names that is built by scanning the directory. The index is rebuilt
+    /// on the next lookup, so time zones added to the directory since the
+    /// last scan become available immediately.
     pub fn reset(&self) {
         let Some(inner) = self.inner.as_deref() else { return };
         match *inner {
             Kind::ZoneInfo(ref db) => db.reset(),
             Kind::Concatenated(ref db) => db.reset(),
             Kind::Bundled(ref db) => db.reset(),
+            #[cfg(feature = "alloc")]
+            Kind::Memory(ref db) => db.reset(),
         }
     }
 
@@ -562,6 +676,8 @@ impl TimeZoneDatabase {
             Kind::ZoneInfo(ref db) => db.is_definitively_empty(),
             Kind::Concatenated(ref db) => db.is_definitively_empty(),
             Kind::Bundled(ref db) => db.is_definitively_empty(),
+            #[cfg(feature = "alloc")]
+            Kind::Memory(ref db) => db.is_definitively_empty(),
         }
     }
 }
@@ -576,6 +692,8 @@ impl core::fmt::Debug for TimeZoneDatabase {
             Kind::ZoneInfo(ref db) => write!(f, "{db:?}")?,
             Kind::Concatenated(ref db) => write!(f, "{db:?}")?,
             Kind::Bundled(ref db) => write!(f, "{db:?}")?,
+            #[cfg(feature = "alloc")]
+            Kind::Memory(ref db) => write!(f, "{db:?}")?,
         }
         write!(f, ")")
     }
@@ -710,4 +828,248 @@ mod tests {
             assert_eq!(1, core::mem::size_of::<TimeZoneDatabase>());
         }
     }
+
+    /// Tests that the available time zone identifiers are enumerated from a
+    /// fixture database, including both canonical names and aliases.
+    #[cfg(feature = "tzdb-concatenated")]
+    #[test]
+    fn time_zone_database_available() {
+        use alloc::{string::String, vec::Vec};
+
+        let db = TimeZoneDatabase::from_concatenated_path(
+            "src/tz/testdata/android/tzdata",
+        )
+        .unwrap();
+        let names: Vec<String> =
+            db.available().map(|name| name.as_str().into()).collect();
+        assert!(!names.is_empty());
+        for name in ["America/New_York", "Europe/London", "US/Eastern", "UTC"]
+        {
+            assert!(
+                names.iter().any(|n| n == name),
+                "expected {name} in available time zones",
+            );
+            assert!(db.get(name).is_ok(), "expected {name} to be loadable");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_zone_database_from_dir_invalid() {
+        use alloc::string::ToString;
+
+        let path = "src/tz/testdata/android/tzdata";
+        let err = TimeZoneDatabase::from_dir(path).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "src/tz/testdata/android/tzdata: path is not a directory",
+        );
+
+        let path = "src/tz/testdata/does-not-exist";
+        let err = TimeZoneDatabase::from_dir(path).unwrap_err();
+        assert!(
+            err.to_string().starts_with("src/tz/testdata/does-not-exist: "),
+            "unexpected error: {err}",
+        );
+        assert_eq!(err.category(), crate::ErrorCategory::Io);
+    }
+
+    #[test]
+    fn time_zone_database_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<TimeZoneDatabase>();
+    }
+
+    /// Tests that clones of a database can resolve time zones concurrently
+    /// and that they share a cache.
+    #[cfg(feature = "std")]
+    #[test]
+    fn time_zone_database_concurrent_lookups() {
+        const NAMES: &[&str] = &[
+            "America/New_York",
+            "Europe/London",
+            "Asia/Tokyo",
+            "Australia/Sydney",
+        ];
+
+        let db = crate::tz::db().clone();
+        if db.is_definitively_empty() {
+            return;
+        }
+
+        let handles: alloc::vec::Vec<_> = (0..8)
+            .map(|i| {
+                let db = db.clone();
+                std::thread::spawn(move || {
+                    let mut got = alloc::vec::Vec::new();
+                    for _ in 0..100 {
+                        let name = NAMES[i % NAMES.len()];
+                        let tz = db.get(name).unwrap();
+                        assert_eq!(tz.iana_name(), Some(name));
+                        got.push(tz);
+                    }
+                    got
+                })
+            })
+            .collect();
+        for handle in handles {
+            for tz in handle.join().unwrap() {
+                assert_eq!(db.get(tz.iana_name().unwrap()).unwrap(), tz);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_database_from_bytes() {
+        use crate::{civil::date, tz::testdata::ANDROID_CONCATENATED_TZIF};
+
+        let db =
+            TimeZoneDatabase::from_bytes(ANDROID_CONCATENATED_TZIF).unwrap();
+        assert!(!db.is_definitively_empty());
+        assert_eq!(db.available().count(), 596);
+
+        let tz = db.get("America/New_York").unwrap();
+        assert_eq!(tz.iana_name(), Some("America/New_York"));
+        let zdt = date(2024, 7, 1).at(12, 0, 0, 0).to_zoned(tz).unwrap();
+        assert_eq!(zdt.offset(), crate::tz::offset(-4));
+        // Lookups are case insensitive and return the same time zone.
+        let tz = db.get("america/new_york").unwrap();
+        assert_eq!(tz.iana_name(), Some("America/New_York"));
+        assert_eq!(db.get("America/New_York").unwrap(), tz);
+
+        let err = db.get("America/Nowhere").unwrap_err();
+        assert_eq!(err.time_zone_name(), Some("America/Nowhere"));
+
+        assert!(TimeZoneDatabase::from_bytes(b"").is_err());
+        assert!(TimeZoneDatabase::from_bytes(
+            &ANDROID_CONCATENATED_TZIF[..20],
+        )
+        .is_err());
+        let mut corrupt = ANDROID_CONCATENATED_TZIF.to_vec();
+        corrupt[..6].copy_from_slice(b"tzdat4");
+        assert!(TimeZoneDatabase::from_bytes(&corrupt).is_err());
+
+        // Corrupt the TZif magic of a single time zone. The error should
+        // tell us which one is broken. Note that `Europe/Monaco` shares its
+        // TZif data with `Europe/Paris` and sorts first, so that's the one
+        // reported.
+        let data = ANDROID_CONCATENATED_TZIF;
+        let read_be32 = |i: usize| {
+            usize::try_from(u32::from_be_bytes(
+                data[i..i + 4].try_into().unwrap(),
+            ))
+            .unwrap()
+        };
+        let (index_offset, data_offset) = (read_be32(12), read_be32(16));
+        let entry = data[index_offset..data_offset]
+            .chunks_exact(52)
+            .find(|entry| entry.starts_with(b"Europe/Paris\0"))
+            .unwrap();
+        let start = data_offset
+            + usize::try_from(u32::from_be_bytes(
+                entry[40..44].try_into().unwrap(),
+            ))
+            .unwrap();
+        let mut corrupt = data.to_vec();
+        corrupt[start..start + 4].copy_from_slice(b"XXXX");
+        insta::assert_snapshot!(
+            TimeZoneDatabase::from_bytes(&corrupt).unwrap_err(),
+            @"failed to parse time zone `Europe/Monaco` in concatenated tzdata bytes: failed to parse TZif data for time zone \"Europe/Monaco\": failed to parse 32-bit header: invalid header: magic bytes mismatch",
+        );
+    }
+
+    /// Tests that a database can be built from concatenated data assembled
+    /// by hand, following the format documented on
+    /// `TimeZoneDatabase::from_bytes`.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_database_from_bytes_two_zones() {
+        use crate::{civil::date, tz::testdata::TzifTestFile};
+
+        let zones = [
+            TzifTestFile::get("America/New_York"),
+            TzifTestFile::get("Europe/Dublin"),
+        ];
+        let be32 = |n: usize| u32::try_from(n).unwrap().to_be_bytes();
+
+        let index_offset = 24;
+        let data_offset = index_offset + zones.len() * 52;
+        let data_len: usize = zones.iter().map(|z| z.data.len()).sum();
+        let mut data = b"tzdata2024a\0".to_vec();
+        data.extend_from_slice(&be32(index_offset));
+        data.extend_from_slice(&be32(data_offset));
+        data.extend_from_slice(&be32(data_offset + data_len));
+        let mut start = 0;
+        for zone in zones.iter() {
+            let mut name = [0; 40];
+            name[..zone.name.len()].copy_from_slice(zone.name.as_bytes());
+            data.extend_from_slice(&name);
+            data.extend_from_slice(&be32(start));
+            data.extend_from_slice(&be32(zone.data.len()));
+            data.extend_from_slice(&be32(0));
+            start += zone.data.len();
+        }
+        for zone in zones.iter() {
+            data.extend_from_slice(zone.data);
+        }
+
+        let db = TimeZoneDatabase::from_bytes(&data).unwrap();
+        assert_eq!(db.available().count(), 2);
+
+        let dt = date(2024, 7, 1).at(12, 0, 0, 0);
+        let tz = db.get("America/New_York").unwrap();
+        assert_eq!(tz.iana_name(), Some("America/New_York"));
+        assert_eq!(dt.to_zoned(tz).unwrap().offset(), crate::tz::offset(-4));
+        let tz = db.get("Europe/Dublin").unwrap();
+        assert_eq!(tz.iana_name(), Some("Europe/Dublin"));
+        assert_eq!(dt.to_zoned(tz).unwrap().offset(), crate::tz::offset(1));
+
+        assert!(db.get("Europe/London").is_err());
+    }
+
+    /// Tests that resetting a zoneinfo database rebuilds its index of time
+    /// zone names, such that existing time zones can still be found and new
+    /// ones are picked up without waiting for the index to expire.
+    #[cfg(feature = "tzdb-zoneinfo")]
+    #[test]
+    fn time_zone_database_zoneinfo_reset_rebuilds_index() {
+        use crate::tz::testdata::TzifTestFile;
+
+        /// Removes the temporary directory when the test finishes, even if
+        /// it fails.
+        struct RemoveDir(std::path::PathBuf);
+
+        impl Drop for RemoveDir {
+            fn drop(&mut self) {
+                let _ = std::fs::remove_dir_all(&self.0);
+            }
+        }
+
+        let guard = RemoveDir(std::env::temp_dir().join(alloc::format!(
+            "jiff-test-zoneinfo-reset-{}",
+            std::process::id(),
+        )));
+        let dir = &guard.0;
+        let _ = std::fs::remove_dir_all(dir);
+        let write = |name: &str| {
+            let path = dir.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            let data = TzifTestFile::get(name).data;
+            std::fs::write(path, data).unwrap();
+        };
+        write("America/New_York");
+
+        let db = TimeZoneDatabase::from_dir(dir).unwrap();
+        let tz = db.get("America/New_York").unwrap();
+        assert_eq!(tz.iana_name(), Some("America/New_York"));
+        assert!(db.get("Australia/Tasmania").is_err());
+
+        write("Australia/Tasmania");
+        db.reset();
+        assert_eq!(db.get("America/New_York").unwrap(), tz);
+        let tz = db.get("australia/tasmania").unwrap();
+        assert_eq!(tz.iana_name(), Some("Australia/Tasmania"));
+        assert_eq!(db.available().count(), 2);
+    }
 }
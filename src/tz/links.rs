@@ -0,0 +1,154 @@
+// Generated from version 2025b of the IANA Time Zone Database.
+pub(super) static LINK_TO_CANONICAL: &[(&str, &str)] = &[
+    (r"Africa/Asmera", r"Africa/Nairobi"),
+    (r"Africa/Timbuktu", r"Africa/Abidjan"),
+    (r"America/Argentina/ComodRivadavia", r"America/Argentina/Catamarca"),
+    (r"America/Atka", r"America/Adak"),
+    (r"America/Buenos_Aires", r"America/Argentina/Buenos_Aires"),
+    (r"America/Catamarca", r"America/Argentina/Catamarca"),
+    (r"America/Coral_Harbour", r"America/Panama"),
+    (r"America/Cordoba", r"America/Argentina/Cordoba"),
+    (r"America/Ensenada", r"America/Tijuana"),
+    (r"America/Fort_Wayne", r"America/Indiana/Indianapolis"),
+    (r"America/Godthab", r"America/Nuuk"),
+    (r"America/Indianapolis", r"America/Indiana/Indianapolis"),
+    (r"America/Jujuy", r"America/Argentina/Jujuy"),
+    (r"America/Knox_IN", r"America/Indiana/Knox"),
+    (r"America/Kralendijk", r"America/Puerto_Rico"),
+    (r"America/Louisville", r"America/Kentucky/Louisville"),
+    (r"America/Lower_Princes", r"America/Puerto_Rico"),
+    (r"America/Marigot", r"America/Puerto_Rico"),
+    (r"America/Mendoza", r"America/Argentina/Mendoza"),
+    (r"America/Montreal", r"America/Toronto"),
+    (r"America/Nipigon", r"America/Toronto"),
+    (r"America/Pangnirtung", r"America/Iqaluit"),
+    (r"America/Porto_Acre", r"America/Rio_Branco"),
+    (r"America/Rainy_River", r"America/Winnipeg"),
+    (r"America/Rosario", r"America/Argentina/Cordoba"),
+    (r"America/Santa_Isabel", r"America/Tijuana"),
+    (r"America/Shiprock", r"America/Denver"),
+    (r"America/St_Barthelemy", r"America/Puerto_Rico"),
+    (r"America/Thunder_Bay", r"America/Toronto"),
+    (r"America/Virgin", r"America/Puerto_Rico"),
+    (r"America/Yellowknife", r"America/Edmonton"),
+    (r"Antarctica/South_Pole", r"Pacific/Auckland"),
+    (r"Arctic/Longyearbyen", r"Europe/Berlin"),
+    (r"Asia/Ashkhabad", r"Asia/Ashgabat"),
+    (r"Asia/Calcutta", r"Asia/Kolkata"),
+    (r"Asia/Choibalsan", r"Asia/Ulaanbaatar"),
+    (r"Asia/Chongqing", r"Asia/Shanghai"),
+    (r"Asia/Chungking", r"Asia/Shanghai"),
+    (r"Asia/Dacca", r"Asia/Dhaka"),
+    (r"Asia/Harbin", r"Asia/Shanghai"),
+    (r"Asia/Istanbul", r"Europe/Istanbul"),
+    (r"Asia/Kashgar", r"Asia/Urumqi"),
+    (r"Asia/Katmandu", r"Asia/Kathmandu"),
+    (r"Asia/Macao", r"Asia/Macau"),
+    (r"Asia/Rangoon", r"Asia/Yangon"),
+    (r"Asia/Saigon", r"Asia/Ho_Chi_Minh"),
+    (r"Asia/Tel_Aviv", r"Asia/Jerusalem"),
+    (r"Asia/Thimbu", r"Asia/Thimphu"),
+    (r"Asia/Ujung_Pandang", r"Asia/Makassar"),
+    (r"Asia/Ulan_Bator", r"Asia/Ulaanbaatar"),
+    (r"Atlantic/Faeroe", r"Atlantic/Faroe"),
+    (r"Atlantic/Jan_Mayen", r"Europe/Berlin"),
+    (r"Australia/ACT", r"Australia/Sydney"),
+    (r"Australia/Canberra", r"Australia/Sydney"),
+    (r"Australia/Currie", r"Australia/Hobart"),
+    (r"Australia/LHI", r"Australia/Lord_Howe"),
+    (r"Australia/North", r"Australia/Darwin"),
+    (r"Australia/NSW", r"Australia/Sydney"),
+    (r"Australia/Queensland", r"Australia/Brisbane"),
+    (r"Australia/South", r"Australia/Adelaide"),
+    (r"Australia/Tasmania", r"Australia/Hobart"),
+    (r"Australia/Victoria", r"Australia/Melbourne"),
+    (r"Australia/West", r"Australia/Perth"),
+    (r"Australia/Yancowinna", r"Australia/Broken_Hill"),
+    (r"Brazil/Acre", r"America/Rio_Branco"),
+    (r"Brazil/DeNoronha", r"America/Noronha"),
+    (r"Brazil/East", r"America/Sao_Paulo"),
+    (r"Brazil/West", r"America/Manaus"),
+    (r"Canada/Atlantic", r"America/Halifax"),
+    (r"Canada/Central", r"America/Winnipeg"),
+    (r"Canada/Eastern", r"America/Toronto"),
+    (r"Canada/Mountain", r"America/Edmonton"),
+    (r"Canada/Newfoundland", r"America/St_Johns"),
+    (r"Canada/Pacific", r"America/Vancouver"),
+    (r"Canada/Saskatchewan", r"America/Regina"),
+    (r"Canada/Yukon", r"America/Whitehorse"),
+    (r"Chile/Continental", r"America/Santiago"),
+    (r"Chile/EasterIsland", r"Pacific/Easter"),
+    (r"Cuba", r"America/Havana"),
+    (r"Egypt", r"Africa/Cairo"),
+    (r"Eire", r"Europe/Dublin"),
+    (r"Etc/GMT+0", r"Etc/GMT"),
+    (r"Etc/GMT-0", r"Etc/GMT"),
+    (r"Etc/GMT0", r"Etc/GMT"),
+    (r"Etc/Greenwich", r"Etc/GMT"),
+    (r"Etc/UCT", r"Etc/UTC"),
+    (r"Etc/Universal", r"Etc/UTC"),
+    (r"Etc/Zulu", r"Etc/UTC"),
+    (r"Europe/Belfast", r"Europe/London"),
+    (r"Europe/Bratislava", r"Europe/Prague"),
+    (r"Europe/Busingen", r"Europe/Zurich"),
+    (r"Europe/Kiev", r"Europe/Kyiv"),
+    (r"Europe/Mariehamn", r"Europe/Helsinki"),
+    (r"Europe/Nicosia", r"Asia/Nicosia"),
+    (r"Europe/Podgorica", r"Europe/Belgrade"),
+    (r"Europe/San_Marino", r"Europe/Rome"),
+    (r"Europe/Tiraspol", r"Europe/Chisinau"),
+    (r"Europe/Uzhgorod", r"Europe/Kyiv"),
+    (r"Europe/Vatican", r"Europe/Rome"),
+    (r"Europe/Zaporozhye", r"Europe/Kyiv"),
+    (r"GB", r"Europe/London"),
+    (r"GB-Eire", r"Europe/London"),
+    (r"GMT", r"Etc/GMT"),
+    (r"GMT+0", r"Etc/GMT"),
+    (r"GMT-0", r"Etc/GMT"),
+    (r"GMT0", r"Etc/GMT"),
+    (r"Greenwich", r"Etc/GMT"),
+    (r"Hongkong", r"Asia/Hong_Kong"),
+    (r"Iceland", r"Africa/Abidjan"),
+    (r"Iran", r"Asia/Tehran"),
+    (r"Israel", r"Asia/Jerusalem"),
+    (r"Jamaica", r"America/Jamaica"),
+    (r"Japan", r"Asia/Tokyo"),
+    (r"Kwajalein", r"Pacific/Kwajalein"),
+    (r"Libya", r"Africa/Tripoli"),
+    (r"Mexico/BajaNorte", r"America/Tijuana"),
+    (r"Mexico/BajaSur", r"America/Mazatlan"),
+    (r"Mexico/General", r"America/Mexico_City"),
+    (r"Navajo", r"America/Denver"),
+    (r"NZ", r"Pacific/Auckland"),
+    (r"NZ-CHAT", r"Pacific/Chatham"),
+    (r"Pacific/Enderbury", r"Pacific/Kanton"),
+    (r"Pacific/Johnston", r"Pacific/Honolulu"),
+    (r"Pacific/Ponape", r"Pacific/Guadalcanal"),
+    (r"Pacific/Samoa", r"Pacific/Pago_Pago"),
+    (r"Pacific/Truk", r"Pacific/Port_Moresby"),
+    (r"Pacific/Yap", r"Pacific/Port_Moresby"),
+    (r"Poland", r"Europe/Warsaw"),
+    (r"Portugal", r"Europe/Lisbon"),
+    (r"PRC", r"Asia/Shanghai"),
+    (r"ROC", r"Asia/Taipei"),
+    (r"ROK", r"Asia/Seoul"),
+    (r"Singapore", r"Asia/Singapore"),
+    (r"Turkey", r"Europe/Istanbul"),
+    (r"UCT", r"Etc/UTC"),
+    (r"Universal", r"Etc/UTC"),
+    (r"US/Alaska", r"America/Anchorage"),
+    (r"US/Aleutian", r"America/Adak"),
+    (r"US/Arizona", r"America/Phoenix"),
+    (r"US/Central", r"America/Chicago"),
+    (r"US/East-Indiana", r"America/Indiana/Indianapolis"),
+    (r"US/Eastern", r"America/New_York"),
+    (r"US/Hawaii", r"Pacific/Honolulu"),
+    (r"US/Indiana-Starke", r"America/Indiana/Knox"),
+    (r"US/Michigan", r"America/Detroit"),
+    (r"US/Mountain", r"America/Denver"),
+    (r"US/Pacific", r"America/Los_Angeles"),
+    (r"US/Samoa", r"Pacific/Pago_Pago"),
+    (r"UTC", r"Etc/UTC"),
+    (r"W-SU", r"Europe/Moscow"),
+    (r"Zulu", r"Etc/UTC"),
+];
@@ -136,9 +136,10 @@ pub use self::{
 };
 
 mod ambiguous;
-#[cfg(feature = "tzdb-concatenated")]
+#[cfg(feature = "alloc")]
 mod concatenated;
 mod db;
+mod links;
 mod offset;
 pub(crate) mod posix;
 #[cfg(feature = "tz-system")]
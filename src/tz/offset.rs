@@ -105,6 +105,44 @@ impl From<bool> for Dst {
 /// assert_eq!(o.to_string(), "+00");
 /// ```
 ///
+/// # Parsing
+///
+/// This type implements the `std::str::FromStr` trait. The following forms
+/// are accepted:
+///
+/// * `Z` or `z` for UTC.
+/// * `{sign}{hours}`, e.g., `+05`.
+/// * `{sign}{hours}{minutes}`, e.g., `+0530`.
+/// * `{sign}{hours}:{minutes}`, e.g., `+05:30`.
+/// * `{sign}{hours}{minutes}{seconds}`, e.g., `-080000`.
+/// * `{sign}{hours}:{minutes}:{seconds}`, e.g., `-08:00:00`.
+///
+/// Every component must be exactly two digits, and the basic (no `:`)
+/// and extended (with `:`) forms cannot be mixed. Fractional seconds are
+/// not accepted. Any other input results in an error. When the error is
+/// caused by trailing input, the error message includes the byte offset at
+/// which it begins.
+///
+/// Since every offset printed via `std::fmt::Display` is in one of these
+/// forms, printing and then parsing an offset always roundtrips.
+///
+/// ```
+/// use jiff::tz::{self, Offset};
+///
+/// assert_eq!("Z".parse::<Offset>()?, Offset::UTC);
+/// assert_eq!("+05".parse::<Offset>()?, tz::offset(5));
+/// assert_eq!("+0530".parse::<Offset>()?, Offset::from_seconds(19_800)?);
+/// assert_eq!("+05:30".parse::<Offset>()?, Offset::from_seconds(19_800)?);
+/// assert_eq!("-08:00:00".parse::<Offset>()?, tz::offset(-8));
+/// // Seconds are useful for historical offsets like Amsterdam's LMT.
+/// assert_eq!("+00:19:32".parse::<Offset>()?, Offset::from_seconds(1_172)?);
+///
+/// assert!("+5".parse::<Offset>().is_err());
+/// assert!("+05:30 ".parse::<Offset>().is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// # Example
 ///
 /// This shows how to create a zoned datetime with a time zone using a fixed
@@ -434,6 +472,11 @@ impl Offset {
 
     /// Converts the given timestamp to a civil datetime using this offset.
     ///
+    /// This conversion never loses precision. Both [`Timestamp`] and
+    /// [`civil::DateTime`] have nanosecond precision, so converting the
+    /// civil datetime returned back with [`Offset::to_timestamp`] always
+    /// gives back the original timestamp.
+    ///
     /// # Example
     ///
     /// ```
@@ -1136,6 +1179,25 @@ impl core::fmt::Display for Offset {
     }
 }
 
+impl core::str::FromStr for Offset {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Offset, Error> {
+        static PARSER: crate::fmt::offset::Parser =
+            crate::fmt::offset::Parser::new().subsecond(false);
+
+        PARSER
+            .parse_full(string.as_bytes())
+            .and_then(|parsed| parsed.to_offset())
+            .with_context(|| {
+                err!(
+                    "failed to parse {string:?} as a UTC offset",
+                    string = crate::util::escape::Bytes(string.as_bytes()),
+                )
+            })
+    }
+}
+
 /// Adds a span of time to an offset. This panics on overflow.
 ///
 /// For checked arithmetic, see [`Offset::checked_add`].
@@ -2078,3 +2140,99 @@ impl OffsetConflict {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::fmt::strtime::BrokenDownTime;
+
+    use super::*;
+
+    #[test]
+    fn offset_to_time_zone() {
+        let offset =
+            BrokenDownTime::parse("%:z", "+05:30").unwrap().offset().unwrap();
+        assert_eq!(offset, Offset::hms(5, 30, 0));
+
+        let tz = offset.to_time_zone();
+        assert_eq!(tz, TimeZone::fixed(offset));
+        assert_eq!(tz.to_fixed_offset().unwrap(), offset);
+
+        let ts: Timestamp = "2024-06-19T12:00:00Z".parse().unwrap();
+        let zdt = ts.to_zoned(tz);
+        assert_eq!(zdt.datetime(), civil::date(2024, 6, 19).at(17, 30, 0, 0));
+        assert_eq!(zdt.offset(), offset);
+        assert_eq!(zdt.to_string(), "2024-06-19T17:30:00+05:30[+05:30]");
+    }
+
+    #[test]
+    fn offset_from_str() {
+        let p = |s: &str| s.parse::<Offset>().unwrap();
+
+        assert_eq!(p("Z"), Offset::UTC);
+        assert_eq!(p("z"), Offset::UTC);
+        assert_eq!(p("+00"), Offset::UTC);
+        assert_eq!(p("-00:00"), Offset::UTC);
+        assert_eq!(p("+05"), Offset::hms(5, 0, 0));
+        assert_eq!(p("+0530"), Offset::hms(5, 30, 0));
+        assert_eq!(p("+05:30"), Offset::hms(5, 30, 0));
+        assert_eq!(p("-08:00:00"), Offset::hms(-8, 0, 0));
+        assert_eq!(p("-080000"), Offset::hms(-8, 0, 0));
+        assert_eq!(p("+00:19:32"), Offset::hms(0, 19, 32));
+        assert_eq!(p("-00:01:15"), Offset::hms(0, -1, -15));
+        assert_eq!(p("+25:59:59"), Offset::MAX);
+        assert_eq!(p("-25:59:59"), Offset::MIN);
+
+        for seconds in (-93_599..=93_599).step_by(61) {
+            let offset = Offset::from_seconds(seconds).unwrap();
+            assert_eq!(p(&offset.to_string()), offset);
+        }
+    }
+
+    #[test]
+    fn offset_from_str_invalid() {
+        let e = |s: &str| s.parse::<Offset>().unwrap_err().to_string();
+
+        insta::assert_snapshot!(
+            e(""),
+            @"failed to parse \"\" as a UTC offset: expected UTC offset, but found end of input",
+        );
+        insta::assert_snapshot!(
+            e("05:30"),
+            @"failed to parse \"05:30\" as a UTC offset: failed to parse sign in UTC numeric offset \"05:30\": expected '+' or '-' sign at start of UTC numeric offset, but found \"0\" instead",
+        );
+        insta::assert_snapshot!(
+            e("+5"),
+            @"failed to parse \"+5\" as a UTC offset: failed to parse hours in UTC numeric offset \"+5\": expected two digit hour after sign, but found end of input",
+        );
+        insta::assert_snapshot!(
+            e("+05:3"),
+            @"failed to parse \"+05:3\" as a UTC offset: failed to parse minutes in UTC numeric offset \"+05:3\": expected two digit minute after hours, but found end of input",
+        );
+        insta::assert_snapshot!(
+            e("+26"),
+            @"failed to parse \"+26\" as a UTC offset: failed to parse hours in UTC numeric offset \"+26\": offset hours are not valid: parameter 'hours' with value 26 is not in the required range of 0..=25",
+        );
+        insta::assert_snapshot!(
+            e("+05:60"),
+            @"failed to parse \"+05:60\" as a UTC offset: failed to parse minutes in UTC numeric offset \"+05:60\": minutes are not valid: parameter 'minutes' with value 60 is not in the required range of 0..=59",
+        );
+        insta::assert_snapshot!(
+            e("+05:30 "),
+            @"failed to parse \"+05:30 \" as a UTC offset: parsed value '+05:30', but unparsed input \" \" remains at byte offset 6 (expected no unparsed input)",
+        );
+        insta::assert_snapshot!(
+            e("+0530:00"),
+            @"failed to parse \"+0530:00\" as a UTC offset: parsed value '+05:30', but unparsed input \":00\" remains at byte offset 5 (expected no unparsed input)",
+        );
+        insta::assert_snapshot!(
+            e("-08:00:00.5"),
+            @"failed to parse \"-08:00:00.5\" as a UTC offset: subsecond precision for UTC numeric offset \"-08:00:00.5\" is not enabled in this context (must provide only integral minutes or seconds)",
+        );
+        insta::assert_snapshot!(
+            e("Zulu"),
+            @"failed to parse \"Zulu\" as a UTC offset: parsed value 'Z', but unparsed input \"ulu\" remains at byte offset 1 (expected no unparsed input)",
+        );
+    }
+}
@@ -258,6 +258,15 @@ impl PosixTimeZone<&'static str> {
 }
 
 impl<ABBREV: AsRef<str>> PosixTimeZone<ABBREV> {
+    /// Returns true if and only if this POSIX time zone is equivalent to
+    /// `UTC0`. That is, it has no DST, a zero offset and the abbreviation
+    /// `UTC`.
+    pub(crate) fn is_utc(&self) -> bool {
+        self.inner.dst.is_none()
+            && self.inner.std_offset.second == 0
+            && self.inner.std_abbrev.as_ref() == "UTC"
+    }
+
     /// Returns the appropriate time zone offset to use for the given
     /// timestamp.
     ///
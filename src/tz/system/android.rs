@@ -7,43 +7,47 @@ use alloc::vec::Vec;
 
 use core::{ffi::CStr, mem, ptr::NonNull};
 
-use crate::tz::{TimeZone, TimeZoneDatabase};
+use crate::{
+    error::{err, Error, ErrorContext},
+    tz::{TimeZone, TimeZoneDatabase},
+};
 
 /// Attempts to find the default "system" time zone.
-pub(super) fn get(db: &TimeZoneDatabase) -> Option<TimeZone> {
+pub(super) fn get(db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
     static PROPERTY_NAME: &str = "persist.sys.timezone\0";
 
     static GETTER: OnceLock<Option<PropertyGetter>> = OnceLock::new();
     let Some(getter) = GETTER.get_or_init(|| PropertyGetter::new()) else {
-        // We don't emit any messages here because `PropertyGetter::new()` will
-        // have already done so.
-        return None;
+        // More detailed messages are emitted as WARN-level logs by
+        // `PropertyGetter::new()`.
+        return Err(err!(
+            "failed to load Android system property getter from libc.so",
+        ));
+    };
+    let Some(tzname) = getter.get(cstr(PROPERTY_NAME)) else {
+        return Err(err!(
+            "failed to read Android property `persist.sys.timezone`",
+        ));
     };
-    let tzname = getter.get(cstr(PROPERTY_NAME))?;
     let Some(tzname) = core::str::from_utf8(&tzname).ok() else {
-        warn!(
+        return Err(err!(
             "found `{PROPERTY_NAME}` name `{name}` on Android, \
              but it's not valid UTF-8",
             name = crate::util::escape::Bytes(&tzname),
-        );
-        return None;
-    };
-    let tz = match db.get(tzname) {
-        Ok(tz) => tz,
-        Err(_err) => {
-            warn!(
-                "found `{PROPERTY_NAME}` name `{tzname}` on Android, \
-                 but could not find it in time zone database {db:?}",
-            );
-            return None;
-        }
+        ));
     };
+    let tz = db.get(tzname).with_context(|| {
+        err!(
+            "found `{PROPERTY_NAME}` name `{tzname}` on Android, \
+             but could not find it in time zone database {db:?}",
+        )
+    })?;
     debug!(
         "found system time zone `{tzname}` from Android property \
          `{PROPERTY_NAME}` and found entry for it in time zone \
          database {db:?}",
     );
-    Some(tz)
+    Ok(tz)
 }
 
 /// Given a path to a system default TZif file, return its corresponding
@@ -52,14 +56,11 @@ pub(super) fn get(db: &TimeZoneDatabase) -> Option<TimeZone> {
 /// This doesn't do any symlink shenanigans like in other Unix environments,
 /// although we could consider doing that. I think probably this is very
 /// unlikely to be used on Android, although it can be by setting `TZ`.
-pub(super) fn read(_db: &TimeZoneDatabase, path: &str) -> Option<TimeZone> {
-    match super::read_unnamed_tzif_file(path) {
-        Ok(tz) => Some(tz),
-        Err(_err) => {
-            trace!("failed to read {path} as unnamed time zone: {_err}");
-            None
-        }
-    }
+pub(super) fn read(
+    _db: &TimeZoneDatabase,
+    path: &str,
+) -> Result<TimeZone, Error> {
+    super::read_unnamed_tzif_file(path)
 }
 
 /// An abstraction for safely reading Android system properties.
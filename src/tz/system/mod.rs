@@ -38,24 +38,20 @@ mod sys;
     )
 )))]
 mod sys {
-    use crate::tz::{TimeZone, TimeZoneDatabase};
+    use crate::{
+        error::{err, Error},
+        tz::{TimeZone, TimeZoneDatabase},
+    };
 
-    pub(super) fn get(_db: &TimeZoneDatabase) -> Option<TimeZone> {
-        warn!("getting system time zone on this platform is unsupported");
-        None
+    pub(super) fn get(_db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
+        Err(err!("getting system time zone on this platform is unsupported"))
     }
 
     pub(super) fn read(
         _db: &TimeZoneDatabase,
         path: &str,
-    ) -> Option<TimeZone> {
-        match super::read_unnamed_tzif_file(path) {
-            Ok(tz) => Some(tz),
-            Err(_err) => {
-                trace!("failed to read {path} as unnamed time zone: {_err}");
-                None
-            }
-        }
+    ) -> Result<TimeZone, Error> {
+        super::read_unnamed_tzif_file(path)
     }
 }
 
@@ -115,22 +111,40 @@ impl Cache {
 /// `/etc/localtime` is a hard link to a TZif file instead of a symlink and
 /// when the time zone name isn't recorded in any of the other obvious places.
 pub(crate) fn get(db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
+    get_cached(&CACHE, || get_force(db))
+}
+
+/// Clears the cached system time zone, if one exists.
+///
+/// The next call to `get` will re-detect the system time zone.
+pub(crate) fn reset() {
+    *CACHE.write().unwrap() = Cache::empty();
+}
+
+/// Returns the time zone in the given cache if it isn't stale. Otherwise,
+/// the time zone is created via `force` and put into the cache.
+///
+/// Note that no lock is held while `force` is running.
+fn get_cached(
+    cache: &RwLock<Cache>,
+    force: impl FnOnce() -> Result<TimeZone, Error>,
+) -> Result<TimeZone, Error> {
     {
-        let cache = CACHE.read().unwrap();
+        let cache = cache.read().unwrap();
         if let Some(ref tz) = cache.tz {
             if !cache.expiration.is_expired() {
                 return Ok(tz.clone());
             }
         }
     }
-    let tz = get_force(db)?;
+    let tz = force()?;
     {
         // It's okay that we race here. We basically assume that any
         // sufficiently close but approximately simultaneous detection of
         // "system" time will lead to the same result. Of course, this is not
         // strictly true, but since we invalidate the cache after a TTL, it
         // will eventually be true in any sane environment.
-        let mut cache = CACHE.write().unwrap();
+        let mut cache = cache.write().unwrap();
         cache.tz = Some(tz.clone());
         cache.expiration = Expiration::after(TTL);
     }
@@ -151,10 +165,7 @@ pub(crate) fn get_force(db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
             trace!("checked TZ environment variable but got error: {_err}");
         }
     }
-    if let Some(tz) = sys::get(db) {
-        return Ok(tz);
-    }
-    Err(err!("failed to find system time zone"))
+    sys::get(db).context("failed to find system time zone")
 }
 
 /// Materializes a `TimeZone` from a `TZ` environment variable.
@@ -230,7 +241,7 @@ fn get_env_tz(db: &TimeZoneDatabase) -> Result<Option<TimeZone>, Error> {
                      could not find time zone in zoneinfo database {db:?} \
                      (continuing to try and use {tz_name_or_path:?}",
                 );
-                Ok(sys::read(db, &tz_name_or_path))
+                sys::read(db, &tz_name_or_path).map(Some)
             }
         };
     };
@@ -242,7 +253,7 @@ fn get_env_tz(db: &TimeZoneDatabase) -> Result<Option<TimeZone>, Error> {
         "extracted {name:?} from TZ={tz_name_or_path:?} \
          and assuming it is an IANA time zone name",
     );
-    match db.get(&name) {
+    match db.get(name) {
         Ok(tz) => return Ok(Some(tz)),
         Err(_err) => {
             trace!(
@@ -257,7 +268,7 @@ fn get_env_tz(db: &TimeZoneDatabase) -> Result<Option<TimeZone>, Error> {
     // The only thing left for us to do is treat the value as a file path
     // and read the data as TZif. This will give us time zone data if it works,
     // but without a name.
-    Ok(sys::read(db, &tz_name_or_path))
+    sys::read(db, &tz_name_or_path).map(Some)
 }
 
 /// Returns the given file path as TZif data without a time zone name.
@@ -268,9 +279,56 @@ fn get_env_tz(db: &TimeZoneDatabase) -> Result<Option<TimeZone>, Error> {
 /// that use case. Although I cannot actually name such a platform...
 fn read_unnamed_tzif_file(path: &str) -> Result<TimeZone, Error> {
     let data = std::fs::read(path)
-        .map_err(Error::io)
-        .with_context(|| err!("failed to read {path:?} as TZif file"))?;
+        .map_err(|e| Error::io(e).path(path))
+        .context("failed to read TZif file")?;
     let tz = TimeZone::tzif_system(&data)
-        .with_context(|| err!("found invalid TZif data at {path:?}"))?;
+        .map_err(|e| e.path(path))
+        .context("found invalid TZif data")?;
     Ok(tz)
 }
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn cache_creates_time_zone_once() {
+        // Some platforms don't have a monotonic clock, in which case, the
+        // cache entry always expires immediately.
+        if crate::now::monotonic_time().is_none() {
+            return;
+        }
+
+        let cache = RwLock::new(Cache::empty());
+        let count = Cell::new(0);
+        let force = || {
+            count.set(count.get() + 1);
+            Ok(TimeZone::fixed(crate::tz::offset(-5)))
+        };
+
+        let tz1 = get_cached(&cache, force).unwrap();
+        let tz2 = get_cached(&cache, force).unwrap();
+        assert_eq!(tz1, tz2);
+        assert_eq!(count.get(), 1);
+
+        *cache.write().unwrap() = Cache::empty();
+        get_cached(&cache, force).unwrap();
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn cache_does_not_store_errors() {
+        let cache = RwLock::new(Cache::empty());
+        let count = Cell::new(0);
+        let force = || {
+            count.set(count.get() + 1);
+            Err(err!("no system time zone"))
+        };
+
+        assert!(get_cached(&cache, force).is_err());
+        assert!(get_cached(&cache, force).is_err());
+        assert_eq!(count.get(), 2);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::tz::{TimeZone, TimeZoneDatabase};
+use crate::{
+    error::Error,
+    tz::{TimeZone, TimeZoneDatabase},
+};
 
 static UNIX_LOCALTIME_PATH: &str = "/etc/localtime";
 
@@ -15,7 +18,10 @@ static UNIX_LOCALTIME_PATH: &str = "/etc/localtime";
 /// try to avoid this is because a TZif data file does not contain the time
 /// zone name. And we *really* want the time zone name as it is the only
 /// standardized way to roundtrip a datetime in a particular time zone.
-pub(super) fn get(db: &TimeZoneDatabase) -> Option<TimeZone> {
+///
+/// If `/etc/localtime` couldn't be read at all, then the error returned
+/// includes its file path.
+pub(super) fn get(db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
     read(db, UNIX_LOCALTIME_PATH)
 }
 
@@ -25,21 +31,18 @@ pub(super) fn get(db: &TimeZoneDatabase) -> Option<TimeZone> {
 /// In Unix, we attempt to read it as a symlink and extract an IANA time zone
 /// identifier. If that ID exists in the tzdb, we return that. Otherwise, we
 /// read the TZif file as an unnamed time zone.
-pub(super) fn read(db: &TimeZoneDatabase, path: &str) -> Option<TimeZone> {
+pub(super) fn read(
+    db: &TimeZoneDatabase,
+    path: &str,
+) -> Result<TimeZone, Error> {
     if let Some(tz) = read_link_to_zoneinfo(db, path) {
-        return Some(tz);
+        return Ok(tz);
     }
     trace!(
         "failed to find time zone name using Unix-specific heuristics, \
-         attempting to read {UNIX_LOCALTIME_PATH} as unnamed time zone",
+         attempting to read {path} as unnamed time zone",
     );
-    match super::read_unnamed_tzif_file(path) {
-        Ok(tz) => Some(tz),
-        Err(_err) => {
-            trace!("failed to read {path} as unnamed time zone: {_err}");
-            None
-        }
-    }
+    super::read_unnamed_tzif_file(path)
 }
 
 /// Attempt to determine the time zone name from the symlink path given.
@@ -76,7 +79,7 @@ fn read_link_to_zoneinfo(
         "extracted {name:?} from symlink target {target:?} \
          for path {path:?} and assuming it is an IANA time zone name",
     );
-    let tz = match db.get(&name) {
+    let tz = match db.get(name) {
         Ok(tz) => tz,
         Err(_err) => {
             trace!(
@@ -94,6 +97,8 @@ fn read_link_to_zoneinfo(
 #[cfg(not(miri))]
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use super::*;
 
     #[test]
@@ -112,6 +117,17 @@ mod tests {
         // successfully constructed. Presumably this may fail in certain
         // environments, but hopefully the `is_definitively_empty` check above
         // will filter most out.
-        assert!(get(db).is_some());
+        assert!(get(db).is_ok());
+    }
+
+    /// Tests that failing to read a missing TZif file reports the path.
+    #[test]
+    fn read_missing_file_reports_path() {
+        let _ = crate::logging::Logger::init();
+
+        let db = crate::tz::db();
+        let path = "/jiff/does/not/exist/localtime";
+        let err = read(db, path).unwrap_err();
+        assert!(err.to_string().contains(path), "{err}");
     }
 }
@@ -24,8 +24,8 @@ mod windows_zones;
 /// API, and mapping the time zone key name returned to an IANA time zone
 /// name via the [CLDR XML data].
 ///
-/// If the API call fails or a valid mapping could not be found, then `None`
-/// is returned and some log messages are emitted.
+/// If the API call fails or a valid mapping could not be found, then an
+/// error is returned.
 ///
 /// Windows does provide a [WinRT GetTimeZone] call that will return the IANA
 /// time zone name directly, but it looks like a mess to use WinRT from Rust
@@ -33,45 +33,27 @@ mod windows_zones;
 ///
 /// [CLDR XML data]: https://github.com/unicode-org/cldr/raw/main/common/supplemental/windowsZones.xml
 /// [WinRT GetTimeZone]: https://learn.microsoft.com/en-us/uwp/api/windows.globalization.calendar.gettimezone?view=winrt-22621
-pub(super) fn get(db: &TimeZoneDatabase) -> Option<TimeZone> {
-    let tz_key_name = match get_tz_key_name() {
-        Ok(tz_key_name) => tz_key_name,
-        Err(_err) => {
-            warn!(
-                "failed to discover current time zone via \
-                 winapi GetDynamicTimeZoneInformation: {_err}",
-            );
-            return None;
-        }
-    };
-    let iana_name = match windows_to_iana(&tz_key_name) {
-        Ok(iana_name) => iana_name,
-        Err(_err) => {
-            warn!("could not find IANA time zone name: {_err}");
-            return None;
-        }
-    };
-    let tz = match db.get(iana_name) {
-        Ok(tz) => tz,
-        Err(_err) => {
-            warn!(
-                "could not find mapped IANA time zone {iana_name} \
-                 in zoneinfo database {db:?}: {_err}",
-            );
-            return None;
-        }
-    };
-    Some(tz)
+pub(super) fn get(db: &TimeZoneDatabase) -> Result<TimeZone, Error> {
+    let tz_key_name = get_tz_key_name().context(
+        "failed to discover current time zone via \
+         winapi GetDynamicTimeZoneInformation",
+    )?;
+    let iana_name = windows_to_iana(&tz_key_name)
+        .context("could not find IANA time zone name")?;
+    let tz = db.get(iana_name).with_context(|| {
+        err!(
+            "could not find mapped IANA time zone {iana_name} \
+             in zoneinfo database {db:?}",
+        )
+    })?;
+    Ok(tz)
 }
 
-pub(super) fn read(_db: &TimeZoneDatabase, path: &str) -> Option<TimeZone> {
-    match super::read_unnamed_tzif_file(path) {
-        Ok(tz) => Some(tz),
-        Err(_err) => {
-            trace!("failed to read {path} as unnamed time zone: {_err}");
-            None
-        }
-    }
+pub(super) fn read(
+    _db: &TimeZoneDatabase,
+    path: &str,
+) -> Result<TimeZone, Error> {
+    super::read_unnamed_tzif_file(path)
 }
 
 fn windows_to_iana(tz_key_name: &str) -> Result<&'static str, Error> {
@@ -148,6 +130,6 @@ mod tests {
         // successfully constructed. Presumably this may fail in certain
         // environments, but hopefully the `is_definitively_empty` check above
         // will filter most out.
-        assert!(get(db).is_some());
+        assert!(get(db).is_ok());
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
     civil::DateTime,
-    error::{err, Error},
+    error::{err, Error, ErrorContext},
     tz::{
         ambiguous::{AmbiguousOffset, AmbiguousTimestamp, AmbiguousZoned},
+        links::LINK_TO_CANONICAL,
         offset::{Dst, Offset},
     },
-    util::{array_str::ArrayStr, sync::Arc},
+    util::{array_str::ArrayStr, sync::Arc, utf8},
     Timestamp, Zoned,
 };
 
@@ -125,12 +126,26 @@ use self::repr::Repr;
 ///
 /// The equality semantics are as follows:
 ///
+/// * Two time zones for which [`TimeZone::is_utc`] returns `true` are equal.
+/// For example, `TimeZone::UTC`, `TimeZone::fixed(Offset::UTC)`,
+/// `TimeZone::get("UTC")` and `TimeZone::get("Etc/UTC")` are all equal.
 /// * Two fixed offset time zones are equal when their offsets are equal.
 /// * Two POSIX time zones are equal when their original rule strings are
 /// byte-for-byte identical.
 /// * Two IANA time zones are equal when their identifiers are equal _and_
-/// checksums of their rules are equal.
-/// * In all other cases, time zones are unequal.
+/// checksums of their rules are equal. This is true regardless of how the
+/// time zones were loaded. For example, two separate calls to
+/// `TimeZone::get("America/New_York")` return equal time zones, and a time
+/// zone embedded into a binary via `jiff::tz::get!` is equal to the same
+/// time zone loaded from a database at runtime (so long as the rules are
+/// identical).
+/// * In all other cases, time zones are unequal. In particular, aliases like
+/// `US/Eastern` and `America/New_York` are unequal, even though they have
+/// the same rules. Use [`TimeZone::canonical_name`] if you need to treat
+/// aliases as the same time zone.
+///
+/// This makes it possible to, for example, de-duplicate time zones with a
+/// simple linear scan. (`TimeZone` does not implement `Hash`.)
 ///
 /// Time zone equality is, for example, used in APIs like [`Zoned::since`]
 /// when asking for spans with calendar units. Namely, since days can be of
@@ -247,7 +262,7 @@ use self::repr::Repr;
 /// [`env_logger`]: https://docs.rs/env_logger
 /// [RFC 8536]: https://datatracker.ietf.org/doc/html/rfc8536
 /// [`with` attribute]: https://serde.rs/field-attrs.html#with
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct TimeZone {
     repr: Repr,
 }
@@ -396,10 +411,45 @@ impl TimeZone {
         }
     }
 
+    /// Clears the cached system time zone.
+    ///
+    /// Detecting the system time zone can require reading files or making
+    /// system calls, so [`TimeZone::system`] and [`TimeZone::try_system`]
+    /// cache their result for a few minutes. This means that changes to the
+    /// system time zone, or to the `TZ` environment variable, may not be
+    /// observed immediately. Calling this routine forces the next call to
+    /// `TimeZone::system` or `TimeZone::try_system` to detect the system time
+    /// zone anew.
+    ///
+    /// When the `tz-system` crate feature is not enabled, this does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    ///
+    /// let tz1 = TimeZone::system();
+    /// // ... the system time zone is changed ...
+    /// TimeZone::refresh_system();
+    /// let tz2 = TimeZone::system();
+    /// # let _ = (tz1, tz2);
+    /// ```
+    #[inline]
+    pub fn refresh_system() {
+        #[cfg(feature = "tz-system")]
+        {
+            crate::tz::system::reset();
+        }
+    }
+
     /// A convenience function for performing a time zone database lookup for
     /// the given time zone identifier. It uses the default global time zone
     /// database via [`tz::db()`](crate::tz::db()).
     ///
+    /// The lookup is performed without regard to ASCII case. The time zone
+    /// returned always uses the identifier as it is written in the database,
+    /// so [`TimeZone::iana_name`] can be used to normalize user input.
+    ///
     /// # Errors
     ///
     /// This returns an error if the given time zone identifier could not be
@@ -418,9 +468,42 @@ impl TimeZone {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// # Example: case insensitive lookups
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    ///
+    /// let tz = TimeZone::get("AMERICA/NEW_YORK")?;
+    /// assert_eq!(tz.iana_name(), Some("America/New_York"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: passing an owned name
+    ///
+    /// Any type that implements `AsRef<str>` can be used as a time zone
+    /// name. The name is only borrowed. It is copied into the error returned
+    /// when the lookup fails, but a successful lookup of a time zone that
+    /// has already been loaded does not allocate.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use jiff::tz::TimeZone;
+    ///
+    /// let name = String::from("Europe/Paris");
+    /// let tz1 = TimeZone::get(&name)?;
+    /// let tz2 = TimeZone::get(Cow::Borrowed("Europe/Paris"))?;
+    /// let tz3 = TimeZone::get(name)?;
+    /// assert_eq!(tz1, tz2);
+    /// assert_eq!(tz2, tz3);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[inline]
-    pub fn get(time_zone_name: &str) -> Result<TimeZone, Error> {
-        crate::tz::db().get(time_zone_name)
+    pub fn get<N: AsRef<str>>(time_zone_name: N) -> Result<TimeZone, Error> {
+        crate::tz::db().get(time_zone_name.as_ref())
     }
 
     /// Returns a time zone with a fixed offset.
@@ -517,24 +600,159 @@ impl TimeZone {
     /// [`TimeZone::get`]. However, this constructor does provide one way
     /// of using custom time zones with Jiff.
     ///
-    /// The name given should be a IANA time zone database identifier.
+    /// The name given should be a IANA time zone database identifier. It is
+    /// attached to the time zone returned and is available via
+    /// [`TimeZone::iana_name`].
+    ///
+    /// For TZif version 2 and later, the 64-bit data block is used, so
+    /// transitions outside the range of 32-bit timestamps are supported.
+    /// Any transitions after the last one in the data block are computed
+    /// from the POSIX time zone string in the TZif footer.
     ///
     /// [RFC 8536]: https://datatracker.ietf.org/doc/html/rfc8536
     ///
     /// # Errors
     ///
     /// This returns an error if the given data was not recognized as valid
-    /// TZif.
+    /// TZif. This includes data that is internally inconsistent, such as
+    /// transitions that aren't in strictly ascending order or transitions
+    /// that refer to a local time type that doesn't exist. The error
+    /// returned includes the name given.
+    ///
+    /// # Example
+    ///
+    /// This shows how to create a time zone from a single TZif file, without
+    /// going through a [`TimeZoneDatabase`](crate::tz::TimeZoneDatabase):
+    ///
+    /// ```no_run
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let data = std::fs::read("/usr/share/zoneinfo/America/New_York")?;
+    /// let tz = TimeZone::tzif("America/New_York", &data)?;
+    /// assert_eq!(tz.iana_name(), Some("America/New_York"));
+    ///
+    /// let zdt = date(2024, 7, 1).at(12, 0, 0, 0).to_zoned(tz)?;
+    /// assert_eq!(
+    ///     zdt.to_string(),
+    ///     "2024-07-01T12:00:00-04:00[America/New_York]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[cfg(feature = "alloc")]
     pub fn tzif(name: &str, data: &[u8]) -> Result<TimeZone, Error> {
         use alloc::string::ToString;
 
-        let name = name.to_string();
-        let tzif = crate::tz::tzif::Tzif::parse(Some(name), data)?;
+        let tzif = crate::tz::tzif::Tzif::parse(Some(name.to_string()), data)
+            .with_context(|| {
+                err!("failed to parse TZif data for time zone {name:?}")
+            })?;
         let repr = Repr::arc_tzif(Arc::new(tzif));
         Ok(TimeZone { repr })
     }
 
+    /// Creates a time zone from an explicit sequence of transitions.
+    ///
+    /// Each transition is a tuple of the timestamp at which it takes effect,
+    /// along with the offset, abbreviation and whether it is daylight saving
+    /// time. Each transition applies from its timestamp (inclusive) until the
+    /// timestamp of the next transition (exclusive). The last transition
+    /// applies to all times after it.
+    ///
+    /// This is useful for simulations and testing, or for modeling
+    /// hypothetical or historical rules that aren't in the Time Zone
+    /// Database. Otherwise, prefer [`TimeZone::get`].
+    ///
+    /// Before the first transition, the offset is zero with the abbreviation
+    /// `-00`. (This is the convention used by the Time Zone Database for when
+    /// local time is unspecified.) To set the offset used before the first
+    /// transition, include a transition at [`Timestamp::MIN`].
+    ///
+    /// The name given is attached to the time zone returned and is available
+    /// via [`TimeZone::iana_name`]. Two time zones created by this routine
+    /// are equal when they have the same name and the same transitions.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when:
+    ///
+    /// * No transitions are given.
+    /// * The transitions are not in strictly ascending order by timestamp.
+    /// * A transition timestamp has a non-zero fractional second.
+    /// * An abbreviation is empty or contains a NUL byte.
+    /// * There are too many distinct combinations of offset, abbreviation
+    /// and DST status, or the abbreviations are too long in total. (The
+    /// limits are the same as for TZif data.)
+    ///
+    /// The error returned includes the name given.
+    ///
+    /// # Example
+    ///
+    /// This creates a time zone that observes DST during the summer of 2025,
+    /// but not afterwards:
+    ///
+    /// ```
+    /// use jiff::{
+    ///     civil::date,
+    ///     tz::{self, AmbiguousOffset, TimeZone},
+    ///     Timestamp,
+    /// };
+    ///
+    /// let tz = TimeZone::from_transitions("Custom/Test", [
+    ///     (Timestamp::MIN, tz::offset(-5), "EST", false),
+    ///     ("2025-03-09T07:00Z".parse()?, tz::offset(-4), "EDT", true),
+    ///     ("2025-11-02T06:00Z".parse()?, tz::offset(-5), "EST", false),
+    /// ])?;
+    /// assert_eq!(tz.iana_name(), Some("Custom/Test"));
+    ///
+    /// let zdt = date(2025, 1, 1).at(12, 0, 0, 0).to_zoned(tz.clone())?;
+    /// assert_eq!(zdt.offset(), tz::offset(-5));
+    /// let zdt = date(2025, 7, 1).at(12, 0, 0, 0).to_zoned(tz.clone())?;
+    /// assert_eq!(zdt.offset(), tz::offset(-4));
+    /// let info = tz.to_offset_info(zdt.timestamp());
+    /// assert_eq!(info.abbreviation(), "EDT");
+    ///
+    /// // This time is skipped when DST begins.
+    /// let dt = date(2025, 3, 9).at(2, 30, 0, 0);
+    /// assert_eq!(
+    ///     tz.to_ambiguous_zoned(dt).offset(),
+    ///     AmbiguousOffset::Gap { before: tz::offset(-5), after: tz::offset(-4) },
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_transitions<'a>(
+        name: &str,
+        transitions: impl IntoIterator<Item = (Timestamp, Offset, &'a str, bool)>,
+    ) -> Result<TimeZone, Error> {
+        use alloc::{string::ToString, vec::Vec};
+
+        let transitions = transitions
+            .into_iter()
+            .map(|(timestamp, offset, abbrev, is_dst)| {
+                if timestamp.subsec_nanosecond() != 0 {
+                    return Err(err!(
+                        "transition timestamp {timestamp} has a non-zero \
+                         fractional second",
+                    ));
+                }
+                Ok((timestamp.as_second(), offset.seconds(), abbrev, is_dst))
+            })
+            .collect::<Result<Vec<_>, Error>>()
+            .and_then(|transitions| {
+                crate::tz::tzif::Tzif::from_transitions(
+                    Some(name.to_string()),
+                    transitions,
+                )
+            })
+            .with_context(|| {
+                err!("failed to create time zone {name:?} from transitions")
+            })?;
+        let repr = Repr::arc_tzif(Arc::new(transitions));
+        Ok(TimeZone { repr })
+    }
+
     /// Returns a `TimeZone` that is specifially marked as "unknown."
     ///
     /// This corresponds to the Unicode CLDR identifier `Etc/Unknown`, which
@@ -668,6 +886,59 @@ impl TimeZone {
         }
     }
 
+    /// When this time zone was loaded from an IANA time zone database entry,
+    /// then this returns the canonical IANA identifier for that time zone.
+    ///
+    /// This differs from [`TimeZone::iana_name`] when the time zone was loaded
+    /// via an alias (called a "link" in the IANA Time Zone Database). For
+    /// example, `US/Eastern` is a link to `America/New_York`. In that case,
+    /// `iana_name` returns `US/Eastern` while this returns `America/New_York`.
+    /// When the time zone was loaded via a canonical identifier, then this
+    /// returns the same value as `iana_name`.
+    ///
+    /// This is useful for de-duplicating time zones that were selected via
+    /// different aliases, or for storing canonical identifiers.
+    ///
+    /// Links are resolved via a table bundled with Jiff, and not via the
+    /// time zone database the time zone was loaded from. This is because
+    /// the TZif data format doesn't record whether an identifier is a link.
+    /// This means that links introduced in releases of the IANA Time Zone
+    /// Database that are newer than Jiff's bundled table will be returned
+    /// as-is.
+    ///
+    /// Like `iana_name`, this returns `None` for fixed offset time zones,
+    /// time zones created from POSIX TZ strings and the unknown time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    ///
+    /// let tz = TimeZone::get("US/Eastern")?;
+    /// assert_eq!(tz.iana_name(), Some("US/Eastern"));
+    /// assert_eq!(tz.canonical_name(), Some("America/New_York"));
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// assert_eq!(tz.iana_name(), Some("America/New_York"));
+    /// assert_eq!(tz.canonical_name(), Some("America/New_York"));
+    ///
+    /// let tz = TimeZone::fixed(jiff::tz::offset(-5));
+    /// assert_eq!(tz.canonical_name(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn canonical_name(&self) -> Option<&str> {
+        let name = self.iana_name()?;
+        let result = LINK_TO_CANONICAL.binary_search_by(|(link, _)| {
+            utf8::cmp_ignore_ascii_case(link, name)
+        });
+        match result {
+            Ok(index) => Some(LINK_TO_CANONICAL[index].1),
+            Err(_) => Some(name),
+        }
+    }
+
     /// Returns true if and only if this time zone is unknown.
     ///
     /// This has the special internal identifier of `Etc/Unknown`, and this
@@ -695,6 +966,175 @@ impl TimeZone {
         self.repr.is_unknown()
     }
 
+    /// Returns true if and only if this time zone is UTC.
+    ///
+    /// This returns true for [`TimeZone::UTC`], a fixed offset time zone with
+    /// a zero offset and any IANA time zone whose canonical identifier is
+    /// `Etc/UTC`. For example, `UTC`, `Etc/UTC`, `Etc/Universal` and `Zulu`.
+    /// In the latter case, the rules of the time zone must also be those of
+    /// UTC. So for example, a time zone named `UTC` that was created from
+    /// TZif data with a non-zero offset is not UTC.
+    ///
+    /// Notably, this returns false for IANA time zones that always have a
+    /// zero offset but are not UTC, like `Etc/GMT`. It also returns false for
+    /// POSIX time zones.
+    ///
+    /// All time zones for which this returns true are
+    /// [equal](TimeZone#time-zone-equality) to one another.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::{self, TimeZone};
+    ///
+    /// assert!(TimeZone::UTC.is_utc());
+    /// assert!(TimeZone::fixed(tz::offset(0)).is_utc());
+    /// assert!(TimeZone::get("UTC")?.is_utc());
+    /// assert!(TimeZone::get("Etc/Zulu")?.is_utc());
+    /// assert_eq!(TimeZone::get("UTC")?, TimeZone::UTC);
+    ///
+    /// assert!(!TimeZone::get("Etc/GMT")?.is_utc());
+    /// assert!(!TimeZone::get("America/New_York")?.is_utc());
+    /// assert!(!TimeZone::unknown().is_utc());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_utc(&self) -> bool {
+        repr::each! {
+            &self.repr,
+            UTC => true,
+            UNKNOWN => false,
+            FIXED(offset) => offset == Offset::UTC,
+            // Checking the rules first is cheap, and in the common case of
+            // a time zone that isn't UTC, avoids looking up its name in the
+            // table of links.
+            STATIC_TZIF(tzif) => {
+                tzif.has_utc_rules()
+                    && self.canonical_name().is_some_and(|name| {
+                        utf8::cmp_ignore_ascii_case(name, "Etc/UTC").is_eq()
+                    })
+            },
+            ARC_TZIF(tzif) => {
+                tzif.has_utc_rules()
+                    && self.canonical_name().is_some_and(|name| {
+                        utf8::cmp_ignore_ascii_case(name, "Etc/UTC").is_eq()
+                    })
+            },
+            ARC_POSIX(_posix) => false,
+        }
+    }
+
+    /// Returns the IANA identifier and rule checksum of this time zone if
+    /// it was created from TZif data.
+    ///
+    /// This is used to compare TZif time zones for equality regardless of
+    /// how they are represented internally.
+    fn tzif_identity(&self) -> Option<(&str, u32)> {
+        repr::each! {
+            &self.repr,
+            UTC => None,
+            UNKNOWN => None,
+            FIXED(_offset) => None,
+            STATIC_TZIF(tzif) => Some((tzif.name()?, tzif.checksum())),
+            ARC_TZIF(tzif) => Some((tzif.name()?, tzif.checksum())),
+            ARC_POSIX(_posix) => None,
+        }
+    }
+
+    /// Returns the number of time zone transitions explicitly recorded in
+    /// this time zone's data.
+    ///
+    /// For time zones created from TZif data (which includes all time zones
+    /// returned by the default time zone database), this is the number of
+    /// historical transitions in that data. Transitions that are computed
+    /// from the POSIX time zone rule used for instants after the last
+    /// recorded transition are not counted, since there are logically an
+    /// unbounded number of them.
+    ///
+    /// For all other kinds of time zones (UTC, fixed offset and POSIX time
+    /// zones), this always returns `0`.
+    ///
+    /// This is mostly useful for diagnostics. To actually inspect the
+    /// transitions in a time zone, use [`TimeZone::preceding`] or
+    /// [`TimeZone::following`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::{self, TimeZone};
+    ///
+    /// let tz = TimeZone::get("America/New_York")?;
+    /// assert!(tz.transition_count() > 0);
+    ///
+    /// assert_eq!(TimeZone::UTC.transition_count(), 0);
+    /// assert_eq!(TimeZone::fixed(tz::offset(-5)).transition_count(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn transition_count(&self) -> usize {
+        repr::each! {
+            &self.repr,
+            UTC => 0,
+            UNKNOWN => 0,
+            FIXED(_offset) => 0,
+            STATIC_TZIF(tzif) => tzif.transition_count(),
+            ARC_TZIF(tzif) => tzif.transition_count(),
+            ARC_POSIX(_posix) => 0,
+        }
+    }
+
+    /// Returns an approximation of the number of bytes of heap memory used
+    /// by this time zone.
+    ///
+    /// This is meant for getting a rough idea of the memory footprint of
+    /// time zones, for example, when many of them are cached. The number
+    /// returned is only an estimate. It doesn't account for allocator
+    /// overhead, and the precise accounting may change in semver compatible
+    /// releases.
+    ///
+    /// Note that a `TimeZone` is cheap to clone because its data is shared.
+    /// So the memory reported here is shared by all clones of this time
+    /// zone, and summing this value over many clones of the same time zone
+    /// will overestimate total memory usage.
+    ///
+    /// UTC, fixed offset and unknown time zones never use heap memory, and
+    /// neither do time zones embedded into the binary at compile time. In
+    /// those cases, this returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::tz::TimeZone;
+    ///
+    /// assert_eq!(TimeZone::UTC.heap_size(), 0);
+    ///
+    /// let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0")?;
+    /// assert!(tz.heap_size() > 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn heap_size(&self) -> usize {
+        repr::each! {
+            &self.repr,
+            UTC => 0,
+            UNKNOWN => 0,
+            FIXED(_offset) => 0,
+            STATIC_TZIF(_tzif) => 0,
+            // For the `Arc` variants, we include the strong and weak
+            // reference counts stored alongside the data.
+            ARC_TZIF(tzif) => {
+                2 * core::mem::size_of::<usize>() + tzif.heap_size()
+            },
+            ARC_POSIX(_posix) => {
+                2 * core::mem::size_of::<usize>()
+                    + core::mem::size_of::<PosixTimeZoneOwned>()
+            },
+        }
+    }
+
     /// When this time zone is a POSIX time zone, return it.
     ///
     /// This doesn't attempt to convert other time zones that are representable
@@ -792,6 +1232,11 @@ impl TimeZone {
     /// ```
     #[inline]
     pub fn to_offset(&self, timestamp: Timestamp) -> Offset {
+        // UTC, unknown and fixed offset time zones return their offset
+        // directly, without searching any transitions. So things like
+        // `Timestamp::to_zoned` with `TimeZone::UTC` are already cheap, and
+        // don't need their own fast path. (See the `timestamp/to_zoned`
+        // benchmarks.)
         repr::each! {
             &self.repr,
             UTC => Offset::UTC,
@@ -1425,6 +1870,24 @@ impl TimeZone {
     }
 }
 
+impl Eq for TimeZone {}
+
+impl PartialEq for TimeZone {
+    #[inline]
+    fn eq(&self, rhs: &TimeZone) -> bool {
+        let (lhs_utc, rhs_utc) = (self.is_utc(), rhs.is_utc());
+        if lhs_utc || rhs_utc {
+            return lhs_utc && rhs_utc;
+        }
+        if let (Some(lhs), Some(rhs)) =
+            (self.tzif_identity(), rhs.tzif_identity())
+        {
+            return lhs == rhs;
+        }
+        self.repr == rhs.repr
+    }
+}
+
 impl core::fmt::Debug for TimeZone {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -2463,7 +2926,7 @@ mod repr {
 mod tests {
     #[cfg(feature = "alloc")]
     use crate::tz::testdata::TzifTestFile;
-    use crate::{civil::date, tz::offset};
+    use crate::{civil::date, tz::offset, SignedDuration};
 
     use super::*;
 
@@ -2502,6 +2965,251 @@ mod tests {
         AmbiguousOffset::Fold { before: earlier, after: later }
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_transition_count_and_heap_size() {
+        let test_file = TzifTestFile::get("America/New_York");
+        let tz = TimeZone::tzif(test_file.name, test_file.data).unwrap();
+        // New York has a long history of DST changes, so we expect a few
+        // hundred transitions. We don't assert an exact number since it
+        // depends on the particular TZif data.
+        let count = tz.transition_count();
+        assert!(100 < count && count < 1_000, "{count}");
+        assert!(
+            tz.heap_size() > count * core::mem::size_of::<i64>(),
+            "{}",
+            tz.heap_size(),
+        );
+        // Clones share the same data.
+        assert_eq!(tz.clone().heap_size(), tz.heap_size());
+
+        let test_file = TzifTestFile::get("UTC");
+        let tz = TimeZone::tzif(test_file.name, test_file.data).unwrap();
+        assert_eq!(tz.transition_count(), 0);
+
+        let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(tz.transition_count(), 0);
+        assert!(tz.heap_size() > 0);
+
+        assert_eq!(TimeZone::UTC.transition_count(), 0);
+        assert_eq!(TimeZone::UTC.heap_size(), 0);
+        assert_eq!(TimeZone::unknown().heap_size(), 0);
+        assert_eq!(TimeZone::fixed(offset(5)).heap_size(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_ambiguous_offset_duration() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let tz = TimeZone::get("Australia/Lord_Howe").unwrap();
+        let gap = tz.to_ambiguous_timestamp(date(2024, 10, 6).at(2, 15, 0, 0));
+        assert!(gap.is_ambiguous());
+        assert_eq!(gap.offset().duration(), SignedDuration::from_mins(30));
+        let fold = tz.to_ambiguous_timestamp(date(2024, 4, 7).at(1, 45, 0, 0));
+        assert!(fold.is_ambiguous());
+        assert_eq!(fold.offset().duration(), SignedDuration::from_mins(30));
+        let ok = tz.to_ambiguous_timestamp(date(2024, 7, 1).at(12, 0, 0, 0));
+        assert_eq!(ok.offset().duration(), SignedDuration::ZERO);
+
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let gap = tz.to_ambiguous_timestamp(date(2024, 3, 10).at(2, 30, 0, 0));
+        assert_eq!(gap.offset().duration(), SignedDuration::from_hours(1));
+        let fold =
+            tz.to_ambiguous_timestamp(date(2024, 11, 3).at(1, 30, 0, 0));
+        assert_eq!(fold.offset().duration(), SignedDuration::from_hours(1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_from_transitions() {
+        use alloc::string::ToString;
+
+        let ts = |second| Timestamp::from_second(second).unwrap();
+        let tz = TimeZone::from_transitions(
+            "Custom/Test",
+            [
+                (ts(1_000), offset(2), "AAA", false),
+                (ts(2_000), offset(3), "BBB", true),
+            ],
+        )
+        .unwrap();
+        assert_eq!(tz.iana_name(), Some("Custom/Test"));
+
+        let info = |second| {
+            let info = tz.to_offset_info(ts(second));
+            (
+                info.offset(),
+                info.abbreviation().to_string(),
+                info.dst().is_dst(),
+            )
+        };
+        assert_eq!(info(999), (offset(0), "-00".to_string(), false));
+        assert_eq!(info(1_000), (offset(2), "AAA".to_string(), false));
+        assert_eq!(info(1_999), (offset(2), "AAA".to_string(), false));
+        assert_eq!(info(2_000), (offset(3), "BBB".to_string(), true));
+        assert_eq!(
+            info(i64::from(i32::MAX)),
+            (offset(3), "BBB".to_string(), true)
+        );
+        assert_eq!(tz.to_offset(Timestamp::MIN), offset(0));
+        assert_eq!(tz.to_offset(Timestamp::MAX), offset(3));
+
+        // Transitions are usable for zoned datetimes too. The first
+        // transition creates a gap of 2 hours, and the second a gap of 1.
+        let zdt = ts(1_000).to_zoned(tz.clone());
+        assert_eq!(zdt.datetime(), date(1970, 1, 1).at(2, 16, 40, 0));
+        let dt = date(1970, 1, 1).at(0, 16, 40, 0);
+        assert_eq!(tz.to_ambiguous_timestamp(dt).offset(), gap(0, 2));
+        let dt = date(1970, 1, 1).at(2, 33, 20, 0);
+        assert_eq!(tz.to_ambiguous_timestamp(dt).offset(), gap(2, 3));
+
+        // Transitions before the first one can be given explicitly.
+        let tz2 = TimeZone::from_transitions(
+            "Custom/Test",
+            [
+                (Timestamp::MIN, offset(1), "ZZZ", false),
+                (ts(1_000), offset(2), "AAA", false),
+                (ts(2_000), offset(3), "BBB", true),
+            ],
+        )
+        .unwrap();
+        assert_eq!(tz2.to_offset(Timestamp::MIN), offset(1));
+        assert_eq!(tz2.to_offset(ts(999)), offset(1));
+        assert_eq!(tz2.to_offset(ts(1_000)), offset(2));
+
+        // Equality is determined by the name and the transitions.
+        let tz3 = TimeZone::from_transitions(
+            "Custom/Test",
+            [
+                (ts(1_000), offset(2), "AAA", false),
+                (ts(2_000), offset(3), "BBB", true),
+            ],
+        )
+        .unwrap();
+        assert_eq!(tz, tz3);
+        assert_ne!(tz, tz2);
+
+        // Naming a time zone `UTC` doesn't make it equal to UTC. Its rules
+        // need to be UTC too.
+        for name in ["UTC", "Etc/UTC"] {
+            let tz = TimeZone::from_transitions(
+                name,
+                [(Timestamp::MIN, offset(5), "XXX", false)],
+            )
+            .unwrap();
+            assert_eq!(tz.to_offset(Timestamp::UNIX_EPOCH), offset(5));
+            assert!(!tz.is_utc(), "{name}");
+            assert_ne!(tz, TimeZone::UTC, "{name}");
+
+            let tz = TimeZone::from_transitions(
+                name,
+                [(Timestamp::MIN, offset(0), "UTC", false)],
+            )
+            .unwrap();
+            assert!(tz.is_utc(), "{name}");
+            assert_eq!(tz, TimeZone::UTC, "{name}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_from_transitions_invalid() {
+        let ts = |second| Timestamp::from_second(second).unwrap();
+        let from = |transitions: &[(Timestamp, Offset, &str, bool)]| {
+            TimeZone::from_transitions("Custom/Test", transitions.to_vec())
+                .unwrap_err()
+        };
+
+        insta::assert_snapshot!(from(&[]), @"failed to create time zone \"Custom/Test\" from transitions: at least one transition is required");
+        insta::assert_snapshot!(
+            from(&[
+                (ts(2_000), offset(2), "AAA", false),
+                (ts(1_000), offset(3), "BBB", true),
+            ]),
+            @"failed to create time zone \"Custom/Test\" from transitions: found transition at Unix timestamp 1000 that does not come after the previous transition at Unix timestamp 2000, but transitions must be in strictly ascending order",
+        );
+        insta::assert_snapshot!(
+            from(&[
+                (ts(1_000), offset(2), "AAA", false),
+                (ts(1_000), offset(3), "BBB", true),
+            ]),
+            @"failed to create time zone \"Custom/Test\" from transitions: found transition at Unix timestamp 1000 that does not come after the previous transition at Unix timestamp 1000, but transitions must be in strictly ascending order",
+        );
+        insta::assert_snapshot!(
+            from(&[(Timestamp::new(1, 5).unwrap(), offset(2), "AAA", false)]),
+            @"failed to create time zone \"Custom/Test\" from transitions: transition timestamp 1970-01-01T00:00:01.000000005Z has a non-zero fractional second",
+        );
+        insta::assert_snapshot!(
+            from(&[(ts(1_000), offset(2), "", false)]),
+            @"failed to create time zone \"Custom/Test\" from transitions: found transition with invalid abbreviation \"\": abbreviations must be non-empty and not contain NUL",
+        );
+        insta::assert_snapshot!(
+            from(&[(ts(1_000), offset(2), "A\x00A", false)]),
+            @"failed to create time zone \"Custom/Test\" from transitions: found transition with invalid abbreviation \"A\\0A\": abbreviations must be non-empty and not contain NUL",
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_equality() {
+        assert_eq!(TimeZone::UTC, TimeZone::fixed(offset(0)));
+        assert_ne!(TimeZone::UTC, TimeZone::fixed(offset(1)));
+        assert_ne!(TimeZone::UTC, TimeZone::unknown());
+        assert_eq!(TimeZone::unknown(), TimeZone::unknown());
+        assert!(TimeZone::UTC.is_utc());
+        assert!(TimeZone::fixed(offset(0)).is_utc());
+        assert!(!TimeZone::fixed(offset(1)).is_utc());
+        assert!(!TimeZone::unknown().is_utc());
+
+        // A TZif time zone is only UTC when its rules are, regardless of the
+        // name it was given.
+        let data = TzifTestFile::get("UTC").data;
+        let utc = TimeZone::tzif("UTC", data).unwrap();
+        assert!(utc.is_utc());
+        assert_eq!(utc, TimeZone::UTC);
+        let data = TzifTestFile::get("America/New_York").data;
+        let not_utc = TimeZone::tzif("Etc/UTC", data).unwrap();
+        assert!(!not_utc.is_utc());
+        assert_ne!(not_utc, TimeZone::UTC);
+        assert_ne!(TimeZone::UTC, not_utc);
+        assert_ne!(not_utc, utc);
+        // Names are compared case insensitively.
+        let data = TzifTestFile::get("UTC").data;
+        let lower = TimeZone::tzif("etc/utc", data).unwrap();
+        assert!(lower.is_utc());
+        assert_eq!(lower, TimeZone::UTC);
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let utc1 = TimeZone::get("UTC").unwrap();
+        let utc2 = TimeZone::get("Etc/UTC").unwrap();
+        assert!(utc1.is_utc());
+        assert!(utc2.is_utc());
+        assert_eq!(utc1, utc2);
+        assert_eq!(utc1, TimeZone::UTC);
+        assert_eq!(TimeZone::UTC, utc2);
+        assert_eq!(utc1, TimeZone::fixed(offset(0)));
+
+        let gmt = TimeZone::get("Etc/GMT").unwrap();
+        assert!(!gmt.is_utc());
+        assert_ne!(gmt, TimeZone::UTC);
+
+        let ny1 = TimeZone::get("America/New_York").unwrap();
+        let ny2 = TimeZone::get("america/new_york").unwrap();
+        let london = TimeZone::get("Europe/London").unwrap();
+        assert!(!ny1.is_utc());
+        assert_eq!(ny1, ny2);
+        assert_eq!(ny1, ny1.clone());
+        assert_ne!(ny1, london);
+        assert_ne!(ny1, TimeZone::UTC);
+        assert_ne!(ny1, TimeZone::fixed(offset(-5)));
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn time_zone_tzif_to_ambiguous_timestamp() {
@@ -3421,6 +4129,84 @@ mod tests {
         assert!(tz.to_zoned(dt).is_err());
     }
 
+    /// Tests creating a time zone from a single TZif file, including
+    /// transitions from both the 64-bit data block and the POSIX footer.
+    #[cfg(all(feature = "alloc", not(miri)))]
+    #[test]
+    fn time_zone_tzif_single_file() {
+        let data = TzifTestFile::get("America/New_York").data;
+        let tz = TimeZone::tzif("America/New_York", data).unwrap();
+        assert_eq!(tz.iana_name(), Some("America/New_York"));
+
+        let check = |ts: &str, off, abbrev| {
+            let ts: Timestamp = ts.parse().unwrap();
+            let info = tz.to_offset_info(ts);
+            assert_eq!(info.offset(), Offset::from_seconds(off).unwrap());
+            assert_eq!(info.abbreviation(), abbrev, "{ts}");
+        };
+        // Switch from local mean time to EST in 1883. This is before the
+        // minimum 32-bit timestamp, so it is only in the 64-bit data block.
+        check("1883-11-18T16:59:59Z", -17_762, "LMT");
+        check("1883-11-18T17:00:00Z", -5 * 60 * 60, "EST");
+        // A transition in the data block.
+        check("2024-03-10T06:59:59Z", -5 * 60 * 60, "EST");
+        check("2024-03-10T07:00:00Z", -4 * 60 * 60, "EDT");
+        // A transition computed from the POSIX time zone string.
+        check("2100-03-14T06:59:59Z", -5 * 60 * 60, "EST");
+        check("2100-03-14T07:00:00Z", -4 * 60 * 60, "EDT");
+
+        assert!(TimeZone::tzif("America/New_York", &data[..10]).is_err());
+    }
+
+    /// Tests that TZif data with inconsistent transitions is rejected with
+    /// an error that mentions the time zone name.
+    #[cfg(all(feature = "alloc", not(miri)))]
+    #[test]
+    fn time_zone_tzif_corrupt() {
+        // Returns the offset of the 64-bit transition times and the number of
+        // transitions in the given TZif data.
+        fn transitions64(data: &[u8]) -> (usize, usize) {
+            let count = |data: &[u8], i: usize| -> usize {
+                let start = 20 + i * 4;
+                let bytes = data[start..start + 4].try_into().unwrap();
+                usize::try_from(u32::from_be_bytes(bytes)).unwrap()
+            };
+            let (isut, isstd, leap, time, typ, chars) = (
+                count(data, 0),
+                count(data, 1),
+                count(data, 2),
+                count(data, 3),
+                count(data, 4),
+                count(data, 5),
+            );
+            let v1_len = time * 5 + typ * 6 + chars + leap * 8 + isstd + isut;
+            let header64 = 44 + v1_len;
+            (header64 + 44, count(&data[header64..], 3))
+        }
+
+        let data = TzifTestFile::get("America/New_York").data;
+        let (start, len) = transitions64(data);
+        assert!(len > 20);
+
+        // Swap the order of two transitions.
+        let mut corrupt = data.to_vec();
+        let (i, j) = (start + 10 * 8, start + 11 * 8);
+        let (first, second) = corrupt.split_at_mut(j);
+        first[i..i + 8].swap_with_slice(&mut second[..8]);
+        insta::assert_snapshot!(
+            TimeZone::tzif("America/New_York", &corrupt).unwrap_err(),
+            @"failed to parse TZif data for time zone \"America/New_York\": found transition at Unix timestamp -1491760800 that does not come after the previous transition at Unix timestamp -1473008400, but transitions must be in strictly ascending order",
+        );
+
+        // Make a transition point past the end of the local time types.
+        let mut corrupt = data.to_vec();
+        corrupt[start + len * 8 + 10] = 0xFF;
+        insta::assert_snapshot!(
+            TimeZone::tzif("America/New_York", &corrupt).unwrap_err(),
+            @"failed to parse TZif data for time zone \"America/New_York\": found transition type index 255, but there are only 6 local time types",
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn time_zone_tzif_previous_transition() {
@@ -3789,6 +4575,62 @@ mod tests {
         assert_eq!(info.abbreviation(), "UTC");
     }
 
+    /// Tests that lookups ignore ASCII case and always return the name as
+    /// it is written in the database.
+    #[test]
+    fn time_zone_get_case_insensitive() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let tests = [
+            ("america/new_york", "America/New_York"),
+            ("AMERICA/NEW_YORK", "America/New_York"),
+            ("America/new_York", "America/New_York"),
+            ("europe/london", "Europe/London"),
+            ("asia/kolkata", "Asia/Kolkata"),
+            ("utc", "UTC"),
+        ];
+        for (input, canonical) in tests {
+            let tz = TimeZone::get(input).unwrap();
+            assert_eq!(tz.iana_name(), Some(canonical), "{input}");
+        }
+        assert!(TimeZone::get("america/new-york").is_err());
+    }
+
+    /// Tests that the offset, abbreviation and DST status all change at
+    /// the precise instant of a time zone transition.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn time_zone_to_offset_info_transition() {
+        let check = |tz: &TimeZone, ts: &str, off, dst, abbrev| {
+            let ts: Timestamp = ts.parse().unwrap();
+            let info = tz.to_offset_info(ts);
+            assert_eq!(info.offset(), offset(off), "{ts}");
+            assert_eq!(info.dst(), dst, "{ts}");
+            assert_eq!(info.abbreviation(), abbrev, "{ts}");
+        };
+
+        let posix = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let mut zones = alloc::vec![posix];
+        if !crate::tz::db().is_definitively_empty() {
+            zones.push(TimeZone::get("America/New_York").unwrap());
+        }
+        for tz in zones.iter() {
+            check(tz, "2024-03-10T06:59:59.999999999Z", -5, Dst::No, "EST");
+            check(tz, "2024-03-10T07:00:00Z", -4, Dst::Yes, "EDT");
+            check(tz, "2024-11-03T05:59:59.999999999Z", -4, Dst::Yes, "EDT");
+            check(tz, "2024-11-03T06:00:00Z", -5, Dst::No, "EST");
+        }
+
+        let tz = TimeZone::fixed(
+            Offset::from_seconds(5 * 60 * 60 + 30 * 60).unwrap(),
+        );
+        let info = tz.to_offset_info(Timestamp::UNIX_EPOCH);
+        assert_eq!(info.dst(), Dst::No);
+        assert_eq!(info.abbreviation(), "+05:30");
+    }
+
     /// This tests a few other cases for `TimeZone::to_fixed_offset` that
     /// probably aren't worth showing in doctest examples.
     #[test]
@@ -3810,4 +4652,51 @@ mod tests {
             assert!(tz.to_fixed_offset().is_err());
         }
     }
+
+    /// Tests that aliases are resolved to their canonical names, and that
+    /// time zones without IANA names have no canonical name either.
+    #[test]
+    fn time_zone_canonical_name() {
+        let tz = TimeZone::UTC;
+        assert_eq!(tz.iana_name(), Some("UTC"));
+        assert_eq!(tz.canonical_name(), Some("Etc/UTC"));
+
+        let tz = TimeZone::fixed(offset(-5));
+        assert_eq!(tz.canonical_name(), None);
+        assert_eq!(TimeZone::unknown().canonical_name(), None);
+
+        #[cfg(feature = "alloc")]
+        {
+            let tz = TimeZone::posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+            assert_eq!(tz.canonical_name(), None);
+
+            let test_file = TzifTestFile::get("America/New_York");
+            let tz = TimeZone::tzif("US/Eastern", test_file.data).unwrap();
+            assert_eq!(tz.iana_name(), Some("US/Eastern"));
+            assert_eq!(tz.canonical_name(), Some("America/New_York"));
+
+            let tz = TimeZone::tzif(test_file.name, test_file.data).unwrap();
+            assert_eq!(tz.canonical_name(), Some("America/New_York"));
+        }
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        let tz = TimeZone::get("US/Eastern").unwrap();
+        assert_eq!(tz.canonical_name(), Some("America/New_York"));
+    }
+
+    /// Tests that the table of links is sorted, since `canonical_name` relies
+    /// on binary search.
+    #[test]
+    fn time_zone_links_sorted() {
+        for pair in crate::tz::links::LINK_TO_CANONICAL.windows(2) {
+            let (link1, link2) = (pair[0].0, pair[1].0);
+            assert_eq!(
+                utf8::cmp_ignore_ascii_case(link1, link2),
+                core::cmp::Ordering::Less,
+                "links {link1} and {link2} are not in sorted order",
+            );
+        }
+    }
 }
@@ -162,6 +162,21 @@ impl TzifOwned {
         Ok(TzifOwned::from_shared_owned(sh))
     }
 
+    /// Builds TZif data from an explicit sequence of transitions.
+    ///
+    /// Each transition is a Unix timestamp in seconds along with the offset,
+    /// abbreviation and DST status that apply at and after that timestamp.
+    /// If the transitions are not in strictly ascending order, or if there
+    /// are no transitions, then an error is returned.
+    pub(crate) fn from_transitions<'a>(
+        name: Option<String>,
+        transitions: impl IntoIterator<Item = (i64, i32, &'a str, bool)>,
+    ) -> Result<Self, Error> {
+        let sh = shared::TzifOwned::from_transitions(name, transitions)
+            .map_err(Error::shared)?;
+        Ok(TzifOwned::from_shared_owned(sh))
+    }
+
     /// Converts from the shared-but-internal API for use in proc macros.
     ///
     /// This is not `const` since it accepts owned values on the heap for
@@ -173,6 +188,28 @@ impl TzifOwned {
         };
         Tzif { inner: sh, posix_tz }
     }
+
+    /// Returns an approximation of the number of bytes allocated on the heap
+    /// for this TZif data.
+    ///
+    /// This includes the size of the `Tzif` value itself (since it is always
+    /// stored behind an `Arc` inside of a `TimeZone`) along with the capacity
+    /// of all of its owned buffers.
+    pub(crate) fn heap_size(&self) -> usize {
+        use core::mem::size_of;
+
+        let fixed = &self.inner.fixed;
+        let trans = &self.inner.transitions;
+        size_of::<TzifOwned>()
+            + fixed.name.as_ref().map_or(0, |name| name.capacity())
+            + fixed.designations.capacity()
+            + self.inner.types.capacity()
+                * size_of::<shared::TzifLocalTimeType>()
+            + trans.timestamps.capacity() * size_of::<i64>()
+            + trans.civil_starts.capacity() * size_of::<shared::TzifDateTime>()
+            + trans.civil_ends.capacity() * size_of::<shared::TzifDateTime>()
+            + trans.infos.capacity() * size_of::<shared::TzifTransitionInfo>()
+    }
 }
 
 impl<
@@ -190,6 +227,32 @@ impl<
         self.inner.fixed.name.as_ref().map(|n| n.as_ref())
     }
 
+    /// Returns the checksum of the TZif data this was constructed from.
+    pub(crate) fn checksum(&self) -> u32 {
+        self.inner.fixed.checksum
+    }
+
+    /// Returns true if and only if the rules in this TZif data are
+    /// indistinguishable from UTC.
+    ///
+    /// That is, every local time type has a zero offset, isn't DST and uses
+    /// the abbreviation `UTC`. And if present, the POSIX time zone must be
+    /// equivalent to `UTC0`.
+    pub(crate) fn has_utc_rules(&self) -> bool {
+        self.types().iter().all(|typ| {
+            typ.offset == 0 && !typ.is_dst && self.designation(typ) == "UTC"
+        }) && self.posix_tz().map_or(true, |tz| tz.is_utc())
+    }
+
+    /// Returns the number of transitions explicitly recorded in this TZif
+    /// data.
+    ///
+    /// This doesn't include the dummy transition we insert at the beginning,
+    /// nor any transitions implied by the POSIX time zone in the footer.
+    pub(crate) fn transition_count(&self) -> usize {
+        self.timestamps().len().saturating_sub(1)
+    }
+
     /// Returns the appropriate time zone offset to use for the given
     /// timestamp.
     pub(crate) fn to_offset(&self, timestamp: Timestamp) -> Offset {
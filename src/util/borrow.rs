@@ -33,7 +33,10 @@ impl<'a, T: core::fmt::Display> core::fmt::Display for DumbCow<'a, T> {
 /// A `Cow`, but can be used in core-only mode.
 ///
 /// In core-only, the `Owned` variant doesn't exist.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+///
+/// Like `std::borrow::Cow`, comparisons and hashing only consider the string
+/// contents, and not whether it is owned or borrowed.
+#[derive(Clone, Debug)]
 pub(crate) enum StringCow<'a> {
     #[cfg(feature = "alloc")]
     Owned(alloc::string::String),
@@ -70,6 +73,32 @@ impl<'a> StringCow<'a> {
     }
 }
 
+impl<'a> Eq for StringCow<'a> {}
+
+impl<'a> PartialEq for StringCow<'a> {
+    fn eq(&self, rhs: &StringCow<'a>) -> bool {
+        self.as_str() == rhs.as_str()
+    }
+}
+
+impl<'a> Ord for StringCow<'a> {
+    fn cmp(&self, rhs: &StringCow<'a>) -> core::cmp::Ordering {
+        self.as_str().cmp(rhs.as_str())
+    }
+}
+
+impl<'a> PartialOrd for StringCow<'a> {
+    fn partial_cmp(&self, rhs: &StringCow<'a>) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<'a> core::hash::Hash for StringCow<'a> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
 impl<'a> core::ops::Deref for StringCow<'a> {
     type Target = str;
     fn deref(&self) -> &str {
@@ -1,5 +1,5 @@
 use crate::{
-    error::{err, Error},
+    error::{err, parse_err, Error},
     util::escape::{Byte, Bytes},
 };
 
@@ -15,19 +15,19 @@ use crate::{
 #[inline(always)]
 pub(crate) fn i64(bytes: &[u8]) -> Result<i64, Error> {
     if bytes.is_empty() {
-        return Err(err!("invalid number, no digits found"));
+        return Err(parse_err!("invalid number, no digits found"));
     }
     let mut n: i64 = 0;
     for &byte in bytes {
         let digit = match byte.checked_sub(b'0') {
             None => {
-                return Err(err!(
+                return Err(parse_err!(
                     "invalid digit, expected 0-9 but got {}",
                     Byte(byte),
                 ));
             }
             Some(digit) if digit > 9 => {
-                return Err(err!(
+                return Err(parse_err!(
                     "invalid digit, expected 0-9 but got {}",
                     Byte(byte),
                 ))
@@ -39,7 +39,7 @@ pub(crate) fn i64(bytes: &[u8]) -> Result<i64, Error> {
         };
         n = n.checked_mul(10).and_then(|n| n.checked_add(digit)).ok_or_else(
             || {
-                err!(
+                parse_err!(
                     "number '{}' too big to parse into 64-bit integer",
                     Bytes(bytes),
                 )
@@ -68,9 +68,9 @@ pub(crate) fn fraction(
     max_precision: usize,
 ) -> Result<i64, Error> {
     if bytes.is_empty() {
-        return Err(err!("invalid fraction, no digits found"));
+        return Err(parse_err!("invalid fraction, no digits found"));
     } else if bytes.len() > max_precision {
-        return Err(err!(
+        return Err(parse_err!(
             "invalid fraction, too many digits \
              (at most {max_precision} are allowed"
         ));
@@ -79,13 +79,13 @@ pub(crate) fn fraction(
     for &byte in bytes {
         let digit = match byte.checked_sub(b'0') {
             None => {
-                return Err(err!(
+                return Err(parse_err!(
                     "invalid fractional digit, expected 0-9 but got {}",
                     Byte(byte),
                 ));
             }
             Some(digit) if digit > 9 => {
-                return Err(err!(
+                return Err(parse_err!(
                     "invalid fractional digit, expected 0-9 but got {}",
                     Byte(byte),
                 ))
@@ -97,7 +97,7 @@ pub(crate) fn fraction(
         };
         n = n.checked_mul(10).and_then(|n| n.checked_add(digit)).ok_or_else(
             || {
-                err!(
+                parse_err!(
                     "fractional '{}' too big to parse into 64-bit integer",
                     Bytes(bytes),
                 )
@@ -106,7 +106,7 @@ pub(crate) fn fraction(
     }
     for _ in bytes.len()..max_precision {
         n = n.checked_mul(10).ok_or_else(|| {
-            err!(
+            parse_err!(
                 "fractional '{}' too big to parse into 64-bit integer \
                  (too much precision supported)",
                 Bytes(bytes)
@@ -40,6 +40,38 @@ use crate::{
 ///
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+///
+/// # Example: comparing all rounding modes
+///
+/// This shows the result of rounding a positive and a negative span that are
+/// both precisely halfway between two hours. That is, this is a tie. Every
+/// rounding mode is shown:
+///
+/// ```
+/// use jiff::{RoundMode, SpanRound, ToSpan, Unit};
+///
+/// let modes = [
+///     // (mode, result for 1h30m, result for -1h30m)
+///     (RoundMode::Ceil, 2, -1),
+///     (RoundMode::Floor, 1, -2),
+///     (RoundMode::Expand, 2, -2),
+///     (RoundMode::Trunc, 1, -1),
+///     (RoundMode::HalfCeil, 2, -1),
+///     (RoundMode::HalfFloor, 1, -2),
+///     (RoundMode::HalfExpand, 2, -2),
+///     (RoundMode::HalfTrunc, 1, -1),
+///     (RoundMode::HalfEven, 2, -2),
+/// ];
+/// for (mode, positive, negative) in modes {
+///     let options = SpanRound::new().smallest(Unit::Hour).mode(mode);
+///     let span = 1.hour().minutes(30).round(options)?;
+///     assert_eq!(span.get_hours(), positive, "{mode:?}");
+///     let span = 1.hour().minutes(30).negate().round(options)?;
+///     assert_eq!(span.get_hours(), negative, "{mode:?}");
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum RoundMode {
@@ -16,7 +16,7 @@ use crate::{
         rangeint::{RInto, TryRFrom},
         t::{self, ZonedDayNanoseconds, C},
     },
-    RoundMode, SignedDuration, Span, SpanRound, Timestamp, Unit,
+    Inclusivity, RoundMode, SignedDuration, Span, SpanRound, Timestamp, Unit,
 };
 
 /// A time zone aware instant in time.
@@ -114,9 +114,38 @@ use crate::{
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 ///
+/// Like [`Timestamp`], printing a `Zoned` never allocates. It can be written
+/// into an existing buffer (or any other [`core::fmt::Write`]
+/// implementation) with [`Zoned::format_into`] or `write!`.
+///
+/// The bracketed time zone annotation is what makes a `Zoned` roundtrip
+/// losslessly, but not every consumer understands it. For example,
+/// JavaScript's `Date` and Go's `time.Parse` with `time.RFC3339` only accept
+/// an offset. To print an [RFC 3339] timestamp with only the offset of a
+/// zoned datetime, use [`Zoned::to_rfc3339`]. Or, to avoid allocating a
+/// `String`, use [`Timestamp::display_with_offset`]:
+///
+/// ```
+/// use jiff::civil::date;
+///
+/// let zdt = date(2021, 7, 30)
+///     .at(17, 20, 4, 123_000_000)
+///     .in_tz("America/New_York")?;
+/// assert_eq!(zdt.to_rfc3339(), "2021-07-30T17:20:04.123-04:00");
+/// let rfc3339 = zdt.timestamp().display_with_offset(zdt.offset());
+/// assert_eq!(rfc3339.to_string(), "2021-07-30T17:20:04.123-04:00");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Note that parsing this string back into a `Zoned` fails, since the time
+/// zone is lost. It can be parsed back into a [`Timestamp`] instead.
+///
 /// For more information on the specific format supported, see the
 /// [`fmt::temporal`](crate::fmt::temporal) module documentation.
 ///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+///
 /// # Leap seconds
 ///
 /// Jiff does not support leap seconds. Jiff behaves as if they don't exist.
@@ -186,6 +215,33 @@ use crate::{
 ///
 /// [temporal-equals]: https://tc39.es/proposal-temporal/docs/zoneddatetime.html#equals
 ///
+/// The `Hash` trait implementation is consistent with `Eq`. That is, only the
+/// instant in time is hashed. This means that a `HashSet<Zoned>` will treat
+/// two zoned datetimes with the same instant but different time zones as the
+/// same value, and only the first one inserted is retained:
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use jiff::civil::date;
+///
+/// let zdt1 = date(2024, 7, 4).at(12, 0, 0, 0).in_tz("America/New_York")?;
+/// let zdt2 = date(2024, 7, 4).at(9, 0, 0, 0).in_tz("America/Los_Angeles")?;
+///
+/// let mut set = HashSet::new();
+/// assert!(set.insert(zdt1.clone()));
+/// assert!(!set.insert(zdt2));
+/// assert_eq!(set.len(), 1);
+/// let got = set.get(&zdt1).unwrap();
+/// assert_eq!(got.time_zone().iana_name(), Some("America/New_York"));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// If you need to distinguish zoned datetimes by their time zone as well,
+/// then use a key like `(zdt.timestamp(), zdt.time_zone().iana_name())`
+/// instead.
+///
 /// # Arithmetic
 ///
 /// This type provides routines for adding and subtracting spans of time, as
@@ -570,6 +626,11 @@ impl Zoned {
     /// The zoned datetime returned is guaranteed to have an equivalent
     /// [`Timestamp`]. However, its civil [`DateTime`] may be different.
     ///
+    /// This is the routine to use for showing an instant in another time
+    /// zone, e.g., converting a meeting time to a participant's local time.
+    /// To instead keep the civil datetime and find the corresponding instant
+    /// in another time zone, use [`Zoned::reinterpret_local`].
+    ///
     /// # Example: What was the civil time in New York when World War 1 ended?
     ///
     /// ```
@@ -632,6 +693,97 @@ impl Zoned {
         Ok(self.with_time_zone(tz))
     }
 
+    /// Return a new zoned datetime with precisely the same instant in a
+    /// different time zone.
+    ///
+    /// This is an alias for [`Zoned::with_time_zone`]. Only the offset and
+    /// time zone of the zoned datetime returned differ from `self`. Its
+    /// [`Timestamp`] is always the same, which also means the two compare
+    /// equal. Use [`Zoned::in_tz`] to convert to a time zone given by its
+    /// IANA time zone identifier instead.
+    ///
+    /// # Example: show a meeting in a participant's local time
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let meeting = date(2024, 3, 12).at(9, 0, 0, 0).in_tz("America/New_York")?;
+    /// let local = meeting.in_zone(TimeZone::get("Europe/Berlin")?);
+    /// assert_eq!(local.timestamp(), meeting.timestamp());
+    /// assert_eq!(local.to_string(), "2024-03-12T14:00:00+01:00[Europe/Berlin]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn in_zone(&self, tz: TimeZone) -> Zoned {
+        self.with_time_zone(tz)
+    }
+
+    /// Return a new zoned datetime with the same civil datetime in a
+    /// different time zone.
+    ///
+    /// This is the opposite of [`Zoned::with_time_zone`]. That is, the
+    /// zoned datetime returned is guaranteed to have an equivalent civil
+    /// [`DateTime`] (except when it falls into a gap, see below), but its
+    /// [`Timestamp`] will generally be different. For example, this is the
+    /// routine to use for turning a meeting at 9am in New York into a meeting
+    /// at 9am in Tokyo.
+    ///
+    /// This is equivalent to `zdt.datetime().to_zoned(time_zone)`.
+    ///
+    /// # Disambiguation
+    ///
+    /// The civil datetime might be ambiguous in the new time zone. When it
+    /// falls into a gap, the civil time is shifted forward by the length of
+    /// the gap. When it falls into a fold, the earlier of the two possible
+    /// instants is used. This corresponds to
+    /// [`Disambiguation::Compatible`](crate::tz::Disambiguation::Compatible).
+    /// To use a different strategy, use [`TimeZone::to_ambiguous_zoned`]
+    /// with [`Zoned::datetime`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the civil datetime, when resolved in the
+    /// given time zone, would overflow the range of instants supported by
+    /// Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let ny = date(2024, 7, 15).at(9, 0, 0, 0).in_tz("America/New_York")?;
+    /// let tokyo = ny.reinterpret_local(TimeZone::get("Asia/Tokyo")?)?;
+    /// assert_eq!(tokyo.to_string(), "2024-07-15T09:00:00+09:00[Asia/Tokyo]");
+    /// // The civil datetime is the same, but the instant changed.
+    /// assert_eq!(ny.datetime(), tokyo.datetime());
+    /// assert_eq!(ny.since(&tokyo)?.get_hours(), 13);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: civil datetime in a gap
+    ///
+    /// ```
+    /// use jiff::{civil::date, tz::TimeZone};
+    ///
+    /// let utc = date(2024, 3, 10).at(2, 30, 0, 0).in_tz("UTC")?;
+    /// let ny = utc.reinterpret_local(TimeZone::get("America/New_York")?)?;
+    /// assert_eq!(
+    ///     ny.to_string(),
+    ///     "2024-03-10T03:30:00-04:00[America/New_York]",
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn reinterpret_local(
+        &self,
+        time_zone: TimeZone,
+    ) -> Result<Zoned, Error> {
+        self.datetime().to_zoned(time_zone)
+    }
+
     /// Returns the time zone attached to this [`Zoned`] value.
     ///
     /// A time zone is more than just an offset. A time zone is a series of
@@ -1171,6 +1323,62 @@ impl Zoned {
             .map(|ts| ts.to_zoned(self.time_zone().clone()))
     }
 
+    /// Returns the length of the civil day that this zoned datetime resides
+    /// in.
+    ///
+    /// This is the duration between the [start of this
+    /// day](Zoned::start_of_day) and the start of the following day. In most
+    /// time zones, this is 24 hours on most days. But on days with a time
+    /// zone transition, the day may be shorter or longer. For example, in
+    /// `America/New_York`, the day that DST begins is 23 hours long and the
+    /// day that DST ends is 25 hours long.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the start of this day or the start of the
+    /// following day cannot be represented. This can only occur near the
+    /// minimum or maximum datetime values supported by Jiff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, SignedDuration};
+    ///
+    /// let zdt = date(2024, 3, 10).at(12, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.day_length()?, SignedDuration::from_hours(23));
+    ///
+    /// let zdt = date(2024, 11, 3).at(12, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.day_length()?, SignedDuration::from_hours(25));
+    ///
+    /// let zdt = date(2024, 7, 4).at(12, 0, 0, 0).in_tz("America/New_York")?;
+    /// assert_eq!(zdt.day_length()?, SignedDuration::from_hours(24));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: transitions that aren't 1 hour
+    ///
+    /// In `Australia/Lord_Howe`, DST only shifts the clocks by 30 minutes:
+    ///
+    /// ```
+    /// use jiff::{civil::date, SignedDuration};
+    ///
+    /// let zdt = date(2024, 10, 6).at(12, 0, 0, 0).in_tz("Australia/Lord_Howe")?;
+    /// assert_eq!(
+    ///     zdt.day_length()?,
+    ///     SignedDuration::from_hours(23) + SignedDuration::from_mins(30),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn day_length(&self) -> Result<SignedDuration, Error> {
+        let start = self.start_of_day()?;
+        let next =
+            self.date().tomorrow()?.to_zoned(self.time_zone().clone())?;
+        Ok(next.timestamp().duration_since(start.timestamp()))
+    }
+
     /// Returns the first date of the month that this zoned datetime resides
     /// in.
     ///
@@ -1825,8 +2033,64 @@ impl Zoned {
         self.inner.timestamp
     }
 
+    /// Returns true if and only if this zoned datetime is between `start`
+    /// and `end`.
+    ///
+    /// Like the comparison trait implementations on `Zoned`, this compares
+    /// the precise instants in time referred to by each zoned datetime.
+    /// Their time zones are ignored. Whether `start` and `end` themselves are
+    /// considered to be in the range is determined by the given
+    /// [`Inclusivity`].
+    ///
+    /// When `start` is after `end`, the range is empty and this always
+    /// returns false.
+    ///
+    /// This is equivalent to calling [`Timestamp::is_between`] on the
+    /// timestamps of each zoned datetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, Inclusivity};
+    ///
+    /// let start = date(2024, 6, 19).at(9, 0, 0, 0).in_tz("America/New_York")?;
+    /// let end = date(2024, 6, 19).at(17, 0, 0, 0).in_tz("America/New_York")?;
+    ///
+    /// // 22:00 in Paris is 16:00 in New York.
+    /// let zdt = date(2024, 6, 19).at(22, 0, 0, 0).in_tz("Europe/Paris")?;
+    /// assert!(zdt.is_between(&start, &end, Inclusivity::ClosedOpen));
+    /// // 23:00 in Paris is 17:00 in New York.
+    /// let zdt = date(2024, 6, 19).at(23, 0, 0, 0).in_tz("Europe/Paris")?;
+    /// assert!(!zdt.is_between(&start, &end, Inclusivity::ClosedOpen));
+    /// assert!(zdt.is_between(&start, &end, Inclusivity::Closed));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_between(
+        &self,
+        start: &Zoned,
+        end: &Zoned,
+        inclusivity: Inclusivity,
+    ) -> bool {
+        self.timestamp().is_between(
+            start.timestamp(),
+            end.timestamp(),
+            inclusivity,
+        )
+    }
+
     /// Returns the civil datetime component of this zoned datetime.
     ///
+    /// This is the local "wall clock" time of this zoned datetime. That is,
+    /// it is the civil datetime in this zoned datetime's time zone, using
+    /// the offset in effect at this instant. It is _not_ the civil datetime
+    /// in UTC. For that, use
+    /// `zdt.with_time_zone(TimeZone::UTC).datetime()`.
+    ///
+    /// This is computed when the zoned datetime is created, so calling this
+    /// routine is cheap.
+    ///
     /// # Example
     ///
     /// ```
@@ -1835,6 +2099,11 @@ impl Zoned {
     /// let zdt = date(2024, 3, 14).at(18, 45, 0, 0).in_tz("America/New_York")?;
     /// assert_eq!(zdt.datetime(), date(2024, 3, 14).at(18, 45, 0, 0));
     ///
+    /// // The civil datetime reflects the offset in effect at the instant,
+    /// // and not UTC.
+    /// let zdt: jiff::Zoned = "2024-03-14T22:45Z[America/New_York]".parse()?;
+    /// assert_eq!(zdt.datetime(), date(2024, 3, 14).at(18, 45, 0, 0));
+    ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[inline]
@@ -2172,7 +2441,12 @@ impl Zoned {
         duration: A,
     ) -> Result<Zoned, Error> {
         let duration: ZonedArithmetic = duration.into();
-        duration.checked_add(self)
+        duration.checked_add(self).with_context(|| {
+            err!(
+                "failed to add {duration} to {self}",
+                duration = duration.duration,
+            )
+        })
     }
 
     #[inline]
@@ -2184,27 +2458,11 @@ impl Zoned {
         if span_calendar.is_zero() {
             return self
                 .timestamp()
-                .checked_add(span)
-                .map(|ts| ts.to_zoned(self.time_zone().clone()))
-                .with_context(|| {
-                    err!(
-                        "failed to add span {span} to timestamp {timestamp} \
-                         from zoned datetime {zoned}",
-                        timestamp = self.timestamp(),
-                        zoned = self,
-                    )
-                });
+                .checked_add_span(span)
+                .map(|ts| ts.to_zoned(self.time_zone().clone()));
         }
         let span_time = span.only_time();
-        let dt =
-            self.datetime().checked_add(span_calendar).with_context(|| {
-                err!(
-                    "failed to add span {span_calendar} to datetime {dt} \
-                     from zoned datetime {zoned}",
-                    dt = self.datetime(),
-                    zoned = self,
-                )
-            })?;
+        let dt = self.datetime().checked_add(span_calendar)?;
 
         let tz = self.time_zone();
         let mut ts =
@@ -2215,7 +2473,7 @@ impl Zoned {
                     tz = self.time_zone().diagnostic_name(),
                 )
             })?;
-        ts = ts.checked_add(span_time).with_context(|| {
+        ts = ts.checked_add_span(span_time).with_context(|| {
             err!(
                 "failed to add span {span_time} to timestamp {ts} \
                  (which was created from {dt})"
@@ -2230,7 +2488,7 @@ impl Zoned {
         duration: SignedDuration,
     ) -> Result<Zoned, Error> {
         self.timestamp()
-            .checked_add(duration)
+            .checked_add_duration(duration)
             .map(|ts| ts.to_zoned(self.time_zone().clone()))
     }
 
@@ -2282,7 +2540,15 @@ impl Zoned {
         duration: A,
     ) -> Result<Zoned, Error> {
         let duration: ZonedArithmetic = duration.into();
-        duration.checked_neg().and_then(|za| za.checked_add(self))
+        duration
+            .checked_neg()
+            .and_then(|za| za.checked_add(self))
+            .with_context(|| {
+                err!(
+                    "failed to subtract {duration} from {self}",
+                    duration = duration.duration,
+                )
+            })
     }
 
     /// This routine is identical to [`Zoned::checked_add`], except the
@@ -2403,7 +2669,7 @@ impl Zoned {
     /// how equality is determined.) This error occurs because the length of
     /// a day may vary depending on the time zone. To work around this
     /// restriction, convert one or both of the zoned datetimes into the same
-    /// time zone.
+    /// time zone, or use [`Zoned::until_anchored`].
     ///
     /// It is guaranteed that if one provides a datetime with the default
     /// [`ZonedDifference`] configuration, then this routine will never
@@ -2572,6 +2838,69 @@ impl Zoned {
         }
     }
 
+    /// Returns a span from this zoned datetime until `other`, with calendar
+    /// arithmetic anchored in this zoned datetime's time zone.
+    ///
+    /// Unlike [`Zoned::until`], this permits `other` to be in a different
+    /// time zone than `self` even when `largest` is `Unit::Day` or bigger.
+    /// This works by first converting `other` to the time zone of `self` (via
+    /// [`Zoned::with_time_zone`]) and then computing the span between them
+    /// with `largest` as the largest unit.
+    ///
+    /// Since the length of a day can vary from one time zone to another, the
+    /// choice of time zone affects the span returned whenever the two zoned
+    /// datetimes straddle a time zone transition, such as a daylight saving
+    /// time change. The time zone of `self` is always used. So if you need the
+    /// days and hours to reflect what a clock in `other`'s time zone shows,
+    /// then call this routine on `other` instead (and negate the result).
+    ///
+    /// Note that the time zone does not matter when `largest` is
+    /// `Unit::Hour` or smaller, since those units always correspond to a
+    /// fixed amount of elapsed time.
+    ///
+    /// # Errors
+    ///
+    /// This has the same error conditions as [`Zoned::until`], except that
+    /// it never returns an error because of `other` being in a different
+    /// time zone than `self`.
+    ///
+    /// # Example
+    ///
+    /// In New York, daylight saving time began at 2am on 2024-03-10. This
+    /// means that noon on 2024-03-09 until noon on 2024-03-10 is one day in
+    /// New York, but only 23 hours in London:
+    ///
+    /// ```
+    /// use jiff::{civil::date, ToSpan, Unit};
+    ///
+    /// let ny = date(2024, 3, 9).at(12, 0, 0, 0).in_tz("America/New_York")?;
+    /// let london = date(2024, 3, 10)
+    ///     .at(12, 0, 0, 0)
+    ///     .in_tz("America/New_York")?
+    ///     .in_tz("Europe/London")?;
+    ///
+    /// // Anchored in New York's time zone.
+    /// let span = ny.until_anchored(&london, Unit::Day)?;
+    /// assert_eq!(span, 1.day().fieldwise());
+    /// // Anchored in London's time zone.
+    /// let span = london.until_anchored(&ny, Unit::Day)?;
+    /// assert_eq!(span, -23.hours().fieldwise());
+    ///
+    /// // Whereas `Zoned::until` refuses to pick a time zone.
+    /// assert!(ny.until((Unit::Day, &london)).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn until_anchored(
+        &self,
+        other: &Zoned,
+        largest: Unit,
+    ) -> Result<Span, Error> {
+        let other = other.with_time_zone(self.time_zone().clone());
+        self.until((largest, &other))
+    }
+
     /// Returns an absolute duration representing the elapsed time from this
     /// zoned datetime until the given `other` zoned datetime.
     ///
@@ -2973,6 +3302,162 @@ impl Zoned {
     }
 }
 
+/// Parsing with access to RFC 9557 annotations.
+impl Zoned {
+    /// Parses a zoned datetime from the Temporal datetime format, and
+    /// returns it along with all of the [RFC 9557] annotations found.
+    ///
+    /// This accepts the same format as this type's `FromStr` trait
+    /// implementation. The difference is that parsing via `FromStr` only
+    /// keeps what is needed to construct a `Zoned` value. In contrast, this
+    /// routine also returns the time zone annotation, the UTC offset and any
+    /// other key/value annotations exactly as they appeared, including
+    /// whether each annotation was marked as critical. This is useful for
+    /// tooling that needs to inspect or faithfully re-emit annotations.
+    ///
+    /// Note that `FromStr` returns an error for any key/value annotation
+    /// marked as critical (e.g., `[!u-ca=iso8601]`), since Jiff doesn't
+    /// support any of them. This routine accepts them instead, and it is the
+    /// responsibility of the caller to act on them (including by returning
+    /// an error) via [`Annotation::is_critical`](temporal::Annotation::is_critical).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as parsing via
+    /// `FromStr`, except for critical key/value annotations as described
+    /// above.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::Zoned;
+    ///
+    /// let (zdt, ann) = Zoned::parse_full(
+    ///     "2024-07-15T09:00-04[!America/New_York][u-ca=iso8601][!x-y=a-b]",
+    /// )?;
+    /// assert_eq!(zdt.to_string(), "2024-07-15T09:00:00-04:00[America/New_York]");
+    /// assert!(ann.time_zone_annotation().unwrap().is_critical());
+    ///
+    /// let others: Vec<(&str, &str, bool)> = ann
+    ///     .others()
+    ///     .iter()
+    ///     .map(|a| (a.key(), a.value(), a.is_critical()))
+    ///     .collect();
+    /// assert_eq!(others, vec![("u-ca", "iso8601", false), ("x-y", "a-b", true)]);
+    ///
+    /// // Parsing via `FromStr` rejects the critical annotation.
+    /// assert!("2024-07-15T09:00-04[America/New_York][!x-y=a-b]"
+    ///     .parse::<Zoned>()
+    ///     .is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557.html
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn parse_full<I: AsRef<[u8]>>(
+        input: I,
+    ) -> Result<(Zoned, temporal::Annotations), Error> {
+        DEFAULT_DATETIME_PARSER.parse_zoned_full(input)
+    }
+}
+
+/// Formatting without RFC 9557 annotations.
+impl Zoned {
+    /// Returns this zoned datetime as an [RFC 3339] string with only its
+    /// offset, and no bracketed time zone annotation.
+    ///
+    /// This is useful for consumers that don't understand the time zone
+    /// annotation added by [RFC 9557], like JavaScript's `Date` or Go's
+    /// `time.Parse` with `time.RFC3339`. The default `Display` implementation
+    /// for `Zoned` is unaffected and still includes the annotation.
+    ///
+    /// This is equivalent to
+    /// `zdt.timestamp().display_with_offset(zdt.offset()).to_string()`. See
+    /// [`Timestamp::display_with_offset`] for a way to print this without
+    /// allocating.
+    ///
+    /// Since the time zone is lost, parsing the string returned back into a
+    /// `Zoned` fails. It can be parsed back into a [`Timestamp`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::{civil::date, Timestamp};
+    ///
+    /// let zdt = date(2021, 7, 30)
+    ///     .at(17, 20, 4, 123_000_000)
+    ///     .in_tz("America/New_York")?;
+    /// assert_eq!(zdt.to_rfc3339(), "2021-07-30T17:20:04.123-04:00");
+    /// assert_eq!(
+    ///     zdt.to_string(),
+    ///     "2021-07-30T17:20:04.123-04:00[America/New_York]",
+    /// );
+    ///
+    /// let ts: Timestamp = zdt.to_rfc3339().parse()?;
+    /// assert_eq!(ts, zdt.timestamp());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    /// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557.html
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_rfc3339(&self) -> alloc::string::String {
+        use alloc::string::ToString;
+
+        self.timestamp().display_with_offset(self.offset()).to_string()
+    }
+}
+
+/// Formatting into an existing buffer.
+impl Zoned {
+    /// Writes this zoned datetime, including its time zone annotation, into
+    /// the given writer.
+    ///
+    /// This writes the same string as this type's `Display` implementation,
+    /// but without needing to go through `write!`. This never allocates, so
+    /// writing into a reused buffer avoids the allocation of a new `String`
+    /// that `to_string` incurs for every zoned datetime printed.
+    ///
+    /// To write an RFC 3339 string with only the offset, and no time zone
+    /// annotation, use
+    /// `zdt.timestamp().display_with_offset(zdt.offset())` with `write!`.
+    ///
+    /// # Errors
+    ///
+    /// This only returns an error when the given writer returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    ///
+    /// let mut buf = String::new();
+    /// for day in [1, 2] {
+    ///     buf.clear();
+    ///     let zdt = date(2024, 7, day).at(9, 0, 0, 0).in_tz("Asia/Tokyo")?;
+    ///     zdt.format_into(&mut buf)?;
+    /// }
+    /// assert_eq!(buf, "2024-07-02T09:00:00+09:00[Asia/Tokyo]");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format_into<W: core::fmt::Write>(
+        &self,
+        wtr: &mut W,
+    ) -> core::fmt::Result {
+        use crate::fmt::StdFmtWrite;
+
+        temporal::DateTimePrinter::new()
+            .print_zoned(self, StdFmtWrite(wtr))
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
 /// Parsing and formatting using a "printf"-style API.
 impl Zoned {
     /// Parses a zoned datetime in `input` matching the given `format`.
@@ -3418,6 +3903,15 @@ impl core::ops::SubAssign<UnsignedDuration> for Zoned {
     }
 }
 
+/// Serializes a zoned datetime using its RFC 9557 string representation.
+///
+/// Fixed offset time zones round-trip losslessly, since the offset is
+/// written in the time zone annotation (e.g., `+05:30[+05:30]`) and parsed
+/// back as a fixed offset. However, the RFC 9557 format cannot represent
+/// offsets with non-zero seconds. So when the time zone has no IANA name
+/// (which would otherwise be used to recover the precise offset) and the
+/// offset has a non-zero seconds component, serialization returns an error
+/// instead of silently writing a rounded offset.
 #[cfg(feature = "serde")]
 impl serde::Serialize for Zoned {
     #[inline]
@@ -3425,6 +3919,18 @@ impl serde::Serialize for Zoned {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
+        use serde::ser;
+
+        if self.time_zone().iana_name().is_none()
+            && self.offset().seconds() % 60 != 0
+        {
+            return Err(ser::Error::custom(format_args!(
+                "cannot serialize zoned datetime {self} losslessly \
+                 because its offset {offset} has a non-zero seconds \
+                 component and its time zone has no IANA name",
+                offset = self.offset(),
+            )));
+        }
         serializer.collect_str(self)
     }
 }
@@ -3469,6 +3975,50 @@ impl<'de> serde::Deserialize<'de> for Zoned {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Zoned {
+    fn arbitrary(
+        u: &mut arbitrary::Unstructured<'a>,
+    ) -> arbitrary::Result<Zoned> {
+        // A small set of well known time zones that exercise a variety of
+        // rules: no DST, northern and southern DST, non-hour offsets and
+        // 30 minute DST transitions. If a zone can't be found (e.g., there
+        // is no time zone database available), then UTC is used instead.
+        const NAMES: &[&str] = &[
+            "UTC",
+            "America/New_York",
+            "America/Los_Angeles",
+            "America/Sao_Paulo",
+            "Europe/London",
+            "Europe/Berlin",
+            "Asia/Tokyo",
+            "Asia/Kolkata",
+            "Asia/Kathmandu",
+            "Australia/Sydney",
+            "Australia/Lord_Howe",
+            "Pacific/Auckland",
+        ];
+
+        let timestamp = Timestamp::arbitrary(u)?;
+        let tz = if bool::arbitrary(u)? {
+            let name = u.choose(NAMES)?;
+            TimeZone::get(name).unwrap_or(TimeZone::UTC)
+        } else {
+            let seconds = u.int_in_range(
+                t::SpanZoneOffset::MIN_REPR..=t::SpanZoneOffset::MAX_REPR,
+            )?;
+            let offset = Offset::from_seconds(seconds)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            TimeZone::fixed(offset)
+        };
+        Ok(Zoned::new(timestamp, tz))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(Timestamp, bool, i32) as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 #[cfg(test)]
 impl quickcheck::Arbitrary for Zoned {
     fn arbitrary(g: &mut quickcheck::Gen) -> Zoned {
@@ -5334,6 +5884,47 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn until_anchored_across_dst() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        // DST began on 2024-03-10 in New York, but on 2024-03-31 in London
+        // and it doesn't exist in Tokyo.
+        let start = date(2024, 3, 9).at(12, 0, 0, 0);
+        let end = date(2024, 3, 10).at(12, 0, 0, 0);
+        let ny1 = start.in_tz("America/New_York").unwrap();
+        let ny2 = end.in_tz("America/New_York").unwrap();
+        let tokyo1 = ny1.in_tz("Asia/Tokyo").unwrap();
+        let london2 = ny2.in_tz("Europe/London").unwrap();
+
+        // Anchored in New York, a day passes.
+        let span = ny1.until_anchored(&london2, Unit::Day).unwrap();
+        span_eq!(span, 1.day());
+        let span = ny1.until_anchored(&london2, Unit::Year).unwrap();
+        span_eq!(span, 1.day());
+        // But it's only 23 hours in Tokyo or London.
+        let span = tokyo1.until_anchored(&london2, Unit::Day).unwrap();
+        span_eq!(span, 23.hours());
+        let span = london2.until_anchored(&tokyo1, Unit::Day).unwrap();
+        span_eq!(span, -23.hours());
+
+        // A longer span shows the difference in the day/hour breakdown.
+        let ny3 = date(2024, 3, 12).at(18, 0, 0, 0).in_tz("America/New_York");
+        let ny3 = ny3.unwrap();
+        let span = ny1.until_anchored(&ny3, Unit::Day).unwrap();
+        span_eq!(span, 3.days().hours(6));
+        let span = tokyo1.until_anchored(&ny3, Unit::Day).unwrap();
+        span_eq!(span, 3.days().hours(5));
+
+        // Smaller units don't depend on the anchor.
+        let span = ny1.until_anchored(&london2, Unit::Hour).unwrap();
+        span_eq!(span, 23.hours());
+        let span = tokyo1.until_anchored(&london2, Unit::Hour).unwrap();
+        span_eq!(span, 23.hours());
+    }
+
     #[test]
     fn until_with_largest_unit() {
         if crate::tz::db().is_definitively_empty() {
@@ -5479,6 +6070,33 @@ mod tests {
         assert_eq!(deserialized, expected);
     }
 
+    /// Tests that a `Zoned` in a fixed offset time zone round-trips through
+    /// serde without its offset being re-resolved to some other time zone.
+    #[test]
+    fn zoned_serde_fixed_offset_roundtrip() {
+        let tz = TimeZone::fixed(tz::Offset::from_seconds(19_800).unwrap());
+        let zdt = datetime(2024, 10, 31, 16, 33, 53, 123456789)
+            .to_zoned(tz.clone())
+            .unwrap();
+
+        let json = serde_json::to_string(&zdt).unwrap();
+        assert_eq!(json, r#""2024-10-31T16:33:53.123456789+05:30[+05:30]""#);
+
+        let got: Zoned = serde_json::from_str(&json).unwrap();
+        assert_eq!(got, zdt);
+        assert_eq!(got.timestamp(), zdt.timestamp());
+        assert_eq!(got.offset(), tz::Offset::from_seconds(19_800).unwrap());
+        assert_eq!(got.time_zone(), &tz);
+        assert_eq!(got.time_zone().iana_name(), None);
+
+        // Offsets with a non-zero seconds component can't be represented in
+        // the RFC 9557 format, so this should be an error instead of silently
+        // rounding the offset.
+        let tz = TimeZone::fixed(tz::Offset::from_seconds(19_815).unwrap());
+        let zdt = datetime(2024, 10, 31, 16, 33, 53, 0).to_zoned(tz).unwrap();
+        assert!(serde_json::to_string(&zdt).is_err());
+    }
+
     /// This is a regression test for a case where changing a zoned datetime
     /// to have a time of midnight ends up producing a counter-intuitive
     /// result.
@@ -5554,6 +6172,212 @@ mod tests {
         assert_eq!(zdt2.to_string(), "2024-03-10T01:30:00-05:00[US/Eastern]");
     }
 
+    /// Tests that overriding the hour into a gap applies each disambiguation
+    /// strategy when re-resolving the civil datetime through the time zone.
+    #[test]
+    fn zoned_with_hour_into_gap_disambiguation() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let zdt: Zoned = "2024-03-10T09:00[America/New_York]".parse().unwrap();
+
+        let got = zdt
+            .with()
+            .hour(2)
+            .minute(30)
+            .disambiguation(Disambiguation::Compatible)
+            .build()
+            .unwrap();
+        assert_eq!(got.timestamp().to_string(), "2024-03-10T07:30:00Z");
+        assert_eq!(
+            got.to_string(),
+            "2024-03-10T03:30:00-04:00[America/New_York]"
+        );
+
+        let got = zdt
+            .with()
+            .hour(2)
+            .minute(30)
+            .disambiguation(Disambiguation::Earlier)
+            .build()
+            .unwrap();
+        assert_eq!(got.timestamp().to_string(), "2024-03-10T06:30:00Z");
+        assert_eq!(
+            got.to_string(),
+            "2024-03-10T01:30:00-05:00[America/New_York]"
+        );
+
+        let got = zdt
+            .with()
+            .hour(2)
+            .minute(30)
+            .disambiguation(Disambiguation::Later)
+            .build()
+            .unwrap();
+        assert_eq!(got.timestamp().to_string(), "2024-03-10T07:30:00Z");
+        assert_eq!(
+            got.to_string(),
+            "2024-03-10T03:30:00-04:00[America/New_York]"
+        );
+
+        let result = zdt
+            .with()
+            .hour(2)
+            .minute(30)
+            .disambiguation(Disambiguation::Reject)
+            .build();
+        assert!(result.is_err());
+    }
+
+    /// Tests that `Hash` is consistent with `Eq`, such that zoned datetimes
+    /// with the same instant in different time zones are deduplicated in a
+    /// hash set.
+    #[test]
+    fn zoned_hash_by_instant() {
+        use std::collections::HashSet;
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let zdt1 = date(2024, 7, 4).at(12, 0, 0, 0).in_tz("America/New_York");
+        let zdt2 = date(2024, 7, 5).at(1, 0, 0, 0).in_tz("Asia/Tokyo");
+        let zdt3 = date(2024, 7, 4).at(12, 0, 0, 0).in_tz("Asia/Tokyo");
+        let (zdt1, zdt2, zdt3) = (zdt1.unwrap(), zdt2.unwrap(), zdt3.unwrap());
+        assert_eq!(zdt1, zdt2);
+        assert_ne!(zdt1, zdt3);
+
+        let mut set = HashSet::new();
+        assert!(set.insert(zdt1.clone()));
+        assert!(!set.insert(zdt2.clone()));
+        assert!(set.insert(zdt3.clone()));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&zdt2));
+        // The first value inserted is retained.
+        let got = set.get(&zdt2).unwrap();
+        assert_eq!(got.time_zone(), zdt1.time_zone());
+
+        let set: HashSet<_> = [&zdt1, &zdt2, &zdt3]
+            .into_iter()
+            .map(|zdt| (zdt.timestamp(), zdt.time_zone().iana_name()))
+            .collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    /// Tests that `Zoned::since` gives a calendar difference while
+    /// `Zoned::duration_since` gives the absolute elapsed time between two
+    /// instants.
+    #[test]
+    fn zoned_since_span_versus_duration() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        // Spring forward: the day is only 23 hours long.
+        let zdt1 = date(2024, 3, 10).at(0, 0, 0, 0).in_tz("America/New_York");
+        let zdt2 = date(2024, 3, 11).at(0, 0, 0, 0).in_tz("America/New_York");
+        let (zdt1, zdt2) = (zdt1.unwrap(), zdt2.unwrap());
+        span_eq!(zdt2.since((Unit::Day, &zdt1)).unwrap(), 1.day());
+        span_eq!(zdt2.since(&zdt1).unwrap(), 23.hours());
+        assert_eq!(zdt2.duration_since(&zdt1), SignedDuration::from_hours(23));
+        assert_eq!(
+            zdt1.duration_since(&zdt2),
+            SignedDuration::from_hours(-23)
+        );
+
+        // Fall back: the day is 25 hours long.
+        let zdt1 = date(2024, 11, 3).at(0, 0, 0, 0).in_tz("America/New_York");
+        let zdt2 = date(2024, 11, 4).at(0, 0, 0, 0).in_tz("America/New_York");
+        let (zdt1, zdt2) = (zdt1.unwrap(), zdt2.unwrap());
+        span_eq!(zdt2.since((Unit::Day, &zdt1)).unwrap(), 1.day());
+        span_eq!(zdt2.since(&zdt1).unwrap(), 25.hours());
+        assert_eq!(zdt2.duration_since(&zdt1), SignedDuration::from_hours(25));
+
+        // The absolute duration only compares instants, so the time zones
+        // don't matter.
+        let zdt3 = zdt2.with_time_zone(TimeZone::get("Asia/Tokyo").unwrap());
+        assert_eq!(zdt3.duration_since(&zdt1), SignedDuration::from_hours(25));
+        assert_eq!(zdt3.duration_since(&zdt2), SignedDuration::ZERO);
+    }
+
+    /// Tests that building a `Zoned` in `TimeZone::UTC`, which doesn't need
+    /// to consult any transitions, gives the same result as building one in
+    /// the TZif-backed `Etc/UTC` time zone.
+    #[test]
+    fn zoned_utc_matches_tzif_utc() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let tzif = TimeZone::get("Etc/UTC").unwrap();
+        let timestamps = [
+            Timestamp::MIN,
+            Timestamp::new(-100_000_000_000, 123_456_789).unwrap(),
+            Timestamp::new(-1, 0).unwrap(),
+            Timestamp::UNIX_EPOCH,
+            Timestamp::new(1, 0).unwrap(),
+            Timestamp::new(1_719_755_160, 123_456_789).unwrap(),
+            Timestamp::new(100_000_000_000, 0).unwrap(),
+            Timestamp::MAX,
+        ];
+        for ts in timestamps {
+            let fast = ts.to_zoned(TimeZone::UTC);
+            let slow = ts.to_zoned(tzif.clone());
+            assert_eq!(fast.timestamp(), slow.timestamp(), "{ts}");
+            assert_eq!(fast.offset(), slow.offset(), "{ts}");
+            assert_eq!(fast.datetime(), slow.datetime(), "{ts}");
+        }
+    }
+
+    /// Tests `start_of_day` and `end_of_day` on normal days and on days with
+    /// time zone transitions, including a day where midnight doesn't exist.
+    #[test]
+    fn zoned_start_and_end_of_day() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let test = |zdt: &str, start: &str, end: &str| {
+            let zdt: Zoned = zdt.parse().unwrap();
+            let got = zdt.start_of_day().unwrap();
+            assert_eq!(got.timestamp().to_string(), start, "start of {zdt}");
+            let got = zdt.end_of_day().unwrap();
+            assert_eq!(got.timestamp().to_string(), end, "end of {zdt}");
+        };
+
+        // A normal day.
+        test(
+            "2024-07-03T12:00[America/New_York]",
+            "2024-07-03T04:00:00Z",
+            "2024-07-04T03:59:59.999999999Z",
+        );
+        // Spring forward. The day starts at `-05` but ends at `-04`.
+        test(
+            "2024-03-10T12:00[America/New_York]",
+            "2024-03-10T05:00:00Z",
+            "2024-03-11T03:59:59.999999999Z",
+        );
+        // Fall back. The day starts at `-04` but ends at `-05`.
+        test(
+            "2024-11-03T12:00[America/New_York]",
+            "2024-11-03T04:00:00Z",
+            "2024-11-04T04:59:59.999999999Z",
+        );
+        // Midnight falls in a gap, so the day starts at 01:00.
+        test(
+            "2018-11-04T12:00[America/Sao_Paulo]",
+            "2018-11-04T03:00:00Z",
+            "2018-11-05T01:59:59.999999999Z",
+        );
+        let zdt: Zoned =
+            "2018-11-04T12:00[America/Sao_Paulo]".parse().unwrap();
+        assert_eq!(
+            zdt.start_of_day().unwrap().to_string(),
+            "2018-11-04T01:00:00-02:00[America/Sao_Paulo]",
+        );
+    }
+
     #[test]
     fn zoned_precision_loss() {
         if crate::tz::db().is_definitively_empty() {
@@ -5571,4 +6395,371 @@ mod tests {
         );
         assert_eq!(zdt1, &zdt2 - span, "should be reversible");
     }
+
+    /// Tests that changing the time zone of a zoned datetime keeps the
+    /// instant, while re-interpreting its civil datetime does not.
+    #[test]
+    fn zoned_with_time_zone_keeps_instant() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let meeting = date(2024, 3, 12)
+            .at(9, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+        let berlin = TimeZone::get("Europe/Berlin").unwrap();
+        let local = meeting.with_time_zone(berlin.clone());
+        assert_eq!(local.timestamp(), meeting.timestamp());
+        assert_eq!(local, meeting);
+        assert_eq!(local.datetime(), date(2024, 3, 12).at(14, 0, 0, 0));
+        assert_eq!(local.offset(), crate::tz::offset(1));
+        assert_eq!(local.time_zone(), &berlin);
+        assert_eq!(local.with_time_zone(meeting.time_zone().clone()), meeting);
+
+        // `in_zone` is an alias for the same conversion.
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        let utc = date(2024, 3, 12).at(9, 0, 0, 0).in_tz("UTC").unwrap();
+        let local = utc.in_zone(tokyo.clone());
+        assert_eq!(local.timestamp(), utc.timestamp());
+        assert_eq!(local.time_zone(), &tokyo);
+        assert_eq!(utc.hour(), 9);
+        assert_eq!(local.hour(), 18);
+        assert_eq!(local, utc.with_time_zone(tokyo));
+
+        // Keeping the civil datetime instead changes the instant.
+        let same_clock = meeting.datetime().to_zoned(berlin).unwrap();
+        assert_ne!(same_clock.timestamp(), meeting.timestamp());
+    }
+
+    #[test]
+    fn zoned_datetime_is_local() {
+        let tz = TimeZone::fixed(crate::tz::offset(-5));
+        let utc = date(2024, 3, 14).at(23, 45, 0, 0).to_zoned(TimeZone::UTC);
+        let zdt = utc.unwrap().timestamp().to_zoned(tz);
+        assert_eq!(zdt.datetime(), date(2024, 3, 14).at(18, 45, 0, 0));
+        assert_eq!(zdt.date(), date(2024, 3, 14));
+        assert_eq!(zdt.hour(), 18);
+
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+        // The civil datetime uses the offset in effect at each instant. So
+        // the same UTC wall clock time maps to different local times on
+        // either side of a DST transition.
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let before = date(2024, 3, 9).at(17, 0, 0, 0).to_zoned(TimeZone::UTC);
+        let after = date(2024, 3, 11).at(17, 0, 0, 0).to_zoned(TimeZone::UTC);
+        let (before, after) = (before.unwrap(), after.unwrap());
+        let before = before.timestamp().to_zoned(tz.clone());
+        let after = after.timestamp().to_zoned(tz);
+        assert_eq!(before.datetime(), date(2024, 3, 9).at(12, 0, 0, 0));
+        assert_eq!(after.datetime(), date(2024, 3, 11).at(13, 0, 0, 0));
+        assert_eq!(
+            after.with_time_zone(TimeZone::UTC).datetime(),
+            date(2024, 3, 11).at(17, 0, 0, 0),
+        );
+    }
+
+    #[test]
+    fn zoned_reinterpret_local() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let meeting = date(2024, 7, 15)
+            .at(9, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        let got = meeting.reinterpret_local(tokyo.clone()).unwrap();
+        assert_eq!(got.datetime(), meeting.datetime());
+        assert_eq!(got.time_zone(), &tokyo);
+        assert_eq!(got.offset(), crate::tz::offset(9));
+        assert_ne!(got.timestamp(), meeting.timestamp());
+        assert_eq!(
+            meeting.timestamp().duration_since(got.timestamp()),
+            crate::SignedDuration::from_hours(13),
+        );
+        // Going back recovers the original instant.
+        assert_eq!(
+            got.reinterpret_local(meeting.time_zone().clone()).unwrap(),
+            meeting,
+        );
+
+        // In a gap, the civil time is shifted forward.
+        let ny = TimeZone::get("America/New_York").unwrap();
+        let gap = date(2024, 3, 10).at(2, 30, 0, 0).in_tz("UTC").unwrap();
+        let got = gap.reinterpret_local(ny.clone()).unwrap();
+        assert_eq!(got.datetime(), date(2024, 3, 10).at(3, 30, 0, 0));
+        // In a fold, the earlier instant is chosen.
+        let fold = date(2024, 11, 3).at(1, 30, 0, 0).in_tz("UTC").unwrap();
+        let got = fold.reinterpret_local(ny).unwrap();
+        assert_eq!(got.datetime(), fold.datetime());
+        assert_eq!(got.offset(), crate::tz::offset(-4));
+
+        // Overflow is reported as an error.
+        let max = Timestamp::MAX.to_zoned(TimeZone::UTC);
+        let west = TimeZone::fixed(crate::tz::offset(-5));
+        assert!(max.reinterpret_local(west).is_err());
+    }
+
+    #[test]
+    fn zoned_is_between() {
+        let start = date(2024, 6, 19)
+            .at(9, 0, 0, 0)
+            .to_zoned(TimeZone::fixed(tz::offset(-4)))
+            .unwrap();
+        let end = date(2024, 6, 19)
+            .at(17, 0, 0, 0)
+            .to_zoned(TimeZone::fixed(tz::offset(-4)))
+            .unwrap();
+        // The same instants as `start` and `end`, but in a different zone.
+        let start_utc = start.with_time_zone(TimeZone::UTC);
+        let end_utc = end.with_time_zone(TimeZone::UTC);
+
+        let tests = [
+            (Inclusivity::Closed, true, true),
+            (Inclusivity::ClosedOpen, true, false),
+            (Inclusivity::OpenClosed, false, true),
+            (Inclusivity::Open, false, false),
+        ];
+        for (inclusivity, has_start, has_end) in tests {
+            assert_eq!(
+                start_utc.is_between(&start, &end, inclusivity),
+                has_start,
+                "{inclusivity:?}",
+            );
+            assert_eq!(
+                end_utc.is_between(&start, &end, inclusivity),
+                has_end,
+                "{inclusivity:?}",
+            );
+            let mid = start.checked_add(1.nanosecond()).unwrap();
+            assert!(mid.is_between(&start_utc, &end_utc, inclusivity));
+            assert!(!mid.is_between(&end, &start, inclusivity));
+        }
+    }
+
+    #[test]
+    fn zoned_add_day_versus_24_hours() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let one_day: Span = "P1D".parse().unwrap();
+        let twenty_four_hours: Span = "PT24H".parse().unwrap();
+
+        // Just before the spring-forward transition in New York on
+        // 2024-03-10, where 02:00 jumps to 03:00.
+        let zdt = date(2024, 3, 9)
+            .at(12, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+
+        let civil = zdt.checked_add(one_day).unwrap();
+        assert_eq!(civil.datetime(), date(2024, 3, 10).at(12, 0, 0, 0));
+        assert_eq!(
+            civil.timestamp().duration_since(zdt.timestamp()),
+            SignedDuration::from_hours(23),
+        );
+
+        let absolute = zdt.checked_add(twenty_four_hours).unwrap();
+        assert_eq!(absolute.datetime(), date(2024, 3, 10).at(13, 0, 0, 0));
+        assert_eq!(
+            absolute.timestamp().duration_since(zdt.timestamp()),
+            SignedDuration::from_hours(24),
+        );
+        // Adding an absolute duration is the same as adding hours.
+        assert_eq!(
+            zdt.checked_add(SignedDuration::from_hours(24)).unwrap(),
+            absolute,
+        );
+
+        // And the reverse across the fall-back transition on 2024-11-03,
+        // where 02:00 goes back to 01:00.
+        let zdt = date(2024, 11, 2)
+            .at(12, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+
+        let civil = zdt.checked_add(one_day).unwrap();
+        assert_eq!(civil.datetime(), date(2024, 11, 3).at(12, 0, 0, 0));
+        assert_eq!(
+            civil.timestamp().duration_since(zdt.timestamp()),
+            SignedDuration::from_hours(25),
+        );
+
+        let absolute = zdt.checked_add(twenty_four_hours).unwrap();
+        assert_eq!(absolute.datetime(), date(2024, 11, 3).at(11, 0, 0, 0));
+        assert_eq!(
+            absolute.timestamp().duration_since(zdt.timestamp()),
+            SignedDuration::from_hours(24),
+        );
+    }
+
+    #[test]
+    fn zoned_arithmetic_overflow_error() {
+        let zdt = date(9999, 12, 30)
+            .at(21, 0, 0, 0)
+            .to_zoned(TimeZone::UTC)
+            .unwrap();
+
+        insta::assert_snapshot!(
+            zdt.checked_add(2.hours()).unwrap_err(),
+            @"failed to add PT2H to 9999-12-30T21:00:00+00:00[UTC]: parameter 'span' with value 7200 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            zdt.checked_add(1.day()).unwrap_err(),
+            @"failed to add P1D to 9999-12-30T21:00:00+00:00[UTC]: failed to convert civil datetime 9999-12-31T21:00:00 to timestamp with time zone UTC: converting 9999-12-31T21:00:00 with offset +00 to timestamp overflowed: parameter 'unix-seconds' with value 253402290000 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            zdt.checked_add(SignedDuration::from_hours(2)).unwrap_err(),
+            @"failed to add PT2H to 9999-12-30T21:00:00+00:00[UTC]: parameter 'second' with value 253402210800 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            zdt.checked_sub(SignedDuration::from_hours(-2)).unwrap_err(),
+            @"failed to subtract -PT2H from 9999-12-30T21:00:00+00:00[UTC]: parameter 'second' with value 253402210800 is not in the required range of -377705023201..=253402207200",
+        );
+        insta::assert_snapshot!(
+            zdt.checked_sub((-2).hours()).unwrap_err(),
+            @"failed to subtract -PT2H from 9999-12-30T21:00:00+00:00[UTC]: parameter 'span' with value 7200 is not in the required range of -377705023201..=253402207200",
+        );
+        let udur = UnsignedDuration::from_secs(u64::MAX);
+        insta::assert_snapshot!(
+            zdt.checked_sub(udur).unwrap_err(),
+            @"failed to subtract 18446744073709551615s from 9999-12-30T21:00:00+00:00[UTC]: failed to negate unsigned duration 18446744073709551615s: seconds in unsigned duration 18446744073709551615s overflowed i64",
+        );
+    }
+
+    #[test]
+    fn zoned_print_offset_only() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let zdt = date(2021, 7, 30)
+            .at(17, 20, 4, 123_000_000)
+            .in_tz("America/New_York")
+            .unwrap();
+        let got =
+            zdt.timestamp().display_with_offset(zdt.offset()).to_string();
+        assert_eq!(got, "2021-07-30T17:20:04.123-04:00");
+        assert!(!got.contains('['));
+        // The default `Display` impl still includes the annotation.
+        assert_eq!(
+            zdt.to_string(),
+            "2021-07-30T17:20:04.123-04:00[America/New_York]",
+        );
+        // And it roundtrips as a timestamp.
+        assert_eq!(got.parse::<Timestamp>().unwrap(), zdt.timestamp());
+    }
+
+    #[test]
+    fn zoned_day_length() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let len = |y, m, d, tz| {
+            date(y, m, d).at(12, 0, 0, 0).in_tz(tz).unwrap().day_length()
+        };
+        let hours = SignedDuration::from_hours;
+
+        // Spring forward, fall back and an ordinary day.
+        assert_eq!(len(2024, 3, 10, "America/New_York").unwrap(), hours(23));
+        assert_eq!(len(2024, 11, 3, "America/New_York").unwrap(), hours(25));
+        assert_eq!(len(2024, 7, 4, "America/New_York").unwrap(), hours(24));
+        assert_eq!(len(2024, 3, 10, "UTC").unwrap(), hours(24));
+        // The length doesn't depend on the time of day.
+        let zdt = date(2024, 3, 10)
+            .at(0, 0, 0, 0)
+            .in_tz("America/New_York")
+            .unwrap();
+        assert_eq!(zdt.day_length().unwrap(), hours(23));
+        // A transition at midnight, where the day starts at 01:00.
+        assert_eq!(len(2015, 10, 18, "America/Sao_Paulo").unwrap(), hours(23));
+        assert_eq!(len(2016, 2, 20, "America/Sao_Paulo").unwrap(), hours(25));
+        // A 30 minute transition.
+        assert_eq!(
+            len(2024, 10, 6, "Australia/Lord_Howe").unwrap(),
+            hours(23) + SignedDuration::from_mins(30),
+        );
+        // Pacific/Apia skipped 2011-12-30 entirely, so 2011-12-29 is
+        // followed immediately by 2011-12-31.
+        assert_eq!(len(2011, 12, 29, "Pacific/Apia").unwrap(), hours(24));
+
+        let zdt = Timestamp::MAX.to_zoned(TimeZone::UTC);
+        assert!(zdt.day_length().is_err());
+    }
+
+    #[test]
+    fn zoned_duration_until_is_duration_since_reversed() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let zdt =
+            |y, m, d, h, tz| date(y, m, d).at(h, 0, 0, 0).in_tz(tz).unwrap();
+        let pairs = [
+            (
+                zdt(2024, 3, 10, 0, "America/New_York"),
+                zdt(2024, 3, 11, 0, "America/New_York"),
+            ),
+            (
+                zdt(2024, 11, 4, 0, "America/New_York"),
+                zdt(2024, 11, 3, 0, "America/New_York"),
+            ),
+            (
+                zdt(2024, 3, 10, 12, "America/New_York"),
+                zdt(2024, 3, 10, 12, "Asia/Tokyo"),
+            ),
+            (
+                zdt(2024, 6, 1, 0, "UTC"),
+                zdt(2024, 6, 1, 0, "UTC").with_time_zone(
+                    TimeZone::get("Australia/Lord_Howe").unwrap(),
+                ),
+            ),
+        ];
+        for (a, b) in &pairs {
+            assert_eq!(a.duration_until(b), b.duration_since(a), "{a} {b}");
+            assert_eq!(a.duration_until(b), -a.duration_since(b), "{a} {b}");
+            assert_eq!(
+                a.duration_until(b),
+                a.timestamp().duration_until(b.timestamp()),
+            );
+        }
+        assert_eq!(
+            pairs[0].0.duration_until(&pairs[0].1),
+            SignedDuration::from_hours(23),
+        );
+        assert_eq!(
+            pairs[2].0.duration_until(&pairs[2].1),
+            SignedDuration::from_hours(-13),
+        );
+        assert_eq!(
+            pairs[3].0.duration_until(&pairs[3].1),
+            SignedDuration::ZERO
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zoned_to_rfc3339() {
+        if crate::tz::db().is_definitively_empty() {
+            return;
+        }
+
+        let dt = date(2021, 7, 30).at(17, 20, 4, 123_000_000);
+        let zdt = dt.in_tz("America/New_York").unwrap();
+        let got = zdt.to_rfc3339();
+        assert_eq!(got, "2021-07-30T17:20:04.123-04:00");
+        assert!(got.ends_with("-04:00"));
+        assert!(!got.contains('[') && !got.contains(']'));
+
+        let zdt = dt.in_tz("Asia/Kolkata").unwrap();
+        assert_eq!(zdt.to_rfc3339(), "2021-07-30T17:20:04.123+05:30");
+        let zdt = dt.to_zoned(TimeZone::UTC).unwrap();
+        assert_eq!(zdt.to_rfc3339(), "2021-07-30T17:20:04.123+00:00");
+    }
 }